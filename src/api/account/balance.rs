@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Balance {
+    /// The balance of the account in EUR
+    pub value: f64,
+    /// Whether auto top-up is enabled for the account
+    #[serde(rename = "autoReload")]
+    pub auto_reload: bool,
+}
+
+/// Top Up
+/// Tops up an account with auto top-up enabled, using the transaction of the first top-up
+#[derive(Debug, Clone, Serialize)]
+pub struct TopUp {
+    trx: String,
+}
+
+impl TopUp {
+    /// Create a new TopUp
+    /// @param trx The transaction reference of the payment that enabled auto top-up
+    pub fn new(trx: String) -> Self {
+        TopUp { trx }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopUpResponse {
+    #[serde(rename = "error-code")]
+    pub error_code: String,
+    #[serde(rename = "error-code-label")]
+    pub error_code_label: String,
+}
+
+impl TopUpResponse {
+    pub fn is_success(&self) -> bool {
+        self.error_code == "200"
+    }
+}
@@ -0,0 +1,251 @@
+mod balance;
+mod secrets;
+mod settings;
+
+use async_trait::async_trait;
+pub use balance::*;
+pub use secrets::AccountSecret;
+use secrets::{NewSecret, SecretListResponse};
+pub use settings::*;
+
+use crate::client::client_types::ApiSecret;
+use crate::client::path::ApiPath;
+use crate::client::{ApiFamily, VonageClient, VonageClientError};
+
+#[async_trait]
+pub trait AccountApi {
+    /// Get Balance
+    /// This function gets the balance of the account
+    /// @return The balance of the account
+    async fn get_balance(&mut self) -> Result<Balance, VonageClientError>;
+
+    /// Top Up
+    /// This function tops up an account with auto top-up enabled
+    /// @param top_up The transaction reference of the payment that enabled auto top-up
+    /// @return The outcome of the top-up
+    async fn top_up(&mut self, top_up: &TopUp) -> Result<TopUpResponse, VonageClientError>;
+
+    /// Update Settings
+    /// This function updates the default webhooks for inbound SMS and delivery receipts
+    /// @param settings The webhooks to update
+    /// @return The settings of the account
+    async fn update_settings(
+        &mut self,
+        settings: &UpdateSettings,
+    ) -> Result<AccountSettings, VonageClientError>;
+
+    /// List Secrets
+    /// This function lists the secrets of an API key
+    /// @param api_key The API key, either the account or a subaccount
+    /// @return The secrets of the API key
+    async fn list_secrets(
+        &mut self,
+        api_key: &str,
+    ) -> Result<Vec<AccountSecret>, VonageClientError>;
+
+    /// Get Secret
+    /// This function gets a secret of an API key
+    /// @param api_key The API key
+    /// @param secret_id The ID of the secret
+    /// @return The secret
+    async fn get_secret(
+        &mut self,
+        api_key: &str,
+        secret_id: &str,
+    ) -> Result<AccountSecret, VonageClientError>;
+
+    /// Create Secret
+    /// This function adds a secret to an API key, an API key can have two secrets
+    /// @param api_key The API key
+    /// @param secret The new secret, 8 to 25 characters with at least one lowercase letter, uppercase letter and digit
+    /// @return The created secret
+    async fn create_secret(
+        &mut self,
+        api_key: &str,
+        secret: ApiSecret,
+    ) -> Result<AccountSecret, VonageClientError>;
+
+    /// Revoke Secret
+    /// This function revokes a secret of an API key, the last secret of an API key cannot be revoked
+    /// @param api_key The API key
+    /// @param secret_id The ID of the secret
+    async fn revoke_secret(
+        &mut self,
+        api_key: &str,
+        secret_id: &str,
+    ) -> Result<(), VonageClientError>;
+}
+
+#[async_trait]
+impl AccountApi for VonageClient {
+    async fn get_balance(&mut self) -> Result<Balance, VonageClientError> {
+        log::debug!("Getting balance");
+        let path = ApiPath::new(ApiFamily::Rest, "/account/get-balance").basic_auth();
+        self.get(path)
+            .await
+            .map(Self::debug_response("Get Balance Response".into()))
+    }
+
+    async fn top_up(&mut self, top_up: &TopUp) -> Result<TopUpResponse, VonageClientError> {
+        log::debug!("Topping up account: {:?}", top_up);
+        let path = ApiPath::new(ApiFamily::Rest, "/account/top-up").basic_auth();
+        self.post_form(path, top_up)
+            .await
+            .map(Self::debug_response("Top Up Response".into()))
+    }
+
+    async fn update_settings(
+        &mut self,
+        settings: &UpdateSettings,
+    ) -> Result<AccountSettings, VonageClientError> {
+        log::debug!("Updating account settings: {:?}", settings);
+        let path = ApiPath::new(ApiFamily::Rest, "/account/settings").basic_auth();
+        self.post_form(path, settings)
+            .await
+            .map(Self::debug_response("Update Settings Response".into()))
+    }
+
+    async fn list_secrets(
+        &mut self,
+        api_key: &str,
+    ) -> Result<Vec<AccountSecret>, VonageClientError> {
+        log::debug!("Listing secrets");
+        let path = ApiPath::new(ApiFamily::Api, "/accounts/{api_key}/secrets")
+            .param("api_key", api_key)
+            .basic_auth();
+        self.get::<SecretListResponse>(path)
+            .await
+            .map(SecretListResponse::into_secrets)
+            .map(Self::debug_response("List Secrets Response".into()))
+    }
+
+    async fn get_secret(
+        &mut self,
+        api_key: &str,
+        secret_id: &str,
+    ) -> Result<AccountSecret, VonageClientError> {
+        log::debug!("Getting secret: {}", secret_id);
+        let path = ApiPath::new(ApiFamily::Api, "/accounts/{api_key}/secrets/{secret_id}")
+            .param("api_key", api_key)
+            .param("secret_id", secret_id)
+            .basic_auth();
+        self.get(path)
+            .await
+            .map(Self::debug_response("Get Secret Response".into()))
+    }
+
+    async fn create_secret(
+        &mut self,
+        api_key: &str,
+        secret: ApiSecret,
+    ) -> Result<AccountSecret, VonageClientError> {
+        log::debug!("Creating secret");
+        let path = ApiPath::new(ApiFamily::Api, "/accounts/{api_key}/secrets")
+            .param("api_key", api_key)
+            .basic_auth();
+        self.post(path, NewSecret::from(secret))
+            .await
+            .map(Self::debug_response("Create Secret Response".into()))
+    }
+
+    async fn revoke_secret(
+        &mut self,
+        api_key: &str,
+        secret_id: &str,
+    ) -> Result<(), VonageClientError> {
+        log::debug!("Revoking secret: {}", secret_id);
+        let path = ApiPath::new(ApiFamily::Api, "/accounts/{api_key}/secrets/{secret_id}")
+            .param("api_key", api_key)
+            .param("secret_id", secret_id)
+            .basic_auth();
+        self.delete(path).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use crate::client::mock::MockServer;
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    #[tokio::test]
+    async fn balance_and_settings() {
+        init();
+        let server = MockServer::default()
+            .respond(200, r#"{"value":10.28,"autoReload":false}"#)
+            .respond(
+                200,
+                r#"{"mo-callback-url":"","dr-callback-url":"https://example.com/webhooks/dlr","max-outbound-request":30,"max-inbound-request":30,"max-calls-per-second":30}"#,
+            );
+        let mut client = server.client();
+
+        let balance = client.get_balance().await.unwrap();
+        assert_eq!(balance.value, 10.28);
+        assert!(!balance.auto_reload);
+
+        let mut update = UpdateSettings::new();
+        update.delivery_receipt_url("https://example.com/webhooks/dlr".to_string());
+        let settings = client.update_settings(&update).await.unwrap();
+        assert_eq!(settings.max_calls_per_second, Some(30));
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].url.as_str(),
+            "https://rest.nexmo.com/account/get-balance"
+        );
+        assert_eq!(
+            requests[1].body.as_deref(),
+            Some("drCallBackUrl=https%3A%2F%2Fexample.com%2Fwebhooks%2Fdlr")
+        );
+    }
+
+    #[tokio::test]
+    async fn rotate_secret() {
+        init();
+        let server = MockServer::default()
+            .respond(
+                201,
+                r#"{"id":"new-secret","created_at":"2024-01-01T00:00:00Z"}"#,
+            )
+            .respond(
+                200,
+                r#"{"_embedded":{"secrets":[{"id":"old-secret","created_at":"2023-01-01T00:00:00Z"},{"id":"new-secret","created_at":"2024-01-01T00:00:00Z"}]}}"#,
+            )
+            .respond(204, "");
+        let mut client = server.client();
+
+        let created = client
+            .create_secret("abcd1234", "Sup3rS3cret".into())
+            .await
+            .unwrap();
+        let old: Vec<_> = client
+            .list_secrets("abcd1234")
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|secret| secret.id != created.id)
+            .collect();
+        client.revoke_secret("abcd1234", &old[0].id).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].url.as_str(),
+            "https://api.nexmo.com/accounts/abcd1234/secrets"
+        );
+        assert_eq!(
+            requests[0].body.as_deref(),
+            Some(r#"{"secret":"Sup3rS3cret"}"#)
+        );
+        assert_eq!(requests[2].method, reqwest::Method::DELETE);
+        assert_eq!(
+            requests[2].path_and_query(),
+            "/accounts/abcd1234/secrets/old-secret"
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::client_types::{ApiSecret, SensitiveUnwrap};
+use crate::types::pages::Links;
+
+/// Account Secret
+/// A secret of an API key, the value of a secret is never returned
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountSecret {
+    pub id: String,
+    pub created_at: String,
+    #[serde(skip_serializing)] // This field should not be serialized
+    pub _links: Option<Links>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct SecretList {
+    secrets: Vec<AccountSecret>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct SecretListResponse {
+    #[serde(rename = "_embedded")]
+    embedded: SecretList,
+}
+
+impl SecretListResponse {
+    pub(crate) fn into_secrets(self) -> Vec<AccountSecret> {
+        self.embedded.secrets
+    }
+}
+
+/// The body of a request to create a secret
+#[derive(Serialize)]
+pub(crate) struct NewSecret {
+    secret: String,
+}
+
+impl From<ApiSecret> for NewSecret {
+    fn from(secret: ApiSecret) -> Self {
+        NewSecret {
+            secret: secret.unwrap(),
+        }
+    }
+}
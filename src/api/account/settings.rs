@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+/// Update Settings
+/// The default webhooks for inbound SMS and delivery receipts, an empty string clears a webhook
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::account::UpdateSettings;
+/// let mut settings = UpdateSettings::new();
+/// settings.delivery_receipt_url("https://example.com/webhooks/dlr".to_string());
+/// ```
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateSettings {
+    #[serde(rename = "moCallBackUrl", skip_serializing_if = "Option::is_none")]
+    mo_callback_url: Option<String>,
+    #[serde(rename = "drCallBackUrl", skip_serializing_if = "Option::is_none")]
+    dr_callback_url: Option<String>,
+}
+
+impl UpdateSettings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the default webhook for inbound SMS
+    pub fn inbound_sms_url(&mut self, url: String) -> &mut Self {
+        self.mo_callback_url = Some(url);
+        self
+    }
+
+    /// Set the default webhook for SMS delivery receipts
+    pub fn delivery_receipt_url(&mut self, url: String) -> &mut Self {
+        self.dr_callback_url = Some(url);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct AccountSettings {
+    pub mo_callback_url: Option<String>,
+    pub dr_callback_url: Option<String>,
+    pub max_outbound_request: Option<u32>,
+    pub max_inbound_request: Option<u32>,
+    pub max_calls_per_second: Option<u32>,
+}
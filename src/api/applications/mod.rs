@@ -0,0 +1,194 @@
+mod application;
+
+pub use application::*;
+use async_trait::async_trait;
+use futures::Stream;
+
+use crate::client::path::ApiPath;
+use crate::client::{ApiFamily, VonageClient, VonageClientError};
+use crate::types::pages::PageOptions;
+
+#[async_trait]
+pub trait ApplicationsApi {
+    /// Create Application
+    /// This function creates an application, a key pair is generated when no public key is set
+    /// @param application The application to create
+    /// @return The created application, use `take_private_key` to get the generated private key
+    async fn create_application(
+        &mut self,
+        application: &Application,
+    ) -> Result<Application, VonageClientError>;
+
+    /// Get Application
+    /// This function gets an application
+    /// @param id The ID of the application
+    /// @return The application
+    async fn get_application(&mut self, id: &str) -> Result<Application, VonageClientError>;
+
+    /// Update Application
+    /// This function replaces the settings of an application, capabilities that are not set are removed
+    /// @param id The ID of the application
+    /// @param application The new settings of the application
+    /// @return The updated application
+    async fn update_application(
+        &mut self,
+        id: &str,
+        application: &Application,
+    ) -> Result<Application, VonageClientError>;
+
+    /// Delete Application
+    /// This function deletes an application
+    /// @param id The ID of the application
+    async fn delete_application(&mut self, id: &str) -> Result<(), VonageClientError>;
+
+    /// List Applications
+    /// This function streams every application in the account
+    /// @param options The page size and the maximum number of applications to yield
+    /// @return A stream of applications
+    fn list_applications(
+        &self,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<Application, VonageClientError>> + Send + 'static;
+}
+
+#[async_trait]
+impl ApplicationsApi for VonageClient {
+    async fn create_application(
+        &mut self,
+        application: &Application,
+    ) -> Result<Application, VonageClientError> {
+        log::debug!("Creating application: {:?}", application);
+        let path = ApiPath::new(ApiFamily::Api, "/v2/applications").basic_auth();
+        self.post(path, application)
+            .await
+            .map(Self::debug_response("Create Application Response".into()))
+    }
+
+    async fn get_application(&mut self, id: &str) -> Result<Application, VonageClientError> {
+        log::debug!("Getting application: {}", id);
+        let path = ApiPath::new(ApiFamily::Api, "/v2/applications/{id}")
+            .param("id", id)
+            .basic_auth();
+        self.get(path)
+            .await
+            .map(Self::debug_response("Get Application Response".into()))
+    }
+
+    async fn update_application(
+        &mut self,
+        id: &str,
+        application: &Application,
+    ) -> Result<Application, VonageClientError> {
+        log::debug!("Updating application {}: {:?}", id, application);
+        let path = ApiPath::new(ApiFamily::Api, "/v2/applications/{id}")
+            .param("id", id)
+            .basic_auth();
+        self.put(path, application)
+            .await
+            .map(Self::debug_response("Update Application Response".into()))
+    }
+
+    async fn delete_application(&mut self, id: &str) -> Result<(), VonageClientError> {
+        log::debug!("Deleting application: {}", id);
+        let path = ApiPath::new(ApiFamily::Api, "/v2/applications/{id}")
+            .param("id", id)
+            .basic_auth();
+        self.delete(path).await
+    }
+
+    fn list_applications(
+        &self,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<Application, VonageClientError>> + Send + 'static {
+        log::debug!("Listing applications");
+        let path = ApiPath::new(ApiFamily::Api, "/v2/applications").basic_auth();
+        self.paginate::<ApplicationListPage>(path, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use crate::client::mock::MockServer;
+    use futures::TryStreamExt;
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    #[tokio::test]
+    async fn create_application_and_build_client() {
+        init();
+        let created = serde_json::json!({
+            "id": "app-id",
+            "name": "Tenant 42",
+            "keys": {
+                "public_key": include_str!("../../client/test_data/public_key.pem"),
+                "private_key": include_str!("../../client/test_data/private_key.pem")
+            }
+        });
+        let server = MockServer::default().respond(201, &created.to_string());
+
+        let mut application = server
+            .client()
+            .create_application(&Application::new("Tenant 42".to_string()))
+            .await
+            .unwrap();
+        let private_key = application.take_private_key().unwrap();
+        assert_eq!(application.id.as_deref(), Some("app-id"));
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].url.as_str(),
+            "https://api.nexmo.com/v2/applications"
+        );
+        assert_eq!(requests[0].body.as_deref(), Some(r#"{"name":"Tenant 42"}"#));
+
+        let tenant = VonageClient::builder()
+            .app_id(application.id.unwrap())
+            .private_key(private_key)
+            .build();
+        assert!(tenant.is_ok());
+    }
+
+    #[tokio::test]
+    async fn list_and_delete_applications() {
+        init();
+        let server = MockServer::default()
+            .respond(
+                200,
+                r#"{"page_size":1,"page":1,"total_items":2,"total_pages":2,"_embedded":{"applications":[{"id":"a","name":"A"}]}}"#,
+            )
+            .respond(
+                200,
+                r#"{"page_size":1,"page":2,"total_items":2,"total_pages":2,"_embedded":{"applications":[{"id":"b","name":"B"}]}}"#,
+            )
+            .respond(204, "");
+        let mut client = server.client();
+
+        let applications: Vec<Application> = client
+            .list_applications(PageOptions::new().page_size(1))
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(applications.len(), 2);
+        client.delete_application("b").await.unwrap();
+
+        let paths: Vec<_> = server
+            .requests()
+            .iter()
+            .map(|req| req.path_and_query())
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/v2/applications?page_size=1&page=1",
+                "/v2/applications?page_size=1&page=2",
+                "/v2/applications/b"
+            ]
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use crate::types::pages::{Links, Page, PageMeta, PageStyle};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize)]
+struct LegList {
+    legs: Vec<Leg>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LegListPage {
+    #[serde(flatten)]
+    meta: PageMeta,
+    #[serde(rename = "_embedded")]
+    embedded: LegList,
+}
+
+impl LegListPage {
+    pub fn meta(&self) -> &PageMeta {
+        &self.meta
+    }
+
+    pub fn legs(&self) -> &[Leg] {
+        &self.embedded.legs
+    }
+}
+
+impl Page for LegListPage {
+    type Item = Leg;
+    const STYLE: PageStyle = PageStyle::Cursor;
+
+    fn next_href(&self) -> Option<&str> {
+        self.meta.links().next().map(|link| link.href())
+    }
+
+    fn into_items(self) -> Vec<Leg> {
+        self.embedded.legs
+    }
+}
+
+/// Leg
+/// A single participant connection to a conversation, such as a PSTN call or an app user
+#[derive(Debug, Clone, Deserialize)]
+pub struct Leg {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub leg_type: LegType,
+    pub direction: Option<LegDirection>,
+    pub status: LegStatus,
+    pub conversation_id: Option<String>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub from: Option<LegEndpoint>,
+    pub to: Option<LegEndpoint>,
+    pub _links: Option<Links>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LegType {
+    Phone,
+    App,
+    Sip,
+    Websocket,
+    Vbc,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LegDirection {
+    Inbound,
+    Outbound,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LegStatus {
+    Started,
+    Ringing,
+    Answered,
+    Busy,
+    Cancelled,
+    Rejected,
+    Timeout,
+    Failed,
+    Unanswered,
+    Completed,
+    #[serde(other)]
+    Unknown,
+}
+
+impl LegStatus {
+    /// Whether the leg has ended, a leg that is not finished is still using a channel
+    pub fn is_finished(&self) -> bool {
+        !matches!(
+            self,
+            LegStatus::Started | LegStatus::Ringing | LegStatus::Answered
+        )
+    }
+}
+
+/// Leg Endpoint
+/// One end of a leg, which fields are set depends on the endpoint type
+#[derive(Debug, Clone, Deserialize)]
+pub struct LegEndpoint {
+    #[serde(rename = "type")]
+    pub endpoint_type: Option<String>,
+    pub number: Option<String>,
+    pub user: Option<String>,
+    pub uri: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
@@ -1,5 +1,12 @@
+mod leg;
+mod session;
 mod user;
-use crate::client::{VonageClient, VonageClientError};
+use crate::client::path::ApiPath;
+use crate::client::{ApiFamily, VonageClient, VonageClientError};
+use crate::types::pages::PageOptions;
+use futures::Stream;
+pub use leg::{Leg, LegDirection, LegEndpoint, LegListPage, LegStatus, LegType};
+pub use session::{SessionUser, UserSession, UserSessionDetails, UserSessionListPage};
 pub use user::{User, UserListPage};
 
 pub trait ConversationApi {
@@ -10,6 +17,15 @@ pub trait ConversationApi {
         &mut self,
     ) -> impl std::future::Future<Output = Result<UserListPage, VonageClientError>>;
 
+    /// List Users
+    /// This function streams every user from the Vonage API, following the next page links
+    /// @param options The page size and the maximum number of users to yield
+    /// @return A stream of users
+    fn list_users(
+        &self,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<User, VonageClientError>> + Send + 'static;
+
     /// Create User
     /// This function creates a user in the Vonage API
     /// @param user The user to create
@@ -18,20 +34,187 @@ pub trait ConversationApi {
         &mut self,
         user: User,
     ) -> impl std::future::Future<Output = Result<User, VonageClientError>>;
+
+    /// List Legs
+    /// This function streams every leg of the application, following the next page links
+    /// @param options The page size and the maximum number of legs to yield
+    /// @return A stream of legs
+    fn list_legs(
+        &self,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<Leg, VonageClientError>> + Send + 'static;
+
+    /// Get Leg
+    /// This function gets a leg from the Vonage API
+    /// @param leg_id The ID of the leg
+    /// @return The leg
+    fn get_leg(
+        &mut self,
+        leg_id: &str,
+    ) -> impl std::future::Future<Output = Result<Leg, VonageClientError>>;
+
+    /// Delete Leg
+    /// This function deletes a leg, disconnecting it if it is still active
+    /// @param leg_id The ID of the leg
+    fn delete_leg(
+        &mut self,
+        leg_id: &str,
+    ) -> impl std::future::Future<Output = Result<(), VonageClientError>>;
+
+    /// List User Sessions
+    /// This function streams the client SDK sessions of a user, following the next page links
+    /// @param user_id The ID of the user
+    /// @param options The page size and the maximum number of sessions to yield
+    /// @return A stream of sessions
+    fn list_user_sessions(
+        &self,
+        user_id: &str,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<UserSession, VonageClientError>> + Send + 'static;
+
+    /// Delete Session
+    /// This function deletes a session, disconnecting the client SDK
+    /// @param session_id The ID of the session
+    fn delete_session(
+        &mut self,
+        session_id: &str,
+    ) -> impl std::future::Future<Output = Result<(), VonageClientError>>;
 }
 
 impl ConversationApi for VonageClient {
     async fn get_users(&mut self) -> Result<UserListPage, VonageClientError> {
         log::debug!("Getting users");
-        self.get("/v1/users")
+        self.get(ApiPath::new(ApiFamily::Conversation, "/v1/users"))
             .await
             .map(Self::debug_response("Get Users Response".into()))
     }
 
+    fn list_users(
+        &self,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<User, VonageClientError>> + Send + 'static {
+        log::debug!("Listing users");
+        self.paginate::<UserListPage>(ApiPath::new(ApiFamily::Conversation, "/v1/users"), options)
+    }
+
     async fn create_user(&mut self, user: User) -> Result<User, VonageClientError> {
         log::debug!("Creating user: {:?}", user);
-        self.post("/v1/users", user)
+        self.post(ApiPath::new(ApiFamily::Conversation, "/v1/users"), user)
             .await
             .map(Self::debug_response("Create User Response".into()))
     }
+
+    fn list_legs(
+        &self,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<Leg, VonageClientError>> + Send + 'static {
+        log::debug!("Listing legs");
+        self.paginate::<LegListPage>(ApiPath::new(ApiFamily::Conversation, "/v1/legs"), options)
+    }
+
+    async fn get_leg(&mut self, leg_id: &str) -> Result<Leg, VonageClientError> {
+        log::debug!("Getting leg: {}", leg_id);
+        let path =
+            ApiPath::new(ApiFamily::Conversation, "/v1/legs/{leg_id}").param("leg_id", leg_id);
+        self.get(path)
+            .await
+            .map(Self::debug_response("Get Leg Response".into()))
+    }
+
+    async fn delete_leg(&mut self, leg_id: &str) -> Result<(), VonageClientError> {
+        log::debug!("Deleting leg: {}", leg_id);
+        let path =
+            ApiPath::new(ApiFamily::Conversation, "/v1/legs/{leg_id}").param("leg_id", leg_id);
+        self.delete(path).await
+    }
+
+    fn list_user_sessions(
+        &self,
+        user_id: &str,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<UserSession, VonageClientError>> + Send + 'static {
+        log::debug!("Listing sessions of user: {}", user_id);
+        let path = ApiPath::new(ApiFamily::Conversation, "/v1/users/{user_id}/sessions")
+            .param("user_id", user_id);
+        self.paginate::<UserSessionListPage>(path, options)
+    }
+
+    async fn delete_session(&mut self, session_id: &str) -> Result<(), VonageClientError> {
+        log::debug!("Deleting session: {}", session_id);
+        let path = ApiPath::new(ApiFamily::Conversation, "/v1/sessions/{session_id}")
+            .param("session_id", session_id);
+        self.delete(path).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use crate::client::mock::MockServer;
+    use futures::TryStreamExt;
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    #[tokio::test]
+    async fn find_and_delete_stuck_legs() {
+        init();
+        let server = MockServer::default()
+            .respond(200, r#"{"page_size":1,"_embedded":{"legs":[{"id":"leg-1","type":"phone","direction":"outbound","status":"answered","conversation_id":"CON-1","from":{"type":"phone","number":"447700900000"}}]},"_links":{"next":{"href":"https://api-us.vonage.com/v1/legs?cursor=abc"}}}"#)
+            .respond(200, r#"{"page_size":1,"_embedded":{"legs":[{"id":"leg-2","type":"carrier","status":"completed"}]},"_links":{}}"#)
+            .respond(204, "");
+        let mut client = server.client();
+
+        let legs: Vec<Leg> = client
+            .list_legs(PageOptions::new().page_size(1))
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(legs.len(), 2);
+        assert_eq!(legs[0].leg_type, LegType::Phone);
+        assert_eq!(legs[0].direction, Some(LegDirection::Outbound));
+        assert_eq!(
+            legs[0].from.as_ref().unwrap().number.as_deref(),
+            Some("447700900000")
+        );
+        assert_eq!(legs[1].leg_type, LegType::Unknown);
+
+        let stuck: Vec<_> = legs
+            .iter()
+            .filter(|leg| !leg.status.is_finished())
+            .collect();
+        assert_eq!(stuck.len(), 1);
+        client.delete_leg(&stuck[0].id).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests[1].path_and_query(), "/v1/legs?cursor=abc");
+        assert_eq!(requests[2].method, reqwest::Method::DELETE);
+        assert_eq!(requests[2].path_and_query(), "/v1/legs/leg-1");
+    }
+
+    #[tokio::test]
+    async fn list_and_delete_user_sessions() {
+        init();
+        let server = MockServer::default()
+            .respond(200, r#"{"page_size":10,"_embedded":{"sessions":[{"id":"ORN-1","_embedded":{"user":{"id":"USR-1","name":"alice"},"api_key":"key"}}]},"_links":{}}"#)
+            .respond(204, "");
+        let mut client = server.client();
+
+        let sessions: Vec<UserSession> = client
+            .list_user_sessions("USR-1", PageOptions::new())
+            .try_collect()
+            .await
+            .unwrap();
+        let user = sessions[0].details.as_ref().unwrap().user.as_ref().unwrap();
+        assert_eq!(user.name.as_deref(), Some("alice"));
+        client.delete_session(&sessions[0].id).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path_and_query(), "/v1/users/USR-1/sessions");
+        assert_eq!(requests[1].path_and_query(), "/v1/sessions/ORN-1");
+    }
 }
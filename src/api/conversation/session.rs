@@ -0,0 +1,60 @@
+use crate::types::pages::{Links, Page, PageMeta, PageStyle};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct UserSessionList {
+    sessions: Vec<UserSession>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserSessionListPage {
+    #[serde(flatten)]
+    meta: PageMeta,
+    #[serde(rename = "_embedded")]
+    embedded: UserSessionList,
+}
+
+impl UserSessionListPage {
+    pub fn meta(&self) -> &PageMeta {
+        &self.meta
+    }
+
+    pub fn sessions(&self) -> &[UserSession] {
+        &self.embedded.sessions
+    }
+}
+
+impl Page for UserSessionListPage {
+    type Item = UserSession;
+    const STYLE: PageStyle = PageStyle::Cursor;
+
+    fn next_href(&self) -> Option<&str> {
+        self.meta.links().next().map(|link| link.href())
+    }
+
+    fn into_items(self) -> Vec<UserSession> {
+        self.embedded.sessions
+    }
+}
+
+/// User Session
+/// A client SDK connection made with a user token
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserSession {
+    pub id: String,
+    #[serde(rename = "_embedded")]
+    pub details: Option<UserSessionDetails>,
+    pub _links: Option<Links>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserSessionDetails {
+    pub user: Option<SessionUser>,
+    pub api_key: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SessionUser {
+    pub id: String,
+    pub name: Option<String>,
+}
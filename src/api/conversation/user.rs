@@ -1,4 +1,4 @@
-use crate::types::pages::{Links, PageMeta};
+use crate::types::pages::{Links, Page, PageMeta, PageStyle};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -10,8 +10,33 @@ pub struct UserList {
 
 #[derive(Debug, Deserialize)]
 pub struct UserListPage {
+    #[serde(flatten)]
     meta: PageMeta,
-    users: Vec<User>,
+    #[serde(rename = "_embedded")]
+    embedded: UserList,
+}
+
+impl UserListPage {
+    pub fn meta(&self) -> &PageMeta {
+        &self.meta
+    }
+
+    pub fn users(&self) -> &[User] {
+        &self.embedded.users
+    }
+}
+
+impl Page for UserListPage {
+    type Item = User;
+    const STYLE: PageStyle = PageStyle::Cursor;
+
+    fn next_href(&self) -> Option<&str> {
+        self.meta.links().next().map(|link| link.href())
+    }
+
+    fn into_items(self) -> Vec<User> {
+        self.embedded.users
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -1,10 +1,55 @@
+#[cfg(feature = "account")]
+pub mod account;
+#[cfg(feature = "applications")]
+pub mod applications;
 #[cfg(feature = "conversation")]
 pub mod conversation;
+#[cfg(feature = "number_insight")]
+pub mod number_insight;
+#[cfg(feature = "numbers")]
+pub mod numbers;
+#[cfg(feature = "pricing")]
+pub mod pricing;
+#[cfg(feature = "reports")]
+pub mod reports;
+#[cfg(feature = "sms")]
+pub mod sms;
+#[cfg(feature = "subaccounts")]
+pub mod subaccounts;
+#[cfg(feature = "video")]
+pub mod video;
 #[cfg(feature = "voice")]
 pub mod voice;
 
+#[cfg(feature = "account")]
+pub use account::*;
+
+#[cfg(feature = "applications")]
+pub use applications::*;
+
 #[cfg(feature = "conversation")]
 pub use conversation::*;
 
+#[cfg(feature = "number_insight")]
+pub use number_insight::*;
+
+#[cfg(feature = "numbers")]
+pub use numbers::*;
+
+#[cfg(feature = "pricing")]
+pub use pricing::*;
+
+#[cfg(feature = "reports")]
+pub use reports::*;
+
+#[cfg(feature = "sms")]
+pub use sms::*;
+
+#[cfg(feature = "subaccounts")]
+pub use subaccounts::*;
+
+#[cfg(feature = "video")]
+pub use video::*;
+
 #[cfg(feature = "voice")]
 pub use voice::*;
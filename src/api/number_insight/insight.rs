@@ -0,0 +1,459 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::client_types::redact_number;
+
+/// Insight Request
+/// The number to look up and the options for the lookup
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::number_insight::InsightRequest;
+/// let mut request = InsightRequest::new("447700900000".to_string());
+/// request.country("GB".to_string()).cnam(true);
+/// ```
+#[derive(Clone, Serialize)]
+pub struct InsightRequest {
+    number: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cnam: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    real_time_data: Option<bool>,
+}
+
+impl InsightRequest {
+    /// Create a new InsightRequest
+    /// @param number The number in international or local format
+    pub fn new(number: String) -> Self {
+        InsightRequest {
+            number,
+            country: None,
+            cnam: None,
+            real_time_data: None,
+        }
+    }
+
+    /// Set the two character country code, required when the number is in local format
+    pub fn country(&mut self, country: String) -> &mut Self {
+        self.country = Some(country);
+        self
+    }
+
+    /// Look up the caller name, standard and advanced lookups only, US numbers only
+    pub fn cnam(&mut self, cnam: bool) -> &mut Self {
+        self.cnam = Some(cnam);
+        self
+    }
+
+    /// Look up whether the handset is active, advanced lookups only
+    pub fn real_time_data(&mut self, real_time_data: bool) -> &mut Self {
+        self.real_time_data = Some(real_time_data);
+        self
+    }
+
+    pub fn number(&self) -> &str {
+        &self.number
+    }
+}
+
+impl std::fmt::Debug for InsightRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InsightRequest")
+            .field("number", &redact_number(&self.number))
+            .field("country", &self.country)
+            .field("cnam", &self.cnam)
+            .field("real_time_data", &self.real_time_data)
+            .finish()
+    }
+}
+
+/// Insight Status
+/// The status code of a lookup, the Number Insight API reports failures with a 200 response
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum InsightStatus {
+    Success,
+    Throttled,
+    InvalidParameters,
+    InvalidCredentials,
+    InternalError,
+    PartnerQuotaExceeded,
+    FacilityNotAllowed,
+    /// The live mobile lookup failed, with codes 43, 44 and 45
+    LiveLookupFailed(u8),
+    Other(u8),
+}
+
+impl InsightStatus {
+    pub fn is_success(&self) -> bool {
+        *self == InsightStatus::Success
+    }
+}
+
+impl From<u8> for InsightStatus {
+    fn from(code: u8) -> Self {
+        match code {
+            0 => InsightStatus::Success,
+            1 => InsightStatus::Throttled,
+            3 => InsightStatus::InvalidParameters,
+            4 => InsightStatus::InvalidCredentials,
+            5 => InsightStatus::InternalError,
+            9 => InsightStatus::PartnerQuotaExceeded,
+            19 => InsightStatus::FacilityNotAllowed,
+            43..=45 => InsightStatus::LiveLookupFailed(code),
+            code => InsightStatus::Other(code),
+        }
+    }
+}
+
+impl From<InsightStatus> for u8 {
+    fn from(status: InsightStatus) -> Self {
+        match status {
+            InsightStatus::Success => 0,
+            InsightStatus::Throttled => 1,
+            InsightStatus::InvalidParameters => 3,
+            InsightStatus::InvalidCredentials => 4,
+            InsightStatus::InternalError => 5,
+            InsightStatus::PartnerQuotaExceeded => 9,
+            InsightStatus::FacilityNotAllowed => 19,
+            InsightStatus::LiveLookupFailed(code) | InsightStatus::Other(code) => code,
+        }
+    }
+}
+
+/// Basic Insight Response
+/// Local and international formats of the number and the country it belongs to
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BasicInsightResponse {
+    pub status: InsightStatus,
+    pub status_message: String,
+    pub request_id: Option<String>,
+    pub international_format_number: Option<String>,
+    pub national_format_number: Option<String>,
+    pub country_code: Option<String>,
+    pub country_code_iso3: Option<String>,
+    pub country_name: Option<String>,
+    pub country_prefix: Option<String>,
+}
+
+impl std::fmt::Debug for BasicInsightResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BasicInsightResponse")
+            .field("status", &self.status)
+            .field("status_message", &self.status_message)
+            .field("request_id", &self.request_id)
+            .field(
+                "international_format_number",
+                &self
+                    .international_format_number
+                    .as_deref()
+                    .map(redact_number),
+            )
+            .field(
+                "national_format_number",
+                &self.national_format_number.as_deref().map(redact_number),
+            )
+            .field("country_code", &self.country_code)
+            .field("country_code_iso3", &self.country_code_iso3)
+            .field("country_name", &self.country_name)
+            .field("country_prefix", &self.country_prefix)
+            .finish()
+    }
+}
+
+/// Standard Insight Response
+/// The basic response along with the carrier, porting, roaming and caller name of the number
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StandardInsightResponse {
+    #[serde(flatten)]
+    pub basic: BasicInsightResponse,
+    pub request_price: Option<String>,
+    pub refund_price: Option<String>,
+    pub remaining_balance: Option<String>,
+    pub current_carrier: Option<Carrier>,
+    pub original_carrier: Option<Carrier>,
+    pub ported: Option<PortedStatus>,
+    pub roaming: Option<Roaming>,
+    pub caller_identity: Option<CallerIdentity>,
+}
+
+/// Advanced Insight Response
+/// The standard response along with the validity and reachability of the number
+///
+/// This is also the payload sent to the callback of an async advanced lookup
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdvancedInsightResponse {
+    #[serde(flatten)]
+    pub standard: StandardInsightResponse,
+    pub lookup_outcome: Option<LookupOutcome>,
+    pub lookup_outcome_message: Option<String>,
+    pub valid_number: Option<ValidNumber>,
+    pub reachable: Option<Reachable>,
+    pub real_time_data: Option<RealTimeData>,
+}
+
+impl AdvancedInsightResponse {
+    /// The number is valid and the network has not reported it as unreachable
+    pub fn is_dialable(&self) -> bool {
+        self.standard.basic.status.is_success()
+            && self.valid_number != Some(ValidNumber::NotValid)
+            && !matches!(
+                self.reachable,
+                Some(Reachable::Undeliverable | Reachable::BadNumber | Reachable::Blacklisted)
+            )
+    }
+}
+
+/// Advanced Insight Callback
+/// The payload sent to the callback URL of an async advanced lookup
+pub type AdvancedInsightCallback = AdvancedInsightResponse;
+
+/// Async Insight Response
+/// The acknowledgement of an async advanced lookup, the result is sent to the callback URL
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AsyncInsightResponse {
+    pub status: InsightStatus,
+    pub request_id: Option<String>,
+    pub number: Option<String>,
+    pub remaining_balance: Option<String>,
+    pub request_price: Option<String>,
+    pub error_text: Option<String>,
+}
+
+impl std::fmt::Debug for AsyncInsightResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncInsightResponse")
+            .field("status", &self.status)
+            .field("request_id", &self.request_id)
+            .field("number", &self.number.as_deref().map(redact_number))
+            .field("remaining_balance", &self.remaining_balance)
+            .field("request_price", &self.request_price)
+            .field("error_text", &self.error_text)
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Carrier {
+    pub network_code: Option<String>,
+    pub name: Option<String>,
+    pub country: Option<String>,
+    pub network_type: Option<NetworkType>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkType {
+    Mobile,
+    Landline,
+    LandlinePremium,
+    LandlineTollfree,
+    Virtual,
+    Pager,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PortedStatus {
+    Ported,
+    NotPorted,
+    AssumedPorted,
+    AssumedNotPorted,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Roaming {
+    pub status: RoamingStatus,
+    pub roaming_country_code: Option<String>,
+    pub roaming_network_code: Option<String>,
+    pub roaming_network_name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoamingStatus {
+    Roaming,
+    NotRoaming,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CallerIdentity {
+    pub caller_type: Option<CallerType>,
+    pub caller_name: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+}
+
+/// The names of the caller are redacted, only whether they are known is shown
+impl std::fmt::Debug for CallerIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let redact = |name: &Option<String>| name.as_ref().map(|_| "<********>");
+        f.debug_struct("CallerIdentity")
+            .field("caller_type", &self.caller_type)
+            .field("caller_name", &redact(&self.caller_name))
+            .field("first_name", &redact(&self.first_name))
+            .field("last_name", &redact(&self.last_name))
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CallerType {
+    Business,
+    Consumer,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "u8", into = "u8")]
+pub enum LookupOutcome {
+    Success,
+    PartialSuccess,
+    Failed,
+}
+
+impl From<u8> for LookupOutcome {
+    fn from(code: u8) -> Self {
+        match code {
+            0 => LookupOutcome::Success,
+            1 => LookupOutcome::PartialSuccess,
+            _ => LookupOutcome::Failed,
+        }
+    }
+}
+
+impl From<LookupOutcome> for u8 {
+    fn from(outcome: LookupOutcome) -> Self {
+        match outcome {
+            LookupOutcome::Success => 0,
+            LookupOutcome::PartialSuccess => 1,
+            LookupOutcome::Failed => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidNumber {
+    Valid,
+    NotValid,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Reachable {
+    Reachable,
+    Undeliverable,
+    Absent,
+    BadNumber,
+    Blacklisted,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RealTimeData {
+    pub active_status: Option<String>,
+    pub handset_status: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_advanced_callback() {
+        let payload = r#"{
+            "status": 0,
+            "status_message": "Success",
+            "lookup_outcome": 0,
+            "lookup_outcome_message": "Success",
+            "request_id": "aaaaaaaa-bbbb-cccc-dddd-0123456789ab",
+            "international_format_number": "447700900000",
+            "national_format_number": "07700 900000",
+            "country_code": "GB",
+            "country_code_iso3": "GBR",
+            "country_name": "United Kingdom",
+            "country_prefix": "44",
+            "request_price": "0.04000000",
+            "remaining_balance": "1.23456789",
+            "current_carrier": {
+                "network_code": "12345",
+                "name": "Acme Inc",
+                "country": "GB",
+                "network_type": "mobile"
+            },
+            "original_carrier": {
+                "network_code": "12345",
+                "name": "Acme Inc",
+                "country": "GB",
+                "network_type": "mobile"
+            },
+            "valid_number": "valid",
+            "reachable": "reachable",
+            "ported": "not_ported",
+            "roaming": { "status": "not_roaming" },
+            "real_time_data": { "active_status": "true", "handset_status": "On" },
+            "ip_warnings": "unknown"
+        }"#;
+        let callback: AdvancedInsightCallback = serde_json::from_str(payload).unwrap();
+        assert!(callback.is_dialable());
+        assert_eq!(callback.standard.basic.country_code.as_deref(), Some("GB"));
+        assert_eq!(callback.standard.ported, Some(PortedStatus::NotPorted));
+        assert_eq!(
+            callback.standard.current_carrier.unwrap().network_type,
+            Some(NetworkType::Mobile)
+        );
+        assert_eq!(callback.lookup_outcome, Some(LookupOutcome::Success));
+    }
+
+    #[test]
+    fn deserialize_failed_lookup() {
+        let payload = r#"{"status": 3, "status_message": "Invalid request :: number is missing"}"#;
+        let response: StandardInsightResponse = serde_json::from_str(payload).unwrap();
+        assert_eq!(response.basic.status, InsightStatus::InvalidParameters);
+        assert!(response.current_carrier.is_none());
+
+        let status: InsightStatus = serde_json::from_str("44").unwrap();
+        assert_eq!(status, InsightStatus::LiveLookupFailed(44));
+        assert_eq!(serde_json::to_string(&status).unwrap(), "44");
+    }
+
+    #[test]
+    fn request_debug_redacts_number() {
+        let request = InsightRequest::new("447700900123".to_string());
+        assert!(format!("{:?}", request).contains("********0123"));
+    }
+
+    #[test]
+    fn response_debug_redacts_numbers_and_names() {
+        let payload = r#"{
+            "status": 0,
+            "status_message": "Success",
+            "international_format_number": "447700900123",
+            "national_format_number": "07700 900123",
+            "caller_identity": {"caller_type": "consumer", "first_name": "Alice", "last_name": "Smith"}
+        }"#;
+        let response: AdvancedInsightResponse = serde_json::from_str(payload).unwrap();
+        let debug = format!("{:?}", response);
+        assert!(debug.contains("********0123"));
+        assert!(!debug.contains("447700900123"));
+        assert!(!debug.contains("07700 900123"));
+        assert!(!debug.contains("Alice") && !debug.contains("Smith"));
+
+        let response: AsyncInsightResponse =
+            serde_json::from_str(r#"{"status": 0, "number": "447700900123"}"#).unwrap();
+        assert!(!format!("{:?}", response).contains("447700900123"));
+    }
+}
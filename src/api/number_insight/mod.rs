@@ -0,0 +1,149 @@
+mod insight;
+
+use async_trait::async_trait;
+pub use insight::*;
+
+use crate::client::path::ApiPath;
+use crate::client::{ApiFamily, VonageClient, VonageClientError};
+
+#[async_trait]
+pub trait NumberInsightApi {
+    /// Basic Number Insight
+    /// This function looks up the format and country of a number, this lookup is free
+    /// @param request The number to look up
+    /// @return The basic insight of the number
+    async fn basic_insight(
+        &mut self,
+        request: &InsightRequest,
+    ) -> Result<BasicInsightResponse, VonageClientError>;
+
+    /// Standard Number Insight
+    /// This function looks up the carrier, porting and roaming status of a number
+    /// @param request The number to look up
+    /// @return The standard insight of the number
+    async fn standard_insight(
+        &mut self,
+        request: &InsightRequest,
+    ) -> Result<StandardInsightResponse, VonageClientError>;
+
+    /// Advanced Number Insight
+    /// This function looks up the validity and reachability of a number
+    /// @param request The number to look up
+    /// @return The advanced insight of the number
+    async fn advanced_insight(
+        &mut self,
+        request: &InsightRequest,
+    ) -> Result<AdvancedInsightResponse, VonageClientError>;
+
+    /// Advanced Number Insight (Async)
+    /// This function requests an advanced lookup, the result is sent to the callback as an `AdvancedInsightCallback`
+    /// @param request The number to look up
+    /// @param callback The URL the result is sent to
+    /// @return The acknowledgement of the request
+    async fn advanced_insight_async(
+        &mut self,
+        request: &InsightRequest,
+        callback: String,
+    ) -> Result<AsyncInsightResponse, VonageClientError>;
+}
+
+#[async_trait]
+impl NumberInsightApi for VonageClient {
+    async fn basic_insight(
+        &mut self,
+        request: &InsightRequest,
+    ) -> Result<BasicInsightResponse, VonageClientError> {
+        log::debug!("Getting basic number insight: {:?}", request);
+        let path = ApiPath::new(ApiFamily::Api, "/ni/basic/json")
+            .basic_auth()
+            .query(request);
+        self.get(path)
+            .await
+            .map(Self::debug_response("Basic Number Insight Response".into()))
+    }
+
+    async fn standard_insight(
+        &mut self,
+        request: &InsightRequest,
+    ) -> Result<StandardInsightResponse, VonageClientError> {
+        log::debug!("Getting standard number insight: {:?}", request);
+        let path = ApiPath::new(ApiFamily::Api, "/ni/standard/json")
+            .basic_auth()
+            .query(request);
+        self.get(path).await.map(Self::debug_response(
+            "Standard Number Insight Response".into(),
+        ))
+    }
+
+    async fn advanced_insight(
+        &mut self,
+        request: &InsightRequest,
+    ) -> Result<AdvancedInsightResponse, VonageClientError> {
+        log::debug!("Getting advanced number insight: {:?}", request);
+        let path = ApiPath::new(ApiFamily::Api, "/ni/advanced/json")
+            .basic_auth()
+            .query(request);
+        self.get(path).await.map(Self::debug_response(
+            "Advanced Number Insight Response".into(),
+        ))
+    }
+
+    async fn advanced_insight_async(
+        &mut self,
+        request: &InsightRequest,
+        callback: String,
+    ) -> Result<AsyncInsightResponse, VonageClientError> {
+        log::debug!("Requesting async advanced number insight: {:?}", request);
+        let path = ApiPath::new(ApiFamily::Api, "/ni/advanced/async/json")
+            .basic_auth()
+            .query(request)
+            .query(&[("callback", callback)]);
+        self.get(path)
+            .await
+            .map(Self::debug_response("Async Number Insight Response".into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use crate::client::mock::MockServer;
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    #[tokio::test]
+    async fn advanced_insight_async_request() {
+        init();
+        let server = MockServer::default().respond(
+            200,
+            r#"{"request_id":"abc","number":"447700900000","remaining_balance":"1.0","request_price":"0.04","status":0}"#,
+        );
+        let mut request = InsightRequest::new("447700900000".to_string());
+        request.real_time_data(true);
+
+        let response = server
+            .client()
+            .advanced_insight_async(&request, "https://example.com/insight".to_string())
+            .await
+            .unwrap();
+        assert!(response.status.is_success());
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, reqwest::Method::GET);
+        assert!(requests[0].body.is_none());
+        assert_eq!(
+            requests[0].url.as_str(),
+            "https://api.nexmo.com/ni/advanced/async/json?number=447700900000&real_time_data=true&callback=https%3A%2F%2Fexample.com%2Finsight"
+        );
+        // Basic auth with the API key and secret, never the JWT
+        assert_eq!(
+            requests[0].headers.get("authorization").unwrap(),
+            "Basic a2V5OnNlY3JldA=="
+        );
+    }
+}
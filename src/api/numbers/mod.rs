@@ -0,0 +1,202 @@
+mod number;
+
+use async_trait::async_trait;
+use futures::Stream;
+pub use number::*;
+
+use crate::client::path::ApiPath;
+use crate::client::{ApiFamily, VonageClient, VonageClientError};
+use crate::types::pages::PageOptions;
+
+#[async_trait]
+pub trait NumbersApi {
+    /// Search Numbers
+    /// This function searches the numbers available to buy
+    /// @param search The search criteria
+    /// @return A page of available numbers
+    async fn search_numbers(
+        &mut self,
+        search: &NumberSearch,
+    ) -> Result<NumberSearchResponse, VonageClientError>;
+
+    /// Buy Number
+    /// This function buys a number found by `search_numbers`
+    /// @param number The number to buy
+    /// @return The outcome of the purchase
+    async fn buy_number(
+        &mut self,
+        number: &NumberRequest,
+    ) -> Result<NumbersResponse, VonageClientError>;
+
+    /// Cancel Number
+    /// This function cancels the rental of a number owned by the account
+    /// @param number The number to cancel
+    /// @return The outcome of the cancellation
+    async fn cancel_number(
+        &mut self,
+        number: &NumberRequest,
+    ) -> Result<NumbersResponse, VonageClientError>;
+
+    /// Update Number
+    /// This function links a number to an application and sets its webhooks
+    /// @param update The number and its new settings
+    /// @return The outcome of the update
+    async fn update_number(
+        &mut self,
+        update: &UpdateNumber,
+    ) -> Result<NumbersResponse, VonageClientError>;
+
+    /// List Owned Numbers
+    /// This function streams every number owned by the account
+    /// @param filter The filter to apply to the list
+    /// @param options The page size, at most 100, and the maximum number of numbers to yield
+    /// @return A stream of owned numbers
+    fn list_owned_numbers(
+        &self,
+        filter: &OwnedNumberFilter,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<OwnedNumber, VonageClientError>> + Send + 'static;
+}
+
+#[async_trait]
+impl NumbersApi for VonageClient {
+    async fn search_numbers(
+        &mut self,
+        search: &NumberSearch,
+    ) -> Result<NumberSearchResponse, VonageClientError> {
+        log::debug!("Searching numbers: {:?}", search);
+        let path = ApiPath::new(ApiFamily::Rest, "/number/search")
+            .basic_auth()
+            .query(search);
+        self.get(path)
+            .await
+            .map(Self::debug_response("Search Numbers Response".into()))
+    }
+
+    async fn buy_number(
+        &mut self,
+        number: &NumberRequest,
+    ) -> Result<NumbersResponse, VonageClientError> {
+        log::debug!("Buying number: {:?}", number);
+        let path = ApiPath::new(ApiFamily::Rest, "/number/buy").basic_auth();
+        self.post_form(path, number)
+            .await
+            .map(Self::debug_response("Buy Number Response".into()))
+    }
+
+    async fn cancel_number(
+        &mut self,
+        number: &NumberRequest,
+    ) -> Result<NumbersResponse, VonageClientError> {
+        log::debug!("Cancelling number: {:?}", number);
+        let path = ApiPath::new(ApiFamily::Rest, "/number/cancel").basic_auth();
+        self.post_form(path, number)
+            .await
+            .map(Self::debug_response("Cancel Number Response".into()))
+    }
+
+    async fn update_number(
+        &mut self,
+        update: &UpdateNumber,
+    ) -> Result<NumbersResponse, VonageClientError> {
+        log::debug!("Updating number: {:?}", update);
+        let path = ApiPath::new(ApiFamily::Rest, "/number/update").basic_auth();
+        self.post_form(path, update)
+            .await
+            .map(Self::debug_response("Update Number Response".into()))
+    }
+
+    fn list_owned_numbers(
+        &self,
+        filter: &OwnedNumberFilter,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<OwnedNumber, VonageClientError>> + Send + 'static {
+        log::debug!("Listing owned numbers: {:?}", filter);
+        let path = ApiPath::new(ApiFamily::Rest, "/account/numbers")
+            .basic_auth()
+            .query(filter);
+        self.paginate::<OwnedNumberPage>(path, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use crate::client::mock::MockServer;
+    use futures::TryStreamExt;
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    #[tokio::test]
+    async fn buy_and_link_number() {
+        init();
+        let server = MockServer::default()
+            .respond(200, r#"{"error-code":"200","error-code-label":"success"}"#)
+            .respond(200, r#"{"error-code":"200","error-code-label":"success"}"#);
+        let mut client = server.client();
+
+        let bought = client
+            .buy_number(&NumberRequest::new(
+                "GB".to_string(),
+                "447700900000".to_string(),
+            ))
+            .await
+            .unwrap();
+        assert!(bought.is_success());
+
+        let mut update = UpdateNumber::new("GB".to_string(), "447700900000".to_string());
+        update.app_id("app-id".to_string());
+        client.update_number(&update).await.unwrap();
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].url.as_str(),
+            "https://rest.nexmo.com/number/buy"
+        );
+        assert_eq!(
+            requests[0].headers.get("content-type").unwrap(),
+            "application/x-www-form-urlencoded"
+        );
+        assert_eq!(
+            requests[0].body.as_deref(),
+            Some("country=GB&msisdn=447700900000")
+        );
+        assert_eq!(
+            requests[1].body.as_deref(),
+            Some("country=GB&msisdn=447700900000&app_id=app-id")
+        );
+    }
+
+    #[tokio::test]
+    async fn list_owned_numbers_pages() {
+        init();
+        let server = MockServer::default()
+            .respond(
+                200,
+                r#"{"count":2,"numbers":[{"country":"GB","msisdn":"447700900000"}]}"#,
+            )
+            .respond(
+                200,
+                r#"{"count":2,"numbers":[{"country":"GB","msisdn":"447700900001"}]}"#,
+            );
+        let mut filter = OwnedNumberFilter::new();
+        filter.has_application(false);
+
+        let numbers: Vec<OwnedNumber> = server
+            .client()
+            .list_owned_numbers(&filter, PageOptions::new().page_size(1))
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(numbers.len(), 2);
+        assert_eq!(
+            server.requests()[1].path_and_query(),
+            "/account/numbers?has_application=false&size=1&index=2"
+        );
+    }
+}
@@ -0,0 +1,486 @@
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::types::pages::{Page, PageStyle};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NumberType {
+    Landline,
+    MobileLvn,
+    LandlineTollFree,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum NumberFeature {
+    Sms,
+    Voice,
+    Mms,
+    #[serde(other)]
+    Unknown,
+}
+
+impl NumberFeature {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NumberFeature::Sms => "SMS",
+            NumberFeature::Voice => "VOICE",
+            NumberFeature::Mms => "MMS",
+            NumberFeature::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+/// Search Pattern
+/// Where the pattern of a search must appear in the number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(into = "u8")]
+pub enum SearchPattern {
+    StartsWith,
+    Contains,
+    EndsWith,
+}
+
+impl From<SearchPattern> for u8 {
+    fn from(pattern: SearchPattern) -> Self {
+        match pattern {
+            SearchPattern::StartsWith => 0,
+            SearchPattern::Contains => 1,
+            SearchPattern::EndsWith => 2,
+        }
+    }
+}
+
+/// The Numbers API takes features as a comma separated list
+fn join_features<S: Serializer>(
+    features: &Option<Vec<NumberFeature>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match features {
+        Some(features) => serializer.serialize_str(
+            &features
+                .iter()
+                .map(NumberFeature::as_str)
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Number Search
+/// The criteria for a search of the numbers available to buy
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::numbers::{NumberFeature, NumberSearch, NumberType, SearchPattern};
+/// let mut search = NumberSearch::new("GB".to_string());
+/// search
+///     .number_type(NumberType::MobileLvn)
+///     .features(vec![NumberFeature::Sms, NumberFeature::Voice])
+///     .pattern("7700".to_string(), SearchPattern::StartsWith);
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct NumberSearch {
+    country: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    number_type: Option<NumberType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pattern: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    search_pattern: Option<SearchPattern>,
+    #[serde(
+        serialize_with = "join_features",
+        skip_serializing_if = "Option::is_none"
+    )]
+    features: Option<Vec<NumberFeature>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<usize>,
+}
+
+impl NumberSearch {
+    /// Create a new NumberSearch
+    /// @param country The two character country code
+    pub fn new(country: String) -> Self {
+        NumberSearch {
+            country,
+            number_type: None,
+            pattern: None,
+            search_pattern: None,
+            features: None,
+            size: None,
+            index: None,
+        }
+    }
+
+    pub fn number_type(&mut self, number_type: NumberType) -> &mut Self {
+        self.number_type = Some(number_type);
+        self
+    }
+
+    /// Only return numbers that support all of these features
+    pub fn features(&mut self, features: Vec<NumberFeature>) -> &mut Self {
+        self.features = Some(features);
+        self
+    }
+
+    /// Only return numbers matching a pattern
+    /// @param pattern The digits to match
+    /// @param search_pattern Where the digits must appear in the number
+    pub fn pattern(&mut self, pattern: String, search_pattern: SearchPattern) -> &mut Self {
+        self.pattern = Some(pattern);
+        self.search_pattern = Some(search_pattern);
+        self
+    }
+
+    /// Set the page size, at most 100
+    pub fn size(&mut self, size: usize) -> &mut Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Set the 1-based page index
+    pub fn index(&mut self, index: usize) -> &mut Self {
+        self.index = Some(index);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NumberSearchResponse {
+    pub count: usize,
+    #[serde(default)]
+    pub numbers: Vec<AvailableNumber>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvailableNumber {
+    pub country: String,
+    pub msisdn: String,
+    #[serde(rename = "type")]
+    pub number_type: Option<NumberType>,
+    pub cost: Option<String>,
+    #[serde(default)]
+    pub features: Vec<NumberFeature>,
+}
+
+/// Number Request
+/// Identifies a number to buy or cancel
+#[derive(Debug, Clone, Serialize)]
+pub struct NumberRequest {
+    country: String,
+    msisdn: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_api_key: Option<String>,
+}
+
+impl NumberRequest {
+    /// Create a new NumberRequest
+    /// @param country The two character country code of the number
+    /// @param msisdn The number in E.164 format without the leading +
+    pub fn new(country: String, msisdn: String) -> Self {
+        NumberRequest {
+            country,
+            msisdn,
+            target_api_key: None,
+        }
+    }
+
+    /// Buy or cancel the number on behalf of a subaccount
+    pub fn target_api_key(&mut self, target_api_key: String) -> &mut Self {
+        self.target_api_key = Some(target_api_key);
+        self
+    }
+}
+
+/// Numbers Response
+/// The outcome of a buy, cancel or update request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NumbersResponse {
+    #[serde(rename = "error-code")]
+    pub error_code: String,
+    #[serde(rename = "error-code-label")]
+    pub error_code_label: String,
+}
+
+impl NumbersResponse {
+    pub fn is_success(&self) -> bool {
+        self.error_code == "200"
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VoiceCallbackType {
+    App,
+    Sip,
+    Tel,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Update Number
+/// Links a number to an application and sets its webhooks, fields that are not set are cleared
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::numbers::UpdateNumber;
+/// let mut update = UpdateNumber::new("GB".to_string(), "447700900000".to_string());
+/// update
+///     .app_id("aaaaaaaa-bbbb-cccc-dddd-0123456789ab".to_string())
+///     .inbound_sms_url("https://example.com/webhooks/inbound-sms".to_string());
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateNumber {
+    country: String,
+    msisdn: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    app_id: Option<String>,
+    #[serde(rename = "moHttpUrl", skip_serializing_if = "Option::is_none")]
+    mo_http_url: Option<String>,
+    #[serde(rename = "moSmppSysType", skip_serializing_if = "Option::is_none")]
+    mo_smpp_sys_type: Option<String>,
+    #[serde(
+        rename = "messagesCallbackType",
+        skip_serializing_if = "Option::is_none"
+    )]
+    messages_callback_type: Option<String>,
+    #[serde(
+        rename = "messagesCallbackValue",
+        skip_serializing_if = "Option::is_none"
+    )]
+    messages_callback_value: Option<String>,
+    #[serde(rename = "voiceCallbackType", skip_serializing_if = "Option::is_none")]
+    voice_callback_type: Option<VoiceCallbackType>,
+    #[serde(rename = "voiceCallbackValue", skip_serializing_if = "Option::is_none")]
+    voice_callback_value: Option<String>,
+    #[serde(
+        rename = "voiceStatusCallback",
+        skip_serializing_if = "Option::is_none"
+    )]
+    voice_status_callback: Option<String>,
+}
+
+impl UpdateNumber {
+    /// Create a new UpdateNumber
+    /// @param country The two character country code of the number
+    /// @param msisdn The number in E.164 format without the leading +
+    pub fn new(country: String, msisdn: String) -> Self {
+        UpdateNumber {
+            country,
+            msisdn,
+            app_id: None,
+            mo_http_url: None,
+            mo_smpp_sys_type: None,
+            messages_callback_type: None,
+            messages_callback_value: None,
+            voice_callback_type: None,
+            voice_callback_value: None,
+            voice_status_callback: None,
+        }
+    }
+
+    /// Link the number to an application, voice calls and messages use the webhooks of the application
+    pub fn app_id(&mut self, app_id: String) -> &mut Self {
+        self.app_id = Some(app_id);
+        self
+    }
+
+    /// Send inbound SMS for the number to a webhook
+    pub fn inbound_sms_url(&mut self, url: String) -> &mut Self {
+        self.mo_http_url = Some(url);
+        self
+    }
+
+    /// Set the SMPP system type inbound SMS are sent with, for SMPP connections only
+    pub fn mo_smpp_sys_type(&mut self, sys_type: String) -> &mut Self {
+        self.mo_smpp_sys_type = Some(sys_type);
+        self
+    }
+
+    /// Route inbound messages to an application other than the one set by `app_id`
+    pub fn messages_app_id(&mut self, app_id: String) -> &mut Self {
+        self.messages_callback_type = Some("app".to_string());
+        self.messages_callback_value = Some(app_id);
+        self
+    }
+
+    /// Route inbound calls to an application, SIP URI or phone number
+    pub fn voice_callback(
+        &mut self,
+        callback_type: VoiceCallbackType,
+        callback_value: String,
+    ) -> &mut Self {
+        self.voice_callback_type = Some(callback_type);
+        self.voice_callback_value = Some(callback_value);
+        self
+    }
+
+    /// Send call events for the number to a webhook
+    pub fn voice_status_callback(&mut self, url: String) -> &mut Self {
+        self.voice_status_callback = Some(url);
+        self
+    }
+}
+
+/// Owned Number Filter
+/// Narrows the list of numbers owned by the account
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct OwnedNumberFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    application_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_application: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pattern: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    search_pattern: Option<SearchPattern>,
+}
+
+impl OwnedNumberFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only list numbers linked to an application
+    pub fn application_id(&mut self, application_id: String) -> &mut Self {
+        self.application_id = Some(application_id);
+        self
+    }
+
+    /// Only list numbers that are, or are not, linked to any application
+    pub fn has_application(&mut self, has_application: bool) -> &mut Self {
+        self.has_application = Some(has_application);
+        self
+    }
+
+    pub fn country(&mut self, country: String) -> &mut Self {
+        self.country = Some(country);
+        self
+    }
+
+    /// Only list numbers matching a pattern
+    pub fn pattern(&mut self, pattern: String, search_pattern: SearchPattern) -> &mut Self {
+        self.pattern = Some(pattern);
+        self.search_pattern = Some(search_pattern);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OwnedNumber {
+    pub country: String,
+    pub msisdn: String,
+    #[serde(rename = "type")]
+    pub number_type: Option<NumberType>,
+    #[serde(default)]
+    pub features: Vec<NumberFeature>,
+    pub app_id: Option<String>,
+    #[serde(rename = "moHttpUrl")]
+    pub mo_http_url: Option<String>,
+    #[serde(rename = "messagesCallbackType")]
+    pub messages_callback_type: Option<String>,
+    #[serde(rename = "messagesCallbackValue")]
+    pub messages_callback_value: Option<String>,
+    #[serde(rename = "voiceCallbackType")]
+    pub voice_callback_type: Option<VoiceCallbackType>,
+    #[serde(rename = "voiceCallbackValue")]
+    pub voice_callback_value: Option<String>,
+    #[serde(rename = "voiceStatusCallback")]
+    pub voice_status_callback: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OwnedNumberPage {
+    count: usize,
+    #[serde(default)]
+    numbers: Vec<OwnedNumber>,
+}
+
+impl Page for OwnedNumberPage {
+    type Item = OwnedNumber;
+    const STYLE: PageStyle = PageStyle::RecordIndex;
+    const PAGE_SIZE_PARAM: &'static str = "size";
+
+    fn total(&self) -> Option<usize> {
+        Some(self.count)
+    }
+
+    fn into_items(self) -> Vec<OwnedNumber> {
+        self.numbers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_search() {
+        let mut search = NumberSearch::new("GB".to_string());
+        search
+            .number_type(NumberType::MobileLvn)
+            .features(vec![NumberFeature::Sms, NumberFeature::Voice])
+            .pattern("7700".to_string(), SearchPattern::StartsWith)
+            .size(10);
+        assert_eq!(
+            serde_urlencoded::to_string(&search).unwrap(),
+            "country=GB&type=mobile-lvn&pattern=7700&search_pattern=0&features=SMS%2CVOICE&size=10"
+        );
+    }
+
+    #[test]
+    fn deserialize_owned_numbers() {
+        let page = r#"{
+            "count": 1,
+            "numbers": [{
+                "country": "GB",
+                "msisdn": "447700900000",
+                "moHttpUrl": "https://example.com/webhooks/inbound-sms",
+                "type": "mobile-lvn",
+                "features": ["VOICE", "SMS", "MMS"],
+                "messagesCallbackType": "app",
+                "messagesCallbackValue": "aaaaaaaa-bbbb-cccc-dddd-0123456789ab",
+                "voiceCallbackType": "app",
+                "voiceCallbackValue": "aaaaaaaa-bbbb-cccc-dddd-0123456789ab",
+                "app_id": "aaaaaaaa-bbbb-cccc-dddd-0123456789ab"
+            }]
+        }"#;
+        let page: OwnedNumberPage = serde_json::from_str(page).unwrap();
+        assert_eq!(page.total(), Some(1));
+        let number = &page.into_items()[0];
+        assert_eq!(number.number_type, Some(NumberType::MobileLvn));
+        assert_eq!(number.voice_callback_type, Some(VoiceCallbackType::App));
+        assert_eq!(number.features.len(), 3);
+    }
+
+    #[test]
+    fn deserialize_unknown_voice_callback_type() {
+        let page = r#"{
+            "count": 2,
+            "numbers": [
+                {"country": "GB", "msisdn": "447700900000", "voiceCallbackType": "vxml"},
+                {"country": "GB", "msisdn": "447700900001", "voiceCallbackType": "sip"}
+            ]
+        }"#;
+        let page: OwnedNumberPage = serde_json::from_str(page).unwrap();
+        let types: Vec<_> = page
+            .into_items()
+            .into_iter()
+            .map(|number| number.voice_callback_type)
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                Some(VoiceCallbackType::Unknown),
+                Some(VoiceCallbackType::Sip)
+            ]
+        );
+    }
+}
@@ -0,0 +1,145 @@
+mod price;
+
+use async_trait::async_trait;
+pub use price::{CountryPricing, NetworkPrice, PricingList, PricingType};
+use price::{CountryQuery, PrefixQuery};
+
+use crate::client::path::ApiPath;
+use crate::client::{ApiFamily, VonageClient, VonageClientError};
+
+#[async_trait]
+pub trait PricingApi {
+    /// Get Country Pricing
+    /// This function gets the outbound prices of a country
+    /// @param pricing_type The service to price
+    /// @param country The two character country code, e.g. `GB`
+    /// @return The prices of the country and its networks
+    async fn get_country_pricing(
+        &mut self,
+        pricing_type: PricingType,
+        country: &str,
+    ) -> Result<CountryPricing, VonageClientError>;
+
+    /// Get Prefix Pricing
+    /// This function gets the outbound prices of every country with a dialing prefix
+    /// @param pricing_type The service to price
+    /// @param prefix The dialing prefix, e.g. `1` for the US and Canada
+    /// @return The prices of the matching countries
+    async fn get_prefix_pricing(
+        &mut self,
+        pricing_type: PricingType,
+        prefix: &str,
+    ) -> Result<PricingList, VonageClientError>;
+
+    /// Get Full Pricing
+    /// This function gets the outbound prices of every country
+    /// @param pricing_type The service to price
+    /// @return The prices of every country
+    async fn get_full_pricing(
+        &mut self,
+        pricing_type: PricingType,
+    ) -> Result<PricingList, VonageClientError>;
+}
+
+#[async_trait]
+impl PricingApi for VonageClient {
+    async fn get_country_pricing(
+        &mut self,
+        pricing_type: PricingType,
+        country: &str,
+    ) -> Result<CountryPricing, VonageClientError> {
+        log::debug!("Getting {:?} pricing for {}", pricing_type, country);
+        let path = ApiPath::new(ApiFamily::Rest, "/account/get-pricing/outbound/{type}")
+            .param("type", pricing_type.as_str())
+            .query(&CountryQuery { country })
+            .basic_auth();
+        self.get(path)
+            .await
+            .map(Self::debug_response("Get Country Pricing Response".into()))
+    }
+
+    async fn get_prefix_pricing(
+        &mut self,
+        pricing_type: PricingType,
+        prefix: &str,
+    ) -> Result<PricingList, VonageClientError> {
+        log::debug!("Getting {:?} pricing for prefix {}", pricing_type, prefix);
+        let path = ApiPath::new(
+            ApiFamily::Rest,
+            "/account/get-prefix-pricing/outbound/{type}",
+        )
+        .param("type", pricing_type.as_str())
+        .query(&PrefixQuery { prefix })
+        .basic_auth();
+        self.get(path)
+            .await
+            .map(Self::debug_response("Get Prefix Pricing Response".into()))
+    }
+
+    async fn get_full_pricing(
+        &mut self,
+        pricing_type: PricingType,
+    ) -> Result<PricingList, VonageClientError> {
+        log::debug!("Getting full {:?} pricing", pricing_type);
+        let path = ApiPath::new(ApiFamily::Rest, "/account/get-full-pricing/outbound/{type}")
+            .param("type", pricing_type.as_str())
+            .basic_auth();
+        self.get(path)
+            .await
+            .map(Self::debug_response("Get Full Pricing Response".into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use crate::client::mock::MockServer;
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    const GB_VOICE: &str = r#"{"countryCode":"GB","countryName":"United Kingdom","countryDisplayName":"United Kingdom","currency":"EUR","defaultPrice":"0.01200000","dialingPrefix":"44","networks":[{"type":"mobile","price":"0.01800000","currency":"EUR","mcc":"234","mnc":"10","networkCode":"23410","networkName":"Telefonica UK Limited"},{"type":"landline","price":"0.00800000","currency":"EUR","networkCode":"GB-FIXED","networkName":"United Kingdom Landline"}]}"#;
+
+    #[tokio::test]
+    async fn country_and_prefix_pricing() {
+        init();
+        let server = MockServer::default()
+            .respond(200, GB_VOICE)
+            .respond(200, &format!(r#"{{"count":1,"countries":[{}]}}"#, GB_VOICE));
+        let mut client = server.client();
+
+        let pricing = client
+            .get_country_pricing(PricingType::Voice, "GB")
+            .await
+            .unwrap();
+        assert_eq!(pricing.networks.len(), 2);
+        assert_eq!(pricing.max_price(), Some(0.018));
+        assert_eq!(pricing.network_price("GB-FIXED"), Some(0.008));
+        assert_eq!(pricing.network_price("99999"), Some(0.012));
+
+        let list = client
+            .get_prefix_pricing(PricingType::SmsTransit, "44")
+            .await
+            .unwrap();
+        assert_eq!(list.count, 1);
+        assert_eq!(list.countries[0].dialing_prefix.as_deref(), Some("44"));
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].url.as_str(),
+            "https://rest.nexmo.com/account/get-pricing/outbound/voice?country=GB"
+        );
+        assert_eq!(
+            requests[1].path_and_query(),
+            "/account/get-prefix-pricing/outbound/sms-transit?prefix=44"
+        );
+        assert_eq!(
+            requests[1].headers.get("authorization").unwrap(),
+            "Basic a2V5OnNlY3JldA=="
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+/// Pricing Type
+/// The service to get prices for, the SMS channel of the Messages API is charged at `Sms` prices
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PricingType {
+    Sms,
+    SmsTransit,
+    Voice,
+}
+
+impl PricingType {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            PricingType::Sms => "sms",
+            PricingType::SmsTransit => "sms-transit",
+            PricingType::Voice => "voice",
+        }
+    }
+}
+
+/// Country Pricing
+/// The outbound prices of a country, the network prices override the default price
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CountryPricing {
+    pub country_code: String,
+    pub country_name: Option<String>,
+    pub country_display_name: Option<String>,
+    pub currency: Option<String>,
+    pub default_price: Option<String>,
+    pub dialing_prefix: Option<String>,
+    #[serde(default)]
+    pub networks: Vec<NetworkPrice>,
+}
+
+impl CountryPricing {
+    /// The highest price of any network in the country, or the default price
+    /// Use this to estimate the worst case cost of a destination
+    pub fn max_price(&self) -> Option<f64> {
+        self.networks
+            .iter()
+            .filter_map(NetworkPrice::price_value)
+            .chain(self.default_price.as_deref().and_then(parse_price))
+            .reduce(f64::max)
+    }
+
+    /// The price of a network in the country, falling back to the default price
+    /// @param network_code The MCC and MNC of the network, e.g. `23410`
+    pub fn network_price(&self, network_code: &str) -> Option<f64> {
+        self.networks
+            .iter()
+            .find(|network| network.network_code.as_deref() == Some(network_code))
+            .and_then(NetworkPrice::price_value)
+            .or_else(|| self.default_price.as_deref().and_then(parse_price))
+    }
+}
+
+/// Network Price
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkPrice {
+    #[serde(rename = "type")]
+    pub network_type: Option<String>,
+    pub price: Option<String>,
+    pub currency: Option<String>,
+    pub mcc: Option<String>,
+    pub mnc: Option<String>,
+    pub network_code: Option<String>,
+    pub network_name: Option<String>,
+}
+
+impl NetworkPrice {
+    /// The price as a number
+    pub fn price_value(&self) -> Option<f64> {
+        self.price.as_deref().and_then(parse_price)
+    }
+}
+
+fn parse_price(price: &str) -> Option<f64> {
+    price.parse().ok()
+}
+
+/// Pricing List
+/// The prices of every country matching a request
+#[derive(Debug, Clone, Deserialize)]
+pub struct PricingList {
+    pub count: usize,
+    #[serde(default)]
+    pub countries: Vec<CountryPricing>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct CountryQuery<'a> {
+    pub(crate) country: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct PrefixQuery<'a> {
+    pub(crate) prefix: &'a str,
+}
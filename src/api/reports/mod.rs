@@ -0,0 +1,312 @@
+mod record;
+mod report;
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::Stream;
+pub use record::*;
+pub use report::*;
+
+use crate::client::path::ApiPath;
+use crate::client::{ApiFamily, VonageClient, VonageClientError};
+use crate::types::pages::PageOptions;
+
+/// The media ID of a report download URL such as `https://api.nexmo.com/v3/media/{file_id}`
+fn media_id(download_url: &str) -> Option<String> {
+    let url = url::Url::parse(download_url).ok()?;
+    let mut segments = url.path_segments()?;
+    match (segments.next(), segments.next(), segments.next()) {
+        (Some("v3"), Some("media"), Some(file_id)) if !file_id.is_empty() => {
+            Some(file_id.to_string())
+        }
+        _ => None,
+    }
+}
+
+#[async_trait]
+pub trait ReportsApi {
+    /// List Records
+    /// This function searches the records of a product synchronously, following the next page links
+    /// The product is picked by the record type, e.g. `list_records::<SmsRecord>`
+    /// @param query The account and the dates or IDs of the records
+    /// @param options The maximum number of records to yield
+    /// @return A stream of records
+    fn list_records<R: ReportRecord>(
+        &self,
+        query: &RecordsQuery,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<R, VonageClientError>> + Send + 'static;
+
+    /// Create Report
+    /// This function requests an asynchronous report
+    /// @param report The product, account and filters of the report
+    /// @return The pending report
+    async fn create_report(&mut self, report: &CreateReport) -> Result<Report, VonageClientError>;
+
+    /// Get Report
+    /// This function gets the status of an asynchronous report
+    /// @param request_id The ID of the report
+    /// @return The report
+    async fn get_report(&mut self, request_id: &str) -> Result<Report, VonageClientError>;
+
+    /// Wait For Report
+    /// This function polls an asynchronous report until it has finished, a status this crate does not know is treated as finished
+    /// @param request_id The ID of the report
+    /// @param interval The time between polls
+    /// @param max_attempts The number of polls before giving up with `ReportNotFinished`
+    /// @return The finished report
+    async fn wait_for_report(
+        &mut self,
+        request_id: &str,
+        interval: Duration,
+        max_attempts: usize,
+    ) -> Result<Report, VonageClientError>;
+
+    /// Cancel Report
+    /// This function cancels a pending report
+    /// @param request_id The ID of the report
+    async fn cancel_report(&mut self, request_id: &str) -> Result<(), VonageClientError>;
+
+    /// Download Report
+    /// This function downloads the zipped CSV of a finished report
+    /// @param download_url The download URL of the report, see `Report::download_url`
+    /// @return The report file
+    async fn download_report(
+        &mut self,
+        download_url: &str,
+    ) -> Result<ReportFile, VonageClientError>;
+}
+
+#[async_trait]
+impl ReportsApi for VonageClient {
+    fn list_records<R: ReportRecord>(
+        &self,
+        query: &RecordsQuery,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<R, VonageClientError>> + Send + 'static {
+        log::debug!("Listing {:?} records: {:?}", R::PRODUCT, query);
+        let path = ApiPath::new(ApiFamily::Api, "/v2/reports/records")
+            .query(&[("product", R::PRODUCT)])
+            .query(query)
+            .basic_auth();
+        self.paginate::<RecordsPage<R>>(path, options)
+    }
+
+    async fn create_report(&mut self, report: &CreateReport) -> Result<Report, VonageClientError> {
+        log::debug!("Creating report: {:?}", report);
+        let path = ApiPath::new(ApiFamily::Api, "/v2/reports").basic_auth();
+        self.post(path, report)
+            .await
+            .map(Self::debug_response("Create Report Response".into()))
+    }
+
+    async fn get_report(&mut self, request_id: &str) -> Result<Report, VonageClientError> {
+        log::debug!("Getting report: {}", request_id);
+        let path = ApiPath::new(ApiFamily::Api, "/v2/reports/{request_id}")
+            .param("request_id", request_id)
+            .basic_auth();
+        self.get(path)
+            .await
+            .map(Self::debug_response("Get Report Response".into()))
+    }
+
+    async fn wait_for_report(
+        &mut self,
+        request_id: &str,
+        interval: Duration,
+        max_attempts: usize,
+    ) -> Result<Report, VonageClientError> {
+        for attempt in 1..=max_attempts {
+            let report = self.get_report(request_id).await?;
+            if report.request_status.is_finished() {
+                return Ok(report);
+            }
+            log::trace!("Report {} is {:?}", request_id, report.request_status);
+            if attempt < max_attempts {
+                tokio::time::sleep(interval).await;
+            }
+        }
+        log::error!(
+            "Report {} not finished after {} polls",
+            request_id,
+            max_attempts
+        );
+        Err(VonageClientError::ReportNotFinished(request_id.to_string()))
+    }
+
+    async fn cancel_report(&mut self, request_id: &str) -> Result<(), VonageClientError> {
+        log::debug!("Cancelling report: {}", request_id);
+        let path = ApiPath::new(ApiFamily::Api, "/v2/reports/{request_id}")
+            .param("request_id", request_id)
+            .basic_auth();
+        self.delete(path).await
+    }
+
+    async fn download_report(
+        &mut self,
+        download_url: &str,
+    ) -> Result<ReportFile, VonageClientError> {
+        log::debug!("Downloading report: {}", download_url);
+        // Only the media ID is taken from the URL so credentials are never sent to another host
+        let file_id = media_id(download_url).ok_or_else(|| {
+            log::error!("Not a report download URL: {}", download_url);
+            VonageClientError::InvalidDownloadUrl(download_url.to_string())
+        })?;
+        let path = ApiPath::new(ApiFamily::Api, "/v3/media/{file_id}")
+            .param("file_id", &file_id)
+            .basic_auth();
+        self.get_bytes(path).await.map(ReportFile::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use crate::client::mock::MockServer;
+    use futures::TryStreamExt;
+    use std::io::Write;
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    fn zipped_csv(csv: &str) -> Vec<u8> {
+        let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        zip.start_file(
+            "report.csv",
+            zip::write::SimpleFileOptions::default()
+                .compression_method(zip::CompressionMethod::Deflated),
+        )
+        .unwrap();
+        zip.write_all(csv.as_bytes()).unwrap();
+        zip.finish().unwrap().into_inner()
+    }
+
+    #[tokio::test]
+    async fn list_records_by_id() {
+        init();
+        let server = MockServer::default().respond(
+            200,
+            r#"{"_links":{"self":{"href":"https://api.nexmo.com/v2/reports/records"}},"request_id":"r-1","request_status":"SUCCESS","records":[{"message_id":"0A0000001234567B","account_id":"key","direction":"outbound","from":"Acme","to":"447700900000","status":"delivered","currency":"EUR","total_price":"0.03330000"}]}"#,
+        );
+        let mut query = RecordsQuery::by_id("key".to_string(), "0A0000001234567B".to_string());
+        query.direction(ReportDirection::Outbound);
+
+        let records: Vec<SmsRecord> = server
+            .client()
+            .list_records(&query, PageOptions::new())
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].total_price.as_deref(), Some("0.03330000"));
+        assert_eq!(records[0].network, None);
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].path_and_query(),
+            "/v2/reports/records?product=SMS&account_id=key&id=0A0000001234567B&direction=outbound"
+        );
+        assert_eq!(
+            requests[0].headers.get("authorization").unwrap(),
+            "Basic a2V5OnNlY3JldA=="
+        );
+    }
+
+    #[tokio::test]
+    async fn create_poll_and_download_report() {
+        init();
+        let csv = "id,account_id,direction,from,to,duration,status,currency,rate,total_price\n\
+                   call-1,key,outbound,447700900000,447700900001,60,completed,EUR,0.01,0.01\n\
+                   call-2,key,outbound,447700900000,447700900002,,failed,EUR,0.01,\n";
+        let server = MockServer::default()
+            .respond(200, r#"{"request_id":"r-1","request_status":"PENDING","product":"VOICE-CALL","account_id":"key"}"#)
+            .respond(200, r#"{"request_id":"r-1","request_status":"PROCESSING","product":"VOICE-CALL","account_id":"key"}"#)
+            .respond(200, r#"{"request_id":"r-1","request_status":"SUCCESS","product":"VOICE-CALL","account_id":"key","items_count":2,"_links":{"self":{"href":"https://api.nexmo.com/v2/reports/r-1"},"download_report":{"href":"https://api.nexmo.com/v3/media/file-1"}}}"#)
+            .respond_bytes(200, zipped_csv(csv));
+        let mut client = server.client();
+
+        let report = client
+            .create_report(&CreateReport::new(
+                ReportProduct::VoiceCall,
+                "key".to_string(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(report.request_status, ReportStatus::Pending);
+        let report = client
+            .wait_for_report(&report.request_id, Duration::ZERO, 5)
+            .await
+            .unwrap();
+        assert_eq!(report.request_status, ReportStatus::Success);
+
+        let file = client
+            .download_report(report.download_url().unwrap())
+            .await
+            .unwrap();
+        let records: Vec<VoiceCallRecord> = file.records().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].total_price.as_deref(), Some("0.01"));
+        assert_eq!(records[1].duration, None);
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].body.as_deref(),
+            Some(r#"{"product":"VOICE-CALL","account_id":"key"}"#)
+        );
+        assert_eq!(requests[2].path_and_query(), "/v2/reports/r-1");
+        assert_eq!(
+            requests[3].url.as_str(),
+            "https://api.nexmo.com/v3/media/file-1"
+        );
+
+        assert!(matches!(
+            client.download_report("https://example.com/file-1").await,
+            Err(VonageClientError::InvalidDownloadUrl(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn wait_for_report_gives_up() {
+        init();
+        let pending =
+            r#"{"request_id":"r-1","request_status":"PENDING","product":"SMS","account_id":"key"}"#;
+        let server = MockServer::default()
+            .respond(200, pending)
+            .respond(200, pending)
+            .respond(200, pending);
+
+        let result = server
+            .client()
+            .wait_for_report("r-1", Duration::ZERO, 2)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(VonageClientError::ReportNotFinished(id)) if id == "r-1"
+        ));
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn wait_for_report_stops_on_unknown_status() {
+        init();
+        let server = MockServer::default().respond(
+            200,
+            r#"{"request_id":"r-1","request_status":"ARCHIVED","product":"SMS","account_id":"key"}"#,
+        );
+
+        let report = server
+            .client()
+            .wait_for_report("r-1", Duration::ZERO, 5)
+            .await
+            .unwrap();
+
+        assert_eq!(report.request_status, ReportStatus::Unknown);
+        assert_eq!(server.requests().len(), 1);
+    }
+}
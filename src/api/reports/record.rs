@@ -0,0 +1,243 @@
+use crate::types::pages::{Page, PageMeta, PageStyle};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// Report Product
+/// The product a report covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING-KEBAB-CASE")]
+pub enum ReportProduct {
+    Sms,
+    VoiceCall,
+    Messages,
+    ConversationEvent,
+    VerifyApi,
+    NumberInsight,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Report Direction
+/// The direction of the records, required for SMS and Messages reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportDirection {
+    Inbound,
+    Outbound,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Report Record
+/// A typed record of a product, used to pick the product of a records search
+pub trait ReportRecord: DeserializeOwned + std::fmt::Debug + Send + 'static {
+    const PRODUCT: ReportProduct;
+}
+
+/// Records Query
+/// Search the records of a product synchronously, either by date or by ID
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::{RecordsQuery, ReportDirection};
+/// let mut query = RecordsQuery::by_date(
+///     "abcd1234".to_string(),
+///     "2024-01-01T00:00:00Z".to_string(),
+///     "2024-01-02T00:00:00Z".to_string(),
+/// );
+/// query.direction(ReportDirection::Outbound);
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordsQuery {
+    account_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_start: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_end: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<ReportDirection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_message: Option<bool>,
+}
+
+impl RecordsQuery {
+    /// Search the records between two ISO 8601 dates, at most 24 hours apart
+    pub fn by_date(account_id: String, date_start: String, date_end: String) -> Self {
+        RecordsQuery {
+            account_id,
+            date_start: Some(date_start),
+            date_end: Some(date_end),
+            id: None,
+            direction: None,
+            status: None,
+            include_message: None,
+        }
+    }
+
+    /// Look up records by ID, such as a message ID or call UUID, up to 20 comma separated IDs
+    pub fn by_id(account_id: String, id: String) -> Self {
+        RecordsQuery {
+            account_id,
+            date_start: None,
+            date_end: None,
+            id: Some(id),
+            direction: None,
+            status: None,
+            include_message: None,
+        }
+    }
+
+    pub fn direction(&mut self, direction: ReportDirection) -> &mut Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn status(&mut self, status: String) -> &mut Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Include the message body in SMS and Messages records
+    pub fn include_message(&mut self, include_message: bool) -> &mut Self {
+        self.include_message = Some(include_message);
+        self
+    }
+}
+
+/// Records Page
+/// A page of records returned by a synchronous search
+#[derive(Debug, Deserialize)]
+#[serde(bound = "R: ReportRecord")]
+pub struct RecordsPage<R> {
+    #[serde(flatten)]
+    meta: PageMeta,
+    pub request_id: Option<String>,
+    pub request_status: Option<String>,
+    #[serde(default = "Vec::new")]
+    records: Vec<R>,
+}
+
+impl<R> RecordsPage<R> {
+    pub fn meta(&self) -> &PageMeta {
+        &self.meta
+    }
+
+    pub fn records(&self) -> &[R] {
+        &self.records
+    }
+}
+
+impl<R: ReportRecord> Page for RecordsPage<R> {
+    type Item = R;
+    const STYLE: PageStyle = PageStyle::Cursor;
+
+    fn next_href(&self) -> Option<&str> {
+        self.meta.links().next().map(|link| link.href())
+    }
+
+    fn into_items(self) -> Vec<R> {
+        self.records
+    }
+}
+
+/// SMS Record
+#[derive(Debug, Clone, Deserialize)]
+pub struct SmsRecord {
+    pub message_id: String,
+    pub account_id: Option<String>,
+    pub client_ref: Option<String>,
+    pub direction: Option<ReportDirection>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub network: Option<String>,
+    pub network_name: Option<String>,
+    pub country: Option<String>,
+    pub country_name: Option<String>,
+    pub date_received: Option<String>,
+    pub date_finalized: Option<String>,
+    pub latency: Option<String>,
+    pub status: Option<String>,
+    pub error_code: Option<String>,
+    pub error_code_description: Option<String>,
+    pub currency: Option<String>,
+    pub total_price: Option<String>,
+    pub message_body: Option<String>,
+}
+
+impl ReportRecord for SmsRecord {
+    const PRODUCT: ReportProduct = ReportProduct::Sms;
+}
+
+/// Voice Call Record
+#[derive(Debug, Clone, Deserialize)]
+pub struct VoiceCallRecord {
+    pub id: String,
+    pub account_id: Option<String>,
+    pub direction: Option<ReportDirection>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub network: Option<String>,
+    pub network_name: Option<String>,
+    pub country: Option<String>,
+    pub country_name: Option<String>,
+    pub date_start: Option<String>,
+    pub date_end: Option<String>,
+    pub duration: Option<String>,
+    pub status: Option<String>,
+    pub currency: Option<String>,
+    pub rate: Option<String>,
+    pub total_price: Option<String>,
+}
+
+impl ReportRecord for VoiceCallRecord {
+    const PRODUCT: ReportProduct = ReportProduct::VoiceCall;
+}
+
+/// Messages Record
+#[derive(Debug, Clone, Deserialize)]
+pub struct MessagesRecord {
+    pub message_id: String,
+    pub account_id: Option<String>,
+    pub client_ref: Option<String>,
+    pub direction: Option<ReportDirection>,
+    pub provider: Option<String>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub country: Option<String>,
+    pub country_name: Option<String>,
+    pub date_received: Option<String>,
+    pub status: Option<String>,
+    pub error_code: Option<String>,
+    pub error_reason: Option<String>,
+    pub currency: Option<String>,
+    pub total_price: Option<String>,
+    pub message_body: Option<String>,
+}
+
+impl ReportRecord for MessagesRecord {
+    const PRODUCT: ReportProduct = ReportProduct::Messages;
+}
+
+/// Conversation Event Record
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConversationEventRecord {
+    pub event_id: String,
+    pub account_id: Option<String>,
+    pub application_id: Option<String>,
+    pub conversation_id: Option<String>,
+    pub conversation_name: Option<String>,
+    pub event_type: Option<String>,
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub date_event: Option<String>,
+    pub currency: Option<String>,
+    pub total_price: Option<String>,
+}
+
+impl ReportRecord for ConversationEventRecord {
+    const PRODUCT: ReportProduct = ReportProduct::ConversationEvent;
+}
@@ -0,0 +1,197 @@
+use super::{ReportDirection, ReportProduct, ReportRecord};
+use crate::types::pages::Link;
+use serde::{Deserialize, Serialize};
+use std::io::{Cursor, Read};
+
+/// Create Report
+/// Request an asynchronous report, the result is a zipped CSV file
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateReport {
+    product: ReportProduct,
+    account_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_start: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_end: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<ReportDirection>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_message: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include_subaccounts: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    callback_url: Option<String>,
+}
+
+impl CreateReport {
+    pub fn new(product: ReportProduct, account_id: String) -> Self {
+        CreateReport {
+            product,
+            account_id,
+            date_start: None,
+            date_end: None,
+            direction: None,
+            status: None,
+            include_message: None,
+            include_subaccounts: None,
+            callback_url: None,
+        }
+    }
+
+    /// Only include records between two ISO 8601 dates, defaults to the last 7 days
+    pub fn dates(&mut self, date_start: String, date_end: String) -> &mut Self {
+        self.date_start = Some(date_start);
+        self.date_end = Some(date_end);
+        self
+    }
+
+    pub fn direction(&mut self, direction: ReportDirection) -> &mut Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn status(&mut self, status: String) -> &mut Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn include_message(&mut self, include_message: bool) -> &mut Self {
+        self.include_message = Some(include_message);
+        self
+    }
+
+    pub fn include_subaccounts(&mut self, include_subaccounts: bool) -> &mut Self {
+        self.include_subaccounts = Some(include_subaccounts);
+        self
+    }
+
+    /// Receive a webhook when the report is finished rather than polling
+    pub fn callback_url(&mut self, callback_url: String) -> &mut Self {
+        self.callback_url = Some(callback_url);
+        self
+    }
+}
+
+/// Report Status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReportStatus {
+    Pending,
+    Processing,
+    Success,
+    Aborted,
+    Failed,
+    Truncated,
+    #[serde(other)]
+    Unknown,
+}
+
+impl ReportStatus {
+    /// Whether the report has stopped processing, a truncated report can still be downloaded
+    pub fn is_finished(&self) -> bool {
+        !matches!(self, ReportStatus::Pending | ReportStatus::Processing)
+    }
+}
+
+/// Report
+/// The status of an asynchronous report
+#[derive(Debug, Clone, Deserialize)]
+pub struct Report {
+    pub request_id: String,
+    pub request_status: ReportStatus,
+    pub product: ReportProduct,
+    pub account_id: String,
+    pub date_start: Option<String>,
+    pub date_end: Option<String>,
+    pub direction: Option<ReportDirection>,
+    pub receive_time: Option<String>,
+    pub start_time: Option<String>,
+    pub items_count: Option<usize>,
+    pub _links: Option<ReportLinks>,
+}
+
+impl Report {
+    /// The URL of the zipped CSV, set once the report has finished
+    pub fn download_url(&self) -> Option<&str> {
+        self._links
+            .as_ref()
+            .and_then(|links| links.download_report.as_ref())
+            .map(|link| link.href())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReportLinks {
+    pub download_report: Option<Link>,
+}
+
+/// Report File
+/// A downloaded report, a zip archive holding a single CSV file
+#[derive(Debug, Clone)]
+pub struct ReportFile {
+    bytes: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub enum ReportFileError {
+    Zip(zip::result::ZipError),
+    Io(std::io::Error),
+    Csv(csv::Error),
+    MissingCsv,
+}
+
+impl std::fmt::Display for ReportFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReportFileError::Zip(e) => write!(f, "error reading report archive: {}", e),
+            ReportFileError::Io(e) => write!(f, "error reading report: {}", e),
+            ReportFileError::Csv(e) => write!(f, "error parsing report: {}", e),
+            ReportFileError::MissingCsv => write!(f, "report archive has no CSV file"),
+        }
+    }
+}
+
+impl std::error::Error for ReportFileError {}
+
+impl ReportFile {
+    pub(crate) fn new(bytes: Vec<u8>) -> Self {
+        ReportFile { bytes }
+    }
+
+    /// The zip archive as downloaded
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Extract the CSV file from the archive
+    pub fn csv(&self) -> Result<String, ReportFileError> {
+        let mut archive =
+            zip::ZipArchive::new(Cursor::new(&self.bytes)).map_err(ReportFileError::Zip)?;
+        let index = (0..archive.len())
+            .find(|&i| {
+                archive
+                    .name_for_index(i)
+                    .is_some_and(|name| name.ends_with(".csv"))
+            })
+            .ok_or(ReportFileError::MissingCsv)?;
+        let mut csv = String::new();
+        archive
+            .by_index(index)
+            .map_err(ReportFileError::Zip)?
+            .read_to_string(&mut csv)
+            .map_err(ReportFileError::Io)?;
+        Ok(csv)
+    }
+
+    /// Parse the records of the report, the record type must match the product of the report
+    pub fn records<R: ReportRecord>(&self) -> Result<Vec<R>, ReportFileError> {
+        let csv = self.csv()?;
+        csv::Reader::from_reader(csv.as_bytes())
+            .deserialize()
+            .collect::<Result<Vec<R>, _>>()
+            .inspect_err(|e| log::error!("Error parsing report: {}", e))
+            .map_err(ReportFileError::Csv)
+    }
+}
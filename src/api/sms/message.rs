@@ -0,0 +1,298 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::client_types::redact_number;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SmsType {
+    Text,
+    Unicode,
+    Binary,
+    #[serde(other)]
+    Unknown,
+}
+
+/// SMS Request
+/// A message sent with the legacy SMS API, messages longer than a single part are split and concatenated by the handset
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::sms::{SmsRequest, SmsType};
+/// let mut sms = SmsRequest::new(
+///     "Acme".to_string(),
+///     "447700900000".to_string(),
+///     "Your code is 1234".to_string(),
+/// );
+/// sms.sms_type(SmsType::Unicode)
+///     .client_ref("order-42".to_string())
+///     .status_report_req(true);
+/// ```
+#[derive(Clone, Serialize)]
+pub struct SmsRequest {
+    from: String,
+    to: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    sms_type: Option<SmsType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    udh: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ttl: Option<u64>,
+    #[serde(rename = "status-report-req", skip_serializing_if = "Option::is_none")]
+    status_report_req: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    callback: Option<String>,
+    #[serde(rename = "message-class", skip_serializing_if = "Option::is_none")]
+    message_class: Option<u8>,
+    #[serde(rename = "client-ref", skip_serializing_if = "Option::is_none")]
+    client_ref: Option<String>,
+    #[serde(rename = "account-ref", skip_serializing_if = "Option::is_none")]
+    account_ref: Option<String>,
+    #[serde(rename = "entity-id", skip_serializing_if = "Option::is_none")]
+    entity_id: Option<String>,
+    #[serde(rename = "content-id", skip_serializing_if = "Option::is_none")]
+    content_id: Option<String>,
+}
+
+impl SmsRequest {
+    /// Create a new text SmsRequest
+    /// @param from The sender ID, either a number or up to 11 alphanumeric characters
+    /// @param to The number to send to in E.164 format without the leading +
+    /// @param text The text of the message
+    pub fn new(from: String, to: String, text: String) -> Self {
+        SmsRequest {
+            from,
+            to,
+            text: Some(text),
+            sms_type: None,
+            body: None,
+            udh: None,
+            ttl: None,
+            status_report_req: None,
+            callback: None,
+            message_class: None,
+            client_ref: None,
+            account_ref: None,
+            entity_id: None,
+            content_id: None,
+        }
+    }
+
+    /// Create a new binary SmsRequest
+    /// @param from The sender ID
+    /// @param to The number to send to in E.164 format without the leading +
+    /// @param body The hex encoded binary body
+    /// @param udh The hex encoded user data header
+    pub fn binary(from: String, to: String, body: String, udh: String) -> Self {
+        let mut sms = SmsRequest::new(from, to, String::new());
+        sms.text = None;
+        sms.sms_type = Some(SmsType::Binary);
+        sms.body = Some(body);
+        sms.udh = Some(udh);
+        sms
+    }
+
+    /// Set the encoding, use `SmsType::Unicode` for text outside the GSM character set
+    pub fn sms_type(&mut self, sms_type: SmsType) -> &mut Self {
+        self.sms_type = Some(sms_type);
+        self
+    }
+
+    /// Set the milliseconds the message is retried for before it expires
+    pub fn ttl(&mut self, ttl: u64) -> &mut Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Request a delivery receipt
+    pub fn status_report_req(&mut self, status_report_req: bool) -> &mut Self {
+        self.status_report_req = Some(status_report_req);
+        self
+    }
+
+    /// Send the delivery receipt to this URL rather than the account default
+    pub fn callback(&mut self, callback: String) -> &mut Self {
+        self.callback = Some(callback);
+        self
+    }
+
+    /// Set the message class, 0 sends a flash message
+    pub fn message_class(&mut self, message_class: u8) -> &mut Self {
+        self.message_class = Some(message_class);
+        self
+    }
+
+    /// Set your own reference, up to 100 characters, returned in the delivery receipt
+    pub fn client_ref(&mut self, client_ref: String) -> &mut Self {
+        self.client_ref = Some(client_ref);
+        self
+    }
+
+    /// Set a reference used to group messages in reports
+    pub fn account_ref(&mut self, account_ref: String) -> &mut Self {
+        self.account_ref = Some(account_ref);
+        self
+    }
+
+    /// Set the DLT entity ID, required for messages sent to India
+    pub fn entity_id(&mut self, entity_id: String) -> &mut Self {
+        self.entity_id = Some(entity_id);
+        self
+    }
+
+    /// Set the DLT template ID, required for messages sent to India
+    pub fn content_id(&mut self, content_id: String) -> &mut Self {
+        self.content_id = Some(content_id);
+        self
+    }
+}
+
+impl std::fmt::Debug for SmsRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SmsRequest")
+            .field("from", &self.from)
+            .field("to", &redact_number(&self.to))
+            .field("type", &self.sms_type)
+            .field("status_report_req", &self.status_report_req)
+            .field("client_ref", &self.client_ref)
+            .finish_non_exhaustive()
+    }
+}
+
+/// SMS Status
+/// The status of a single message part, the SMS API reports failures with a 200 response
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum SmsStatus {
+    Success,
+    Throttled,
+    MissingParameters,
+    InvalidParameters,
+    InvalidCredentials,
+    InternalError,
+    InvalidMessage,
+    NumberBarred,
+    PartnerAccountBarred,
+    PartnerQuotaViolation,
+    TooManyExistingBinds,
+    AccountNotEnabledForHttp,
+    MessageTooLong,
+    InvalidSignature,
+    InvalidSenderAddress,
+    InvalidNetworkCode,
+    InvalidCallbackUrl,
+    NonWhitelistedDestination,
+    SignatureAndApiSecretDisallowed,
+    NumberDeactivated,
+    Other(u8),
+}
+
+impl SmsStatus {
+    pub fn is_success(&self) -> bool {
+        *self == SmsStatus::Success
+    }
+}
+
+impl From<u8> for SmsStatus {
+    fn from(code: u8) -> Self {
+        match code {
+            0 => SmsStatus::Success,
+            1 => SmsStatus::Throttled,
+            2 => SmsStatus::MissingParameters,
+            3 => SmsStatus::InvalidParameters,
+            4 => SmsStatus::InvalidCredentials,
+            5 => SmsStatus::InternalError,
+            6 => SmsStatus::InvalidMessage,
+            7 => SmsStatus::NumberBarred,
+            8 => SmsStatus::PartnerAccountBarred,
+            9 => SmsStatus::PartnerQuotaViolation,
+            10 => SmsStatus::TooManyExistingBinds,
+            11 => SmsStatus::AccountNotEnabledForHttp,
+            12 => SmsStatus::MessageTooLong,
+            14 => SmsStatus::InvalidSignature,
+            15 => SmsStatus::InvalidSenderAddress,
+            22 => SmsStatus::InvalidNetworkCode,
+            23 => SmsStatus::InvalidCallbackUrl,
+            29 => SmsStatus::NonWhitelistedDestination,
+            32 => SmsStatus::SignatureAndApiSecretDisallowed,
+            33 => SmsStatus::NumberDeactivated,
+            code => SmsStatus::Other(code),
+        }
+    }
+}
+
+impl From<SmsStatus> for u8 {
+    fn from(status: SmsStatus) -> Self {
+        match status {
+            SmsStatus::Success => 0,
+            SmsStatus::Throttled => 1,
+            SmsStatus::MissingParameters => 2,
+            SmsStatus::InvalidParameters => 3,
+            SmsStatus::InvalidCredentials => 4,
+            SmsStatus::InternalError => 5,
+            SmsStatus::InvalidMessage => 6,
+            SmsStatus::NumberBarred => 7,
+            SmsStatus::PartnerAccountBarred => 8,
+            SmsStatus::PartnerQuotaViolation => 9,
+            SmsStatus::TooManyExistingBinds => 10,
+            SmsStatus::AccountNotEnabledForHttp => 11,
+            SmsStatus::MessageTooLong => 12,
+            SmsStatus::InvalidSignature => 14,
+            SmsStatus::InvalidSenderAddress => 15,
+            SmsStatus::InvalidNetworkCode => 22,
+            SmsStatus::InvalidCallbackUrl => 23,
+            SmsStatus::NonWhitelistedDestination => 29,
+            SmsStatus::SignatureAndApiSecretDisallowed => 32,
+            SmsStatus::NumberDeactivated => 33,
+            SmsStatus::Other(code) => code,
+        }
+    }
+}
+
+/// The SMS API sends status codes as strings
+impl TryFrom<String> for SmsStatus {
+    type Error = std::num::ParseIntError;
+
+    fn try_from(code: String) -> Result<Self, Self::Error> {
+        code.parse::<u8>().map(SmsStatus::from)
+    }
+}
+
+impl From<SmsStatus> for String {
+    fn from(status: SmsStatus) -> Self {
+        u8::from(status).to_string()
+    }
+}
+
+/// SMS Response
+/// The outcome of each part of a message
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmsResponse {
+    #[serde(rename = "message-count")]
+    pub message_count: String,
+    pub messages: Vec<SmsMessagePart>,
+}
+
+impl SmsResponse {
+    /// Every part of the message was accepted
+    pub fn is_success(&self) -> bool {
+        self.messages.iter().all(|part| part.status.is_success())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct SmsMessagePart {
+    pub status: SmsStatus,
+    pub to: Option<String>,
+    pub message_id: Option<String>,
+    pub remaining_balance: Option<String>,
+    pub message_price: Option<String>,
+    pub network: Option<String>,
+    pub client_ref: Option<String>,
+    pub account_ref: Option<String>,
+    pub error_text: Option<String>,
+}
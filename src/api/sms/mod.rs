@@ -0,0 +1,81 @@
+mod message;
+mod webhooks;
+
+use async_trait::async_trait;
+pub use message::*;
+pub use webhooks::*;
+
+use crate::client::path::ApiPath;
+use crate::client::{ApiFamily, VonageClient, VonageClientError};
+
+#[async_trait]
+pub trait SmsApi {
+    /// Send SMS
+    /// This function sends a message with the legacy SMS API
+    /// @param sms The message to send
+    /// @return The status of each part of the message
+    async fn send_sms(&mut self, sms: &SmsRequest) -> Result<SmsResponse, VonageClientError>;
+}
+
+#[async_trait]
+impl SmsApi for VonageClient {
+    async fn send_sms(&mut self, sms: &SmsRequest) -> Result<SmsResponse, VonageClientError> {
+        log::debug!("Sending SMS: {:?}", sms);
+        let path = ApiPath::new(ApiFamily::Rest, "/sms/json").form_auth();
+        self.post_form(path, sms)
+            .await
+            .map(Self::debug_response("Send SMS Response".into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use crate::client::mock::MockServer;
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    #[tokio::test]
+    async fn send_multipart_sms() {
+        init();
+        let server = MockServer::default().respond(
+            200,
+            r#"{
+                "message-count": "2",
+                "messages": [
+                    {"to": "447700900000", "message-id": "0A0000000123ABCD1", "status": "0", "remaining-balance": "3.14", "message-price": "0.03", "network": "12345", "client-ref": "order-42"},
+                    {"to": "447700900000", "status": "9", "error-text": "Quota Exceeded - rejected"}
+                ]
+            }"#,
+        );
+        let mut sms = SmsRequest::new(
+            "Acme".to_string(),
+            "447700900000".to_string(),
+            "Hello".to_string(),
+        );
+        sms.client_ref("order-42".to_string())
+            .status_report_req(true);
+
+        let response = server.client().send_sms(&sms).await.unwrap();
+        assert!(!response.is_success());
+        assert_eq!(response.messages[0].status, SmsStatus::Success);
+        assert_eq!(
+            response.messages[1].status,
+            SmsStatus::PartnerQuotaViolation
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests[0].url.as_str(), "https://rest.nexmo.com/sms/json");
+        assert!(requests[0].headers.get("authorization").is_none());
+        assert_eq!(
+            requests[0].body.as_deref(),
+            Some("api_key=key&api_secret=secret&from=Acme&to=447700900000&text=Hello&status-report-req=true&client-ref=order-42")
+        );
+        assert!(!format!("{:?}", sms).contains("447700900000"));
+    }
+}
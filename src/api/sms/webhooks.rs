@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use super::SmsType;
+use crate::client::client_types::redact_number;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeliveryStatus {
+    Delivered,
+    Expired,
+    Failed,
+    Rejected,
+    Accepted,
+    Buffered,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Delivery Receipt
+/// The payload sent to the delivery receipt webhook, as query parameters or a JSON body
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct DeliveryReceipt {
+    /// The number the message was sent to
+    pub msisdn: String,
+    /// The sender ID the message was sent from
+    pub to: String,
+    pub network_code: Option<String>,
+    #[serde(rename = "messageId")]
+    pub message_id: String,
+    pub price: Option<String>,
+    pub status: DeliveryStatus,
+    /// When the receipt was generated by the carrier, in `YYMMDDHHMM` format
+    pub scts: Option<String>,
+    /// The error code of the carrier, `0` when delivered
+    pub err_code: Option<String>,
+    pub api_key: Option<String>,
+    pub client_ref: Option<String>,
+    pub message_timestamp: Option<String>,
+    pub timestamp: Option<String>,
+    pub nonce: Option<String>,
+    pub sig: Option<String>,
+}
+
+impl DeliveryReceipt {
+    pub fn is_delivered(&self) -> bool {
+        self.status == DeliveryStatus::Delivered
+    }
+}
+
+/// Inbound SMS
+/// The payload sent to the inbound SMS webhook, each part of a long message is sent separately
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct InboundSms {
+    /// The number the message was sent from
+    pub msisdn: String,
+    /// The number the message was sent to
+    pub to: String,
+    #[serde(rename = "messageId")]
+    pub message_id: String,
+    pub text: Option<String>,
+    #[serde(rename = "type")]
+    pub sms_type: Option<SmsType>,
+    pub keyword: Option<String>,
+    pub api_key: Option<String>,
+    pub message_timestamp: Option<String>,
+    pub timestamp: Option<String>,
+    pub nonce: Option<String>,
+    pub concat: Option<String>,
+    pub concat_ref: Option<String>,
+    pub concat_total: Option<String>,
+    pub concat_part: Option<String>,
+    pub data: Option<String>,
+    pub udh: Option<String>,
+    pub sig: Option<String>,
+}
+
+impl InboundSms {
+    /// This is one part of a long message
+    pub fn is_concatenated(&self) -> bool {
+        self.concat.as_deref() == Some("true")
+    }
+
+    /// The total number of parts of a long message
+    pub fn concat_total(&self) -> Option<usize> {
+        self.concat_total.as_deref()?.parse().ok()
+    }
+
+    /// The 1-based position of this part in a long message
+    pub fn concat_part(&self) -> Option<usize> {
+        self.concat_part.as_deref()?.parse().ok()
+    }
+}
+
+/// The parts of a long message received so far
+#[derive(Debug)]
+struct PendingMessage {
+    parts: HashMap<usize, InboundSms>,
+    first_seen: Instant,
+}
+
+/// Inbound SMS Assembler
+/// Reassembles long messages from their parts, parts may arrive in any order and more than once
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::sms::{InboundSms, InboundSmsAssembler};
+/// fn on_inbound(assembler: &mut InboundSmsAssembler, sms: InboundSms) {
+///     if let Some(message) = assembler.push(sms) {
+///         println!("{:?}", message.text);
+///     }
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct InboundSmsAssembler {
+    pending: HashMap<(String, String), PendingMessage>,
+}
+
+impl InboundSmsAssembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a message or a part of a long message
+    /// @param sms The payload of the inbound SMS webhook
+    /// @return The whole message once every part has arrived, with the text of the parts joined in order
+    pub fn push(&mut self, sms: InboundSms) -> Option<InboundSms> {
+        let (Some(concat_ref), Some(total), Some(part)) = (
+            sms.concat_ref.clone(),
+            sms.concat_total(),
+            sms.concat_part(),
+        ) else {
+            return Some(sms);
+        };
+        if !sms.is_concatenated() {
+            return Some(sms);
+        }
+        let key = (sms.msisdn.clone(), concat_ref);
+        let pending = self
+            .pending
+            .entry(key.clone())
+            .or_insert_with(|| PendingMessage {
+                parts: HashMap::new(),
+                first_seen: Instant::now(),
+            });
+        pending.parts.insert(part, sms);
+        log::trace!(
+            "Received part {} of {} of inbound SMS {} from {}",
+            part,
+            total,
+            key.1,
+            redact_number(&key.0)
+        );
+        if !(1..=total).all(|index| pending.parts.contains_key(&index)) {
+            return None;
+        }
+
+        let mut parts = self.pending.remove(&key)?.parts;
+        let mut message = parts.remove(&1)?;
+        for index in 2..=total {
+            let text = parts.remove(&index)?.text.unwrap_or_default();
+            message.text.get_or_insert_with(String::new).push_str(&text);
+        }
+        message.concat = None;
+        message.concat_part = None;
+        Some(message)
+    }
+
+    /// Drop long messages that are still missing parts after a timeout
+    /// @param timeout How long to wait for the remaining parts
+    /// @return The number of messages dropped
+    pub fn expire(&mut self, timeout: Duration) -> usize {
+        let before = self.pending.len();
+        self.pending
+            .retain(|_, pending| pending.first_seen.elapsed() < timeout);
+        before - self.pending.len()
+    }
+
+    /// The number of long messages waiting for parts
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(part: usize, text: &str) -> InboundSms {
+        serde_json::from_value(serde_json::json!({
+            "msisdn": "447700900001",
+            "to": "447700900000",
+            "messageId": format!("id-{}", part),
+            "text": text,
+            "type": "text",
+            "message-timestamp": "2024-01-01 12:00:00",
+            "concat": "true",
+            "concat-ref": "9",
+            "concat-total": "3",
+            "concat-part": part.to_string()
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn reassemble_concatenated_sms() {
+        let mut assembler = InboundSmsAssembler::new();
+        assert!(assembler.push(part(3, " three")).is_none());
+        assert!(assembler.push(part(1, "one")).is_none());
+        // Vonage may retry a webhook, a duplicate part is ignored
+        assert!(assembler.push(part(1, "one")).is_none());
+        assert_eq!(assembler.pending(), 1);
+
+        let message = assembler.push(part(2, " two")).unwrap();
+        assert_eq!(message.text.as_deref(), Some("one two three"));
+        assert_eq!(message.message_id, "id-1");
+        assert_eq!(assembler.pending(), 0);
+
+        assert!(assembler.push(part(1, "one")).is_none());
+        assert_eq!(assembler.expire(Duration::ZERO), 1);
+    }
+
+    #[test]
+    fn deserialize_delivery_receipt() {
+        let query = "msisdn=447700900000&to=Acme&network-code=23410&messageId=0A0000001234567B&price=0.03330000&status=delivered&scts=2001011400&err-code=0&api-key=abcd1234&client-ref=order-42&message-timestamp=2020-01-01+12%3A00%3A00";
+        let receipt: DeliveryReceipt = serde_urlencoded::from_str(query).unwrap();
+        assert!(receipt.is_delivered());
+        assert_eq!(receipt.client_ref.as_deref(), Some("order-42"));
+        assert_eq!(
+            receipt.message_timestamp.as_deref(),
+            Some("2020-01-01 12:00:00")
+        );
+    }
+}
@@ -0,0 +1,366 @@
+mod subaccount;
+mod transfer;
+
+use async_trait::async_trait;
+pub use subaccount::*;
+pub use transfer::{BalanceTransfer, CreditTransfer, NumberTransfer, Transfer, TransferFilter};
+use transfer::{BalanceTransferListResponse, CreditTransferListResponse};
+
+use crate::client::path::ApiPath;
+use crate::client::{ApiFamily, VonageClient, VonageClientError};
+
+#[async_trait]
+pub trait SubaccountsApi {
+    /// List Subaccounts
+    /// This function lists the primary account and its subaccounts
+    /// @param api_key The API key of the primary account
+    /// @return The primary account, the subaccounts and their totals
+    async fn list_subaccounts(
+        &mut self,
+        api_key: &str,
+    ) -> Result<SubaccountList, VonageClientError>;
+
+    /// Create Subaccount
+    /// This function creates a subaccount, the response holds the only copy of its secret
+    /// @param api_key The API key of the primary account
+    /// @param subaccount The name and settings of the subaccount
+    /// @return The created subaccount
+    async fn create_subaccount(
+        &mut self,
+        api_key: &str,
+        subaccount: &NewSubaccount,
+    ) -> Result<Subaccount, VonageClientError>;
+
+    /// Get Subaccount
+    /// This function gets a subaccount
+    /// @param api_key The API key of the primary account
+    /// @param subaccount_key The API key of the subaccount
+    /// @return The subaccount
+    async fn get_subaccount(
+        &mut self,
+        api_key: &str,
+        subaccount_key: &str,
+    ) -> Result<Subaccount, VonageClientError>;
+
+    /// Update Subaccount
+    /// This function renames, suspends or reactivates a subaccount, or moves it to its own balance
+    /// @param api_key The API key of the primary account
+    /// @param subaccount_key The API key of the subaccount
+    /// @param update The fields to change
+    /// @return The updated subaccount
+    async fn update_subaccount(
+        &mut self,
+        api_key: &str,
+        subaccount_key: &str,
+        update: &UpdateSubaccount,
+    ) -> Result<Subaccount, VonageClientError>;
+
+    /// List Credit Transfers
+    /// This function lists the credit transfers between the primary account and its subaccounts
+    /// @param api_key The API key of the primary account
+    /// @param filter The dates and subaccount of the transfers
+    /// @return The credit transfers
+    async fn list_credit_transfers(
+        &mut self,
+        api_key: &str,
+        filter: &TransferFilter,
+    ) -> Result<Vec<CreditTransfer>, VonageClientError>;
+
+    /// Transfer Credit
+    /// This function moves credit limit between the primary account and a subaccount
+    /// @param api_key The API key of the primary account
+    /// @param transfer The accounts and amount of the transfer
+    /// @return The credit transfer
+    async fn transfer_credit(
+        &mut self,
+        api_key: &str,
+        transfer: &Transfer,
+    ) -> Result<CreditTransfer, VonageClientError>;
+
+    /// List Balance Transfers
+    /// This function lists the balance transfers between the primary account and its subaccounts
+    /// @param api_key The API key of the primary account
+    /// @param filter The dates and subaccount of the transfers
+    /// @return The balance transfers
+    async fn list_balance_transfers(
+        &mut self,
+        api_key: &str,
+        filter: &TransferFilter,
+    ) -> Result<Vec<BalanceTransfer>, VonageClientError>;
+
+    /// Transfer Balance
+    /// This function moves balance between the primary account and a subaccount
+    /// @param api_key The API key of the primary account
+    /// @param transfer The accounts and amount of the transfer
+    /// @return The balance transfer
+    async fn transfer_balance(
+        &mut self,
+        api_key: &str,
+        transfer: &Transfer,
+    ) -> Result<BalanceTransfer, VonageClientError>;
+
+    /// Transfer Number
+    /// This function moves a number between the primary account and a subaccount
+    /// @param api_key The API key of the primary account
+    /// @param transfer The accounts, number and country of the transfer
+    /// @return The number transfer
+    async fn transfer_number(
+        &mut self,
+        api_key: &str,
+        transfer: &NumberTransfer,
+    ) -> Result<NumberTransfer, VonageClientError>;
+}
+
+#[async_trait]
+impl SubaccountsApi for VonageClient {
+    async fn list_subaccounts(
+        &mut self,
+        api_key: &str,
+    ) -> Result<SubaccountList, VonageClientError> {
+        log::debug!("Listing subaccounts");
+        let path = ApiPath::new(ApiFamily::Api, "/accounts/{api_key}/subaccounts")
+            .param("api_key", api_key)
+            .basic_auth();
+        self.get(path)
+            .await
+            .map(Self::debug_response("List Subaccounts Response".into()))
+    }
+
+    async fn create_subaccount(
+        &mut self,
+        api_key: &str,
+        subaccount: &NewSubaccount,
+    ) -> Result<Subaccount, VonageClientError> {
+        log::debug!("Creating subaccount: {:?}", subaccount);
+        let path = ApiPath::new(ApiFamily::Api, "/accounts/{api_key}/subaccounts")
+            .param("api_key", api_key)
+            .basic_auth();
+        self.post(path, subaccount)
+            .await
+            .map(Self::debug_response("Create Subaccount Response".into()))
+    }
+
+    async fn get_subaccount(
+        &mut self,
+        api_key: &str,
+        subaccount_key: &str,
+    ) -> Result<Subaccount, VonageClientError> {
+        log::debug!("Getting subaccount: {}", subaccount_key);
+        let path = ApiPath::new(
+            ApiFamily::Api,
+            "/accounts/{api_key}/subaccounts/{subaccount_key}",
+        )
+        .param("api_key", api_key)
+        .param("subaccount_key", subaccount_key)
+        .basic_auth();
+        self.get(path)
+            .await
+            .map(Self::debug_response("Get Subaccount Response".into()))
+    }
+
+    async fn update_subaccount(
+        &mut self,
+        api_key: &str,
+        subaccount_key: &str,
+        update: &UpdateSubaccount,
+    ) -> Result<Subaccount, VonageClientError> {
+        log::debug!("Updating subaccount {}: {:?}", subaccount_key, update);
+        let path = ApiPath::new(
+            ApiFamily::Api,
+            "/accounts/{api_key}/subaccounts/{subaccount_key}",
+        )
+        .param("api_key", api_key)
+        .param("subaccount_key", subaccount_key)
+        .basic_auth();
+        self.patch(path, update)
+            .await
+            .map(Self::debug_response("Update Subaccount Response".into()))
+    }
+
+    async fn list_credit_transfers(
+        &mut self,
+        api_key: &str,
+        filter: &TransferFilter,
+    ) -> Result<Vec<CreditTransfer>, VonageClientError> {
+        log::debug!("Listing credit transfers: {:?}", filter);
+        let path = ApiPath::new(ApiFamily::Api, "/accounts/{api_key}/credit-transfers")
+            .param("api_key", api_key)
+            .query(filter)
+            .basic_auth();
+        self.get::<CreditTransferListResponse>(path)
+            .await
+            .map(CreditTransferListResponse::into_transfers)
+            .map(Self::debug_response(
+                "List Credit Transfers Response".into(),
+            ))
+    }
+
+    async fn transfer_credit(
+        &mut self,
+        api_key: &str,
+        transfer: &Transfer,
+    ) -> Result<CreditTransfer, VonageClientError> {
+        log::debug!("Transferring credit: {:?}", transfer);
+        let path = ApiPath::new(ApiFamily::Api, "/accounts/{api_key}/credit-transfers")
+            .param("api_key", api_key)
+            .basic_auth();
+        self.post(path, transfer)
+            .await
+            .map(Self::debug_response("Transfer Credit Response".into()))
+    }
+
+    async fn list_balance_transfers(
+        &mut self,
+        api_key: &str,
+        filter: &TransferFilter,
+    ) -> Result<Vec<BalanceTransfer>, VonageClientError> {
+        log::debug!("Listing balance transfers: {:?}", filter);
+        let path = ApiPath::new(ApiFamily::Api, "/accounts/{api_key}/balance-transfers")
+            .param("api_key", api_key)
+            .query(filter)
+            .basic_auth();
+        self.get::<BalanceTransferListResponse>(path)
+            .await
+            .map(BalanceTransferListResponse::into_transfers)
+            .map(Self::debug_response(
+                "List Balance Transfers Response".into(),
+            ))
+    }
+
+    async fn transfer_balance(
+        &mut self,
+        api_key: &str,
+        transfer: &Transfer,
+    ) -> Result<BalanceTransfer, VonageClientError> {
+        log::debug!("Transferring balance: {:?}", transfer);
+        let path = ApiPath::new(ApiFamily::Api, "/accounts/{api_key}/balance-transfers")
+            .param("api_key", api_key)
+            .basic_auth();
+        self.post(path, transfer)
+            .await
+            .map(Self::debug_response("Transfer Balance Response".into()))
+    }
+
+    async fn transfer_number(
+        &mut self,
+        api_key: &str,
+        transfer: &NumberTransfer,
+    ) -> Result<NumberTransfer, VonageClientError> {
+        log::debug!("Transferring number: {:?}", transfer);
+        let path = ApiPath::new(ApiFamily::Api, "/accounts/{api_key}/transfer-number")
+            .param("api_key", api_key)
+            .basic_auth();
+        self.post(path, transfer)
+            .await
+            .map(Self::debug_response("Transfer Number Response".into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use crate::client::client_types::SensitiveUnwrap;
+    use crate::client::mock::MockServer;
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    const SUBACCOUNT: &str = r#"{"api_key":"sub1","secret":"Sup3rSecret","primary_account_api_key":"key","use_primary_account_balance":false,"name":"Customer 42","balance":0,"credit_limit":0,"suspended":false,"created_at":"2024-01-01T00:00:00.000Z"}"#;
+
+    #[tokio::test]
+    async fn create_fund_and_suspend_subaccount() {
+        init();
+        let server = MockServer::default()
+            .respond(200, SUBACCOUNT)
+            .respond(
+                200,
+                r#"{"balance_transfer_id":"bt-1","amount":12.5,"from":"key","to":"sub1","reference":"Invoice 42","created_at":"2024-01-01T00:00:00.000Z"}"#,
+            )
+            .respond(200, &SUBACCOUNT.replace(r#""suspended":false"#, r#""suspended":true"#));
+        let mut client = server.client();
+
+        let mut new = NewSubaccount::new("Customer 42".to_string());
+        new.use_primary_account_balance(false);
+        let subaccount = client.create_subaccount("key", &new).await.unwrap();
+        assert_eq!(subaccount.secret.clone().unwrap().unwrap(), "Sup3rSecret");
+
+        let mut transfer = Transfer::new("key".to_string(), subaccount.api_key.clone(), 12.5);
+        transfer.reference("Invoice 42".to_string());
+        let transfer = client.transfer_balance("key", &transfer).await.unwrap();
+        assert_eq!(transfer.amount, 12.5);
+
+        let mut update = UpdateSubaccount::new();
+        update.suspended(true);
+        let subaccount = client
+            .update_subaccount("key", &subaccount.api_key, &update)
+            .await
+            .unwrap();
+        assert!(subaccount.suspended);
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].url.as_str(),
+            "https://api.nexmo.com/accounts/key/subaccounts"
+        );
+        assert_eq!(
+            requests[0].body.as_deref(),
+            Some(r#"{"name":"Customer 42","use_primary_account_balance":false}"#)
+        );
+        assert_eq!(
+            requests[1].path_and_query(),
+            "/accounts/key/balance-transfers"
+        );
+        assert_eq!(requests[2].method, reqwest::Method::PATCH);
+        assert_eq!(
+            requests[2].path_and_query(),
+            "/accounts/key/subaccounts/sub1"
+        );
+        assert_eq!(requests[2].body.as_deref(), Some(r#"{"suspended":true}"#));
+    }
+
+    #[tokio::test]
+    async fn list_subaccounts_and_transfers() {
+        init();
+        let server = MockServer::default()
+            .respond(
+                200,
+                &format!(
+                    r#"{{"_embedded":{{"primary_account":{{"api_key":"key","name":"Acme","balance":100.5,"credit_limit":0,"suspended":false,"created_at":"2023-01-01T00:00:00.000Z"}},"subaccounts":[{}]}},"total_balance":100.5,"total_credit_limit":0}}"#,
+                    SUBACCOUNT.replace(r#""secret":"Sup3rSecret","#, "")
+                ),
+            )
+            .respond(
+                200,
+                r#"{"_embedded":{"credit_transfers":[{"credit_transfer_id":"ct-1","amount":5,"from":"key","to":"sub1","created_at":"2024-01-01T00:00:00.000Z"}]}}"#,
+            );
+        let mut client = server.client();
+
+        let list = client.list_subaccounts("key").await.unwrap();
+        assert_eq!(list.primary_account().balance, Some(100.5));
+        assert!(list.subaccounts()[0].secret.is_none());
+
+        let mut filter = TransferFilter::new("2024-01-01T00:00:00Z".to_string());
+        filter.subaccount("sub1".to_string());
+        let transfers = client.list_credit_transfers("key", &filter).await.unwrap();
+        assert_eq!(transfers[0].reference, None);
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[1].path_and_query(),
+            "/accounts/key/credit-transfers?start_date=2024-01-01T00%3A00%3A00Z&subaccount=sub1"
+        );
+
+        // Requests can be made as the subaccount with its own credentials
+        let mut subaccount_client =
+            client.with_api_credentials("sub1".to_string(), "Sup3rSecret".into());
+        subaccount_client.list_subaccounts("sub1").await.ok();
+        assert_eq!(
+            server.requests()[2].headers.get("authorization").unwrap(),
+            "Basic c3ViMTpTdXAzclNlY3JldA=="
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::client_types::{ApiSecret, SensitiveUnwrap};
+
+/// Subaccount
+/// An account managed by a primary account, with its own API key
+#[derive(Debug, Clone, Deserialize)]
+pub struct Subaccount {
+    pub api_key: String,
+    /// Only returned when the subaccount is created
+    pub secret: Option<ApiSecret>,
+    pub primary_account_api_key: String,
+    pub use_primary_account_balance: bool,
+    pub name: Option<String>,
+    /// `None` when the subaccount uses the balance of the primary account
+    pub balance: Option<f64>,
+    pub credit_limit: Option<f64>,
+    pub suspended: bool,
+    pub created_at: String,
+}
+
+/// Primary Account
+#[derive(Debug, Clone, Deserialize)]
+pub struct PrimaryAccount {
+    pub api_key: String,
+    pub name: Option<String>,
+    pub balance: Option<f64>,
+    pub credit_limit: Option<f64>,
+    pub suspended: bool,
+    pub created_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SubaccountListEmbedded {
+    primary_account: PrimaryAccount,
+    subaccounts: Vec<Subaccount>,
+}
+
+/// Subaccount List
+/// The primary account and every subaccount
+#[derive(Debug, Deserialize)]
+pub struct SubaccountList {
+    #[serde(rename = "_embedded")]
+    embedded: SubaccountListEmbedded,
+    pub total_balance: Option<f64>,
+    pub total_credit_limit: Option<f64>,
+}
+
+impl SubaccountList {
+    pub fn primary_account(&self) -> &PrimaryAccount {
+        &self.embedded.primary_account
+    }
+
+    pub fn subaccounts(&self) -> &[Subaccount] {
+        &self.embedded.subaccounts
+    }
+
+    pub fn into_subaccounts(self) -> Vec<Subaccount> {
+        self.embedded.subaccounts
+    }
+}
+
+/// New Subaccount
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::NewSubaccount;
+/// let mut subaccount = NewSubaccount::new("Customer 42".to_string());
+/// subaccount.use_primary_account_balance(false);
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct NewSubaccount {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    secret: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    use_primary_account_balance: Option<bool>,
+}
+
+impl NewSubaccount {
+    pub fn new(name: String) -> Self {
+        NewSubaccount {
+            name,
+            secret: None,
+            use_primary_account_balance: None,
+        }
+    }
+
+    /// Set the secret of the subaccount, one is generated otherwise
+    pub fn secret(&mut self, secret: ApiSecret) -> &mut Self {
+        self.secret = Some(secret.unwrap());
+        self
+    }
+
+    /// Whether the subaccount spends the balance of the primary account, defaults to true
+    pub fn use_primary_account_balance(&mut self, use_primary_account_balance: bool) -> &mut Self {
+        self.use_primary_account_balance = Some(use_primary_account_balance);
+        self
+    }
+}
+
+/// Update Subaccount
+/// Only the fields that are set are changed
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateSubaccount {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suspended: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    use_primary_account_balance: Option<bool>,
+}
+
+impl UpdateSubaccount {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(&mut self, name: String) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn suspended(&mut self, suspended: bool) -> &mut Self {
+        self.suspended = Some(suspended);
+        self
+    }
+
+    /// A subaccount can only stop using the balance of the primary account, it cannot go back
+    pub fn use_primary_account_balance(&mut self, use_primary_account_balance: bool) -> &mut Self {
+        self.use_primary_account_balance = Some(use_primary_account_balance);
+        self
+    }
+}
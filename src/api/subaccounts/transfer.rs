@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+
+/// Transfer
+/// A credit or balance transfer between the primary account and a subaccount
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::Transfer;
+/// let mut transfer = Transfer::new("primary".to_string(), "subaccount".to_string(), 12.5);
+/// transfer.reference("Invoice 42".to_string());
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct Transfer {
+    from: String,
+    to: String,
+    amount: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reference: Option<String>,
+}
+
+impl Transfer {
+    /// @param from The API key to transfer from
+    /// @param to The API key to transfer to
+    /// @param amount The amount in euros
+    pub fn new(from: String, to: String, amount: f64) -> Self {
+        Transfer {
+            from,
+            to,
+            amount,
+            reference: None,
+        }
+    }
+
+    pub fn reference(&mut self, reference: String) -> &mut Self {
+        self.reference = Some(reference);
+        self
+    }
+}
+
+/// Credit Transfer
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreditTransfer {
+    pub credit_transfer_id: String,
+    pub amount: f64,
+    pub from: String,
+    pub to: String,
+    pub reference: Option<String>,
+    pub created_at: String,
+}
+
+/// Balance Transfer
+#[derive(Debug, Clone, Deserialize)]
+pub struct BalanceTransfer {
+    pub balance_transfer_id: String,
+    pub amount: f64,
+    pub from: String,
+    pub to: String,
+    pub reference: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct CreditTransferList {
+    credit_transfers: Vec<CreditTransfer>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct CreditTransferListResponse {
+    #[serde(rename = "_embedded")]
+    embedded: CreditTransferList,
+}
+
+impl CreditTransferListResponse {
+    pub(crate) fn into_transfers(self) -> Vec<CreditTransfer> {
+        self.embedded.credit_transfers
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct BalanceTransferList {
+    balance_transfers: Vec<BalanceTransfer>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct BalanceTransferListResponse {
+    #[serde(rename = "_embedded")]
+    embedded: BalanceTransferList,
+}
+
+impl BalanceTransferListResponse {
+    pub(crate) fn into_transfers(self) -> Vec<BalanceTransfer> {
+        self.embedded.balance_transfers
+    }
+}
+
+/// Transfer Filter
+/// Filter credit and balance transfers by date and subaccount
+#[derive(Debug, Clone, Serialize)]
+pub struct TransferFilter {
+    start_date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subaccount: Option<String>,
+}
+
+impl TransferFilter {
+    /// @param start_date The ISO 8601 date of the earliest transfer
+    pub fn new(start_date: String) -> Self {
+        TransferFilter {
+            start_date,
+            end_date: None,
+            subaccount: None,
+        }
+    }
+
+    pub fn end_date(&mut self, end_date: String) -> &mut Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Only list the transfers of a subaccount
+    pub fn subaccount(&mut self, api_key: String) -> &mut Self {
+        self.subaccount = Some(api_key);
+        self
+    }
+}
+
+/// Number Transfer
+/// Move a number between the primary account and a subaccount
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NumberTransfer {
+    pub from: String,
+    pub to: String,
+    pub number: String,
+    pub country: String,
+}
+
+impl NumberTransfer {
+    /// @param from The API key that owns the number
+    /// @param to The API key to move the number to
+    /// @param number The number in E.164 format
+    /// @param country The two character country code of the number
+    pub fn new(from: String, to: String, number: String, country: String) -> Self {
+        NumberTransfer {
+            from,
+            to,
+            number,
+            country,
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LayoutType {
+    BestFit,
+    Pip,
+    VerticalPresentation,
+    HorizontalPresentation,
+    Custom,
+}
+
+/// Video Layout
+/// How streams are arranged in a composed archive or broadcast
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoLayout {
+    #[serde(rename = "type")]
+    pub layout_type: LayoutType,
+    /// The CSS of a `LayoutType::Custom` layout
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stylesheet: Option<String>,
+    /// The layout used while a screen is shared, with `LayoutType::BestFit` only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub screenshare_type: Option<LayoutType>,
+}
+
+impl VideoLayout {
+    pub fn new(layout_type: LayoutType) -> Self {
+        VideoLayout {
+            layout_type,
+            stylesheet: None,
+            screenshare_type: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputMode {
+    /// All streams are composed into a single file
+    Composed,
+    /// Each stream is recorded to its own file
+    Individual,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StreamMode {
+    /// Every stream in the session is included
+    Auto,
+    /// Streams are added and removed explicitly
+    Manual,
+}
+
+/// Start Archive
+/// The settings of a new archive
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::video::{OutputMode, StartArchive};
+/// let mut archive = StartArchive::new("session_id".to_string());
+/// archive.name("Consultation 42".to_string()).output_mode(OutputMode::Individual);
+/// ```
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartArchive {
+    session_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_audio: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_video: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_mode: Option<OutputMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resolution: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    layout: Option<VideoLayout>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stream_mode: Option<StreamMode>,
+}
+
+impl StartArchive {
+    /// Create a new StartArchive
+    /// @param session_id The session to archive, it must use `MediaMode::Routed`
+    pub fn new(session_id: String) -> Self {
+        StartArchive {
+            session_id,
+            name: None,
+            has_audio: None,
+            has_video: None,
+            output_mode: None,
+            resolution: None,
+            layout: None,
+            stream_mode: None,
+        }
+    }
+
+    pub fn name(&mut self, name: String) -> &mut Self {
+        self.name = Some(name);
+        self
+    }
+
+    pub fn has_audio(&mut self, has_audio: bool) -> &mut Self {
+        self.has_audio = Some(has_audio);
+        self
+    }
+
+    pub fn has_video(&mut self, has_video: bool) -> &mut Self {
+        self.has_video = Some(has_video);
+        self
+    }
+
+    pub fn output_mode(&mut self, output_mode: OutputMode) -> &mut Self {
+        self.output_mode = Some(output_mode);
+        self
+    }
+
+    /// Set the resolution of a composed archive, e.g. `1280x720`
+    pub fn resolution(&mut self, resolution: String) -> &mut Self {
+        self.resolution = Some(resolution);
+        self
+    }
+
+    pub fn layout(&mut self, layout: VideoLayout) -> &mut Self {
+        self.layout = Some(layout);
+        self
+    }
+
+    pub fn stream_mode(&mut self, stream_mode: StreamMode) -> &mut Self {
+        self.stream_mode = Some(stream_mode);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ArchiveStatus {
+    Started,
+    Paused,
+    Stopped,
+    Uploaded,
+    Available,
+    Expired,
+    Failed,
+    Deleted,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Archive {
+    pub id: String,
+    pub status: ArchiveStatus,
+    pub session_id: String,
+    pub application_id: Option<String>,
+    pub name: Option<String>,
+    /// When the archive started, in milliseconds since the unix epoch
+    pub created_at: Option<u64>,
+    /// The length of the archive in seconds
+    pub duration: Option<u64>,
+    /// The size of the archive in bytes
+    pub size: Option<u64>,
+    /// The download URL of an available archive
+    pub url: Option<String>,
+    pub reason: Option<String>,
+    pub has_audio: Option<bool>,
+    pub has_video: Option<bool>,
+    pub output_mode: Option<OutputMode>,
+    pub resolution: Option<String>,
+    pub stream_mode: Option<StreamMode>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveList {
+    pub count: usize,
+    pub items: Vec<Archive>,
+}
+
+/// List Archives
+/// Narrows the list of archives, archives are listed newest first
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListArchives {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    session_id: Option<String>,
+}
+
+impl ListArchives {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Skip this many archives
+    pub fn offset(&mut self, offset: usize) -> &mut Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Return at most this many archives, up to 1000
+    pub fn count(&mut self, count: usize) -> &mut Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Only list archives of a session
+    pub fn session_id(&mut self, session_id: String) -> &mut Self {
+        self.session_id = Some(session_id);
+        self
+    }
+}
@@ -0,0 +1,156 @@
+use serde::{Deserialize, Serialize};
+
+use super::{StreamMode, VideoLayout};
+
+/// Start Broadcast
+/// The settings of a new live streaming broadcast
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::video::{HlsOutput, RtmpOutput, StartBroadcast};
+/// let mut broadcast = StartBroadcast::new("session_id".to_string());
+/// broadcast.hls(HlsOutput::default()).rtmp(RtmpOutput::new(
+///     "rtmps://live.example.com/app".to_string(),
+///     "stream-key".to_string(),
+/// ));
+/// ```
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartBroadcast {
+    session_id: String,
+    outputs: BroadcastOutputs,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    layout: Option<VideoLayout>,
+    /// The maximum length of the broadcast in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_duration: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    resolution: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stream_mode: Option<StreamMode>,
+}
+
+impl StartBroadcast {
+    /// Create a new StartBroadcast, add at least one HLS or RTMP output
+    /// @param session_id The session to broadcast, it must use `MediaMode::Routed`
+    pub fn new(session_id: String) -> Self {
+        StartBroadcast {
+            session_id,
+            outputs: BroadcastOutputs::default(),
+            layout: None,
+            max_duration: None,
+            resolution: None,
+            stream_mode: None,
+        }
+    }
+
+    pub fn hls(&mut self, hls: HlsOutput) -> &mut Self {
+        self.outputs.hls = Some(hls);
+        self
+    }
+
+    /// Add an RTMP output, up to 5 per broadcast
+    pub fn rtmp(&mut self, rtmp: RtmpOutput) -> &mut Self {
+        self.outputs.rtmp.push(rtmp);
+        self
+    }
+
+    pub fn layout(&mut self, layout: VideoLayout) -> &mut Self {
+        self.layout = Some(layout);
+        self
+    }
+
+    pub fn max_duration(&mut self, max_duration: u64) -> &mut Self {
+        self.max_duration = Some(max_duration);
+        self
+    }
+
+    /// Set the resolution of the broadcast, e.g. `1280x720`
+    pub fn resolution(&mut self, resolution: String) -> &mut Self {
+        self.resolution = Some(resolution);
+        self
+    }
+
+    pub fn stream_mode(&mut self, stream_mode: StreamMode) -> &mut Self {
+        self.stream_mode = Some(stream_mode);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+struct BroadcastOutputs {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hls: Option<HlsOutput>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    rtmp: Vec<RtmpOutput>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HlsOutput {
+    /// Allow viewers to rewind the stream
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dvr: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub low_latency: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RtmpOutput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub server_url: String,
+    pub stream_name: String,
+}
+
+impl RtmpOutput {
+    pub fn new(server_url: String, stream_name: String) -> Self {
+        RtmpOutput {
+            id: None,
+            server_url,
+            stream_name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BroadcastStatus {
+    Started,
+    Stopped,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Broadcast {
+    pub id: String,
+    pub session_id: String,
+    pub application_id: Option<String>,
+    pub status: BroadcastStatus,
+    pub created_at: Option<u64>,
+    pub updated_at: Option<u64>,
+    pub max_duration: Option<u64>,
+    pub resolution: Option<String>,
+    pub broadcast_urls: Option<BroadcastUrls>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BroadcastUrls {
+    /// The URL viewers play the HLS stream from
+    pub hls: Option<String>,
+    #[serde(default)]
+    pub rtmp: Vec<RtmpStatus>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RtmpStatus {
+    pub id: Option<String>,
+    pub server_url: String,
+    pub stream_name: String,
+    /// The status of the stream, e.g. `connecting`, `live` or `offline`
+    pub status: Option<String>,
+}
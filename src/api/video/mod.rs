@@ -0,0 +1,450 @@
+mod archive;
+mod broadcast;
+mod session;
+
+pub use archive::*;
+use async_trait::async_trait;
+pub use broadcast::*;
+use session::MuteSession;
+pub use session::{
+    ArchiveMode, ClientTokenOptions, MediaMode, SessionOptions, Signal, VideoRole, VideoSession,
+};
+
+use crate::client::client_types::Token;
+use crate::client::path::ApiPath;
+use crate::client::{ApiFamily, VonageClient, VonageClientError};
+
+/// The Video API scopes most paths to the application, e.g. `/v2/project/{app_id}/archive`
+fn project_path(client: &VonageClient, template: &'static str) -> Option<ApiPath> {
+    let app_id = client.application_id()?;
+    Some(ApiPath::new(ApiFamily::Video, template).param("app_id", app_id))
+}
+
+fn missing_application_id() -> VonageClientError {
+    log::error!("The Video API requires the client to be built with an app_id");
+    VonageClientError::MissingApplicationId
+}
+
+#[async_trait]
+pub trait VideoApi {
+    /// Create Session
+    /// This function creates a session clients can connect to
+    /// @param options The settings of the session
+    /// @return The created session
+    async fn create_session(
+        &mut self,
+        options: &SessionOptions,
+    ) -> Result<VideoSession, VonageClientError>;
+
+    /// Generate Client Token
+    /// This function signs a token a client SDK uses to connect to a session, no request is made
+    /// @param options The session, role, expiry and connection data of the token
+    /// @return The signed token
+    async fn generate_client_token(
+        &self,
+        options: &ClientTokenOptions,
+    ) -> Result<Token, VonageClientError>;
+
+    /// Start Archive
+    /// This function starts recording a session
+    /// @param archive The settings of the archive
+    /// @return The started archive
+    async fn start_archive(&mut self, archive: &StartArchive)
+        -> Result<Archive, VonageClientError>;
+
+    /// Stop Archive
+    /// This function stops recording a session
+    /// @param archive_id The ID of the archive
+    /// @return The stopped archive
+    async fn stop_archive(&mut self, archive_id: &str) -> Result<Archive, VonageClientError>;
+
+    /// Get Archive
+    /// This function gets an archive
+    /// @param archive_id The ID of the archive
+    /// @return The archive
+    async fn get_archive(&mut self, archive_id: &str) -> Result<Archive, VonageClientError>;
+
+    /// List Archives
+    /// This function lists the archives of the application, newest first
+    /// @param filter The offset, count and session of the archives to list
+    /// @return A page of archives and the total count
+    async fn list_archives(
+        &mut self,
+        filter: &ListArchives,
+    ) -> Result<ArchiveList, VonageClientError>;
+
+    /// Delete Archive
+    /// This function deletes an archive
+    /// @param archive_id The ID of the archive
+    async fn delete_archive(&mut self, archive_id: &str) -> Result<(), VonageClientError>;
+
+    /// Start Broadcast
+    /// This function starts streaming a session to HLS and RTMP outputs
+    /// @param broadcast The settings of the broadcast
+    /// @return The started broadcast
+    async fn start_broadcast(
+        &mut self,
+        broadcast: &StartBroadcast,
+    ) -> Result<Broadcast, VonageClientError>;
+
+    /// Stop Broadcast
+    /// This function stops a broadcast
+    /// @param broadcast_id The ID of the broadcast
+    /// @return The stopped broadcast
+    async fn stop_broadcast(&mut self, broadcast_id: &str) -> Result<Broadcast, VonageClientError>;
+
+    /// Send Signal
+    /// This function sends a signal to every client in a session, or to a single connection
+    /// @param session_id The ID of the session
+    /// @param connection_id The connection to signal, or `None` for every client
+    /// @param signal The signal to send
+    async fn send_signal(
+        &mut self,
+        session_id: &str,
+        connection_id: Option<&str>,
+        signal: &Signal,
+    ) -> Result<(), VonageClientError>;
+
+    /// Force Disconnect
+    /// This function disconnects a client from a session
+    /// @param session_id The ID of the session
+    /// @param connection_id The connection to disconnect
+    async fn force_disconnect(
+        &mut self,
+        session_id: &str,
+        connection_id: &str,
+    ) -> Result<(), VonageClientError>;
+
+    /// Mute Stream
+    /// This function mutes the audio of a single stream
+    /// @param session_id The ID of the session
+    /// @param stream_id The stream to mute
+    async fn mute_stream(
+        &mut self,
+        session_id: &str,
+        stream_id: &str,
+    ) -> Result<(), VonageClientError>;
+
+    /// Mute Session
+    /// This function mutes the audio of every stream in a session, including streams published later
+    /// @param session_id The ID of the session
+    /// @param excluded_stream_ids Streams that stay unmuted, e.g. the moderator
+    async fn mute_session(
+        &mut self,
+        session_id: &str,
+        excluded_stream_ids: Vec<String>,
+    ) -> Result<(), VonageClientError>;
+}
+
+#[async_trait]
+impl VideoApi for VonageClient {
+    async fn create_session(
+        &mut self,
+        options: &SessionOptions,
+    ) -> Result<VideoSession, VonageClientError> {
+        log::debug!("Creating video session: {:?}", options);
+        let path = ApiPath::new(ApiFamily::Video, "/session/create");
+        // The session is returned as the only element of an array
+        let sessions: Vec<VideoSession> = self
+            .post_form(path, options)
+            .await
+            .map(Self::debug_response("Create Session Response".into()))?;
+        sessions.into_iter().next().ok_or_else(|| {
+            log::error!("Create session returned no session");
+            VonageClientError::MissingSession
+        })
+    }
+
+    async fn generate_client_token(
+        &self,
+        options: &ClientTokenOptions,
+    ) -> Result<Token, VonageClientError> {
+        log::debug!("Generating video client token: {:?}", options);
+        let app_id = self
+            .application_id()
+            .ok_or_else(missing_application_id)?
+            .to_string();
+        self.sign_claims(&options.claims(app_id)).await
+    }
+
+    async fn start_archive(
+        &mut self,
+        archive: &StartArchive,
+    ) -> Result<Archive, VonageClientError> {
+        log::debug!("Starting archive: {:?}", archive);
+        let path = project_path(self, "/v2/project/{app_id}/archive")
+            .ok_or_else(missing_application_id)?;
+        self.post(path, archive)
+            .await
+            .map(Self::debug_response("Start Archive Response".into()))
+    }
+
+    async fn stop_archive(&mut self, archive_id: &str) -> Result<Archive, VonageClientError> {
+        log::debug!("Stopping archive: {}", archive_id);
+        let path = project_path(self, "/v2/project/{app_id}/archive/{archive_id}/stop")
+            .ok_or_else(missing_application_id)?
+            .param("archive_id", archive_id);
+        self.post(path, serde_json::json!({}))
+            .await
+            .map(Self::debug_response("Stop Archive Response".into()))
+    }
+
+    async fn get_archive(&mut self, archive_id: &str) -> Result<Archive, VonageClientError> {
+        log::debug!("Getting archive: {}", archive_id);
+        let path = project_path(self, "/v2/project/{app_id}/archive/{archive_id}")
+            .ok_or_else(missing_application_id)?
+            .param("archive_id", archive_id);
+        self.get(path)
+            .await
+            .map(Self::debug_response("Get Archive Response".into()))
+    }
+
+    async fn list_archives(
+        &mut self,
+        filter: &ListArchives,
+    ) -> Result<ArchiveList, VonageClientError> {
+        log::debug!("Listing archives: {:?}", filter);
+        let path = project_path(self, "/v2/project/{app_id}/archive")
+            .ok_or_else(missing_application_id)?
+            .query(filter);
+        self.get(path)
+            .await
+            .map(Self::debug_response("List Archives Response".into()))
+    }
+
+    async fn delete_archive(&mut self, archive_id: &str) -> Result<(), VonageClientError> {
+        log::debug!("Deleting archive: {}", archive_id);
+        let path = project_path(self, "/v2/project/{app_id}/archive/{archive_id}")
+            .ok_or_else(missing_application_id)?
+            .param("archive_id", archive_id);
+        self.delete(path).await
+    }
+
+    async fn start_broadcast(
+        &mut self,
+        broadcast: &StartBroadcast,
+    ) -> Result<Broadcast, VonageClientError> {
+        log::debug!("Starting broadcast: {:?}", broadcast);
+        let path = project_path(self, "/v2/project/{app_id}/broadcast")
+            .ok_or_else(missing_application_id)?;
+        self.post(path, broadcast)
+            .await
+            .map(Self::debug_response("Start Broadcast Response".into()))
+    }
+
+    async fn stop_broadcast(&mut self, broadcast_id: &str) -> Result<Broadcast, VonageClientError> {
+        log::debug!("Stopping broadcast: {}", broadcast_id);
+        let path = project_path(self, "/v2/project/{app_id}/broadcast/{broadcast_id}/stop")
+            .ok_or_else(missing_application_id)?
+            .param("broadcast_id", broadcast_id);
+        self.post(path, serde_json::json!({}))
+            .await
+            .map(Self::debug_response("Stop Broadcast Response".into()))
+    }
+
+    async fn send_signal(
+        &mut self,
+        session_id: &str,
+        connection_id: Option<&str>,
+        signal: &Signal,
+    ) -> Result<(), VonageClientError> {
+        log::debug!("Sending signal to session {}: {:?}", session_id, signal);
+        let path = match connection_id {
+            Some(connection_id) => project_path(
+                self,
+                "/v2/project/{app_id}/session/{session_id}/connection/{connection_id}/signal",
+            )
+            .map(|path| path.param("connection_id", connection_id)),
+            None => project_path(self, "/v2/project/{app_id}/session/{session_id}/signal"),
+        }
+        .ok_or_else(missing_application_id)?
+        .param("session_id", session_id);
+        self.post_no_content(path, signal).await
+    }
+
+    async fn force_disconnect(
+        &mut self,
+        session_id: &str,
+        connection_id: &str,
+    ) -> Result<(), VonageClientError> {
+        log::debug!(
+            "Disconnecting connection {} from session {}",
+            connection_id,
+            session_id
+        );
+        let path = project_path(
+            self,
+            "/v2/project/{app_id}/session/{session_id}/connection/{connection_id}",
+        )
+        .ok_or_else(missing_application_id)?
+        .param("session_id", session_id)
+        .param("connection_id", connection_id);
+        self.delete(path).await
+    }
+
+    async fn mute_stream(
+        &mut self,
+        session_id: &str,
+        stream_id: &str,
+    ) -> Result<(), VonageClientError> {
+        log::debug!("Muting stream {} in session {}", stream_id, session_id);
+        let path = project_path(
+            self,
+            "/v2/project/{app_id}/session/{session_id}/stream/{stream_id}/mute",
+        )
+        .ok_or_else(missing_application_id)?
+        .param("session_id", session_id)
+        .param("stream_id", stream_id);
+        self.post_no_content(path, serde_json::json!({})).await
+    }
+
+    async fn mute_session(
+        &mut self,
+        session_id: &str,
+        excluded_stream_ids: Vec<String>,
+    ) -> Result<(), VonageClientError> {
+        log::debug!("Muting session {}", session_id);
+        let path = project_path(self, "/v2/project/{app_id}/session/{session_id}/mute")
+            .ok_or_else(missing_application_id)?
+            .param("session_id", session_id);
+        let mute = MuteSession {
+            active: true,
+            excluded_stream_ids,
+        };
+        self.post_no_content(path, mute).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use crate::client::client_types::SensitiveUnwrap;
+    use crate::client::mock::MockServer;
+    use crate::client::PrivateKeyTokenProvider;
+    use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+
+    const PRIVATE_KEY: &str = include_str!("../../client/test_data/private_key.pem");
+    const PUBLIC_KEY: &str = include_str!("../../client/test_data/public_key.pem");
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    fn video_client(server: &MockServer) -> VonageClient {
+        VonageClient::builder()
+            .app_id("app-id".to_string())
+            .private_key(PRIVATE_KEY.into())
+            .middleware(server.clone())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn client_token_claims() {
+        init();
+        let client = video_client(&MockServer::default());
+        let mut options = ClientTokenOptions::new("session-id".to_string());
+        options
+            .role(VideoRole::Moderator)
+            .data("name=Dr. Smith".to_string())
+            .initial_layout_class_list(vec!["focus".to_string(), "full".to_string()]);
+
+        let token = client.generate_client_token(&options).await.unwrap();
+        // The clock is mocked to the epoch in tests
+        let mut validation = Validation::new(Algorithm::RS256);
+        validation.validate_exp = false;
+        let claims = jsonwebtoken::decode::<serde_json::Value>(
+            &token.unwrap(),
+            &DecodingKey::from_rsa_pem(PUBLIC_KEY.as_bytes()).unwrap(),
+            &validation,
+        )
+        .unwrap()
+        .claims;
+        assert_eq!(claims["application_id"], "app-id");
+        assert_eq!(claims["sub"], "video");
+        assert_eq!(claims["scope"], "session.connect");
+        assert_eq!(claims["session_id"], "session-id");
+        assert_eq!(claims["role"], "moderator");
+        assert_eq!(claims["data"], "name=Dr. Smith");
+        assert_eq!(claims["initial_layout_class_list"], "focus full");
+        assert!(claims["acl"]["paths"]["/session/**"].is_object());
+
+        // The Video API needs the application ID even when tokens come from a provider
+        let client = VonageClient::builder()
+            .token_provider(PrivateKeyTokenProvider::new(
+                "app-id".to_string(),
+                PRIVATE_KEY.into(),
+                None,
+            ))
+            .build()
+            .unwrap();
+        assert!(matches!(
+            client.generate_client_token(&options).await,
+            Err(VonageClientError::MissingApplicationId)
+        ));
+    }
+
+    #[tokio::test]
+    async fn session_and_archive_requests() {
+        init();
+        let server = MockServer::default()
+            .respond(
+                200,
+                r#"[{"session_id":"session-id","application_id":"app-id","create_dt":"Mon Jan 01 00:00:00 PST 2024","media_server_url":""}]"#,
+            )
+            .respond(
+                200,
+                r#"{"id":"archive-id","status":"started","sessionId":"session-id","createdAt":1704067200000,"outputMode":"composed"}"#,
+            )
+            .respond(204, "");
+        let mut client = video_client(&server);
+
+        let mut options = SessionOptions::new();
+        options
+            .media_mode(MediaMode::Routed)
+            .archive_mode(ArchiveMode::Manual);
+        let session = client.create_session(&options).await.unwrap();
+        let archive = client
+            .start_archive(&StartArchive::new(session.session_id.clone()))
+            .await
+            .unwrap();
+        assert_eq!(archive.status, ArchiveStatus::Started);
+        client
+            .send_signal(
+                &session.session_id,
+                Some("connection-id"),
+                &Signal::new("chat".to_string(), "hello".to_string()),
+            )
+            .await
+            .unwrap();
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].url.as_str(),
+            "https://video.api.vonage.com/session/create"
+        );
+        assert_eq!(
+            requests[0].body.as_deref(),
+            Some("archiveMode=manual&p2p.preference=disabled")
+        );
+        assert_eq!(requests[1].path_and_query(), "/v2/project/app-id/archive");
+        assert_eq!(
+            requests[1].body.as_deref(),
+            Some(r#"{"sessionId":"session-id"}"#)
+        );
+        assert_eq!(
+            requests[2].path_and_query(),
+            "/v2/project/app-id/session/session-id/connection/connection-id/signal"
+        );
+        assert!(requests[2]
+            .headers
+            .get("authorization")
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .starts_with("Bearer "));
+    }
+}
@@ -0,0 +1,210 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::{VonageAcl, VonageJwt};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ArchiveMode {
+    Manual,
+    Always,
+}
+
+/// Media Mode
+/// How streams are sent between clients
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MediaMode {
+    /// Streams are sent through the Vonage Media Router, required for archiving
+    Routed,
+    /// Clients send streams directly to each other where possible
+    Relayed,
+}
+
+impl Serialize for MediaMode {
+    /// The Video API takes the media mode as the `p2p.preference` field
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(match self {
+            MediaMode::Routed => "disabled",
+            MediaMode::Relayed => "enabled",
+        })
+    }
+}
+
+/// Session Options
+/// The settings of a new session
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::video::{ArchiveMode, MediaMode, SessionOptions};
+/// let mut options = SessionOptions::new();
+/// options.media_mode(MediaMode::Routed).archive_mode(ArchiveMode::Always);
+/// ```
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SessionOptions {
+    #[serde(rename = "archiveMode", skip_serializing_if = "Option::is_none")]
+    archive_mode: Option<ArchiveMode>,
+    #[serde(rename = "p2p.preference", skip_serializing_if = "Option::is_none")]
+    media_mode: Option<MediaMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    e2ee: Option<bool>,
+}
+
+impl SessionOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Archive the session automatically, requires `MediaMode::Routed`
+    pub fn archive_mode(&mut self, archive_mode: ArchiveMode) -> &mut Self {
+        self.archive_mode = Some(archive_mode);
+        self
+    }
+
+    pub fn media_mode(&mut self, media_mode: MediaMode) -> &mut Self {
+        self.media_mode = Some(media_mode);
+        self
+    }
+
+    /// Prefer a Media Router close to this IPv4 address
+    pub fn location(&mut self, location: String) -> &mut Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Encrypt media end to end, requires `MediaMode::Routed`
+    pub fn e2ee(&mut self, e2ee: bool) -> &mut Self {
+        self.e2ee = Some(e2ee);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VideoSession {
+    pub session_id: String,
+    pub application_id: Option<String>,
+    pub create_dt: Option<String>,
+    pub media_server_url: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VideoRole {
+    /// Can publish and subscribe to streams
+    Publisher,
+    /// Can only subscribe to streams
+    Subscriber,
+    /// Can publish, subscribe, force disconnect and mute other clients
+    Moderator,
+    /// Can only publish streams
+    PublisherOnly,
+}
+
+/// Client Token Options
+/// The claims of a token a client SDK uses to connect to a session
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::video::{ClientTokenOptions, VideoRole};
+/// let mut options = ClientTokenOptions::new("session_id".to_string());
+/// options
+///     .role(VideoRole::Moderator)
+///     .expires_in(3600)
+///     .data("name=Dr. Smith".to_string());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClientTokenOptions {
+    session_id: String,
+    role: VideoRole,
+    expires_in: usize,
+    data: Option<String>,
+    initial_layout_class_list: Vec<String>,
+}
+
+impl ClientTokenOptions {
+    /// Create a new ClientTokenOptions for a publisher token valid for 24 hours
+    /// @param session_id The session the token connects to
+    pub fn new(session_id: String) -> Self {
+        ClientTokenOptions {
+            session_id,
+            role: VideoRole::Publisher,
+            expires_in: 86400,
+            data: None,
+            initial_layout_class_list: Vec::new(),
+        }
+    }
+
+    pub fn role(&mut self, role: VideoRole) -> &mut Self {
+        self.role = role;
+        self
+    }
+
+    /// Set the lifetime of the token in seconds, at most 30 days
+    pub fn expires_in(&mut self, expires_in: usize) -> &mut Self {
+        self.expires_in = expires_in;
+        self
+    }
+
+    /// Set the connection data other clients see, up to 1000 characters
+    pub fn data(&mut self, data: String) -> &mut Self {
+        self.data = Some(data);
+        self
+    }
+
+    /// Set the layout classes of streams published with the token, used by composed archives and broadcasts
+    pub fn initial_layout_class_list(&mut self, classes: Vec<String>) -> &mut Self {
+        self.initial_layout_class_list = classes;
+        self
+    }
+
+    /// Build the JWT claims of the token
+    /// @param application_id The Vonage Application ID
+    pub(crate) fn claims(&self, application_id: String) -> VonageJwt {
+        let mut acl = VonageAcl::new();
+        acl.add_path("/session/**".to_string(), None);
+        let mut claims =
+            VonageJwt::new_with_sub(application_id, "video".to_string(), Some(self.expires_in));
+        claims
+            .set_acl(acl)
+            .add_claim("scope".to_string(), "session.connect".into())
+            .add_claim("session_id".to_string(), self.session_id.clone().into())
+            .add_claim(
+                "role".to_string(),
+                serde_json::to_value(&self.role).unwrap_or_default(),
+            )
+            .add_claim(
+                "initial_layout_class_list".to_string(),
+                self.initial_layout_class_list.join(" ").into(),
+            );
+        if let Some(data) = &self.data {
+            claims.add_claim("data".to_string(), data.clone().into());
+        }
+        claims
+    }
+}
+
+/// Signal
+/// A message sent to the clients connected to a session
+#[derive(Debug, Clone, Serialize)]
+pub struct Signal {
+    #[serde(rename = "type")]
+    signal_type: String,
+    data: String,
+}
+
+impl Signal {
+    /// Create a new Signal
+    /// @param signal_type The type clients listen for, up to 128 characters
+    /// @param data The payload of the signal, up to 8kB
+    pub fn new(signal_type: String, data: String) -> Self {
+        Signal { signal_type, data }
+    }
+}
+
+/// The body of a request to mute every stream in a session
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MuteSession {
+    pub active: bool,
+    pub excluded_stream_ids: Vec<String>,
+}
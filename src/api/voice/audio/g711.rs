@@ -0,0 +1,166 @@
+//! G.711 mu-law and A-law companding, as used by PSTN and SIP trunks
+
+const MULAW_BIAS: i32 = 0x84;
+const MULAW_CLIP: i32 = 32635;
+
+/// The top of each A-law segment, for 13-bit samples
+const ALAW_SEGMENT_ENDS: [i32; 8] = [0x1F, 0x3F, 0x7F, 0xFF, 0x1FF, 0x3FF, 0x7FF, 0xFFF];
+
+/// Encode a 16-bit linear sample as mu-law
+pub fn linear_to_mulaw(sample: i16) -> u8 {
+    let mut pcm = sample as i32;
+    let sign = if pcm < 0 {
+        pcm = -pcm;
+        0x80
+    } else {
+        0
+    };
+    pcm = pcm.min(MULAW_CLIP) + MULAW_BIAS;
+    let exponent = (0..=7)
+        .rev()
+        .find(|exponent| pcm & (0x80 << exponent) != 0)
+        .unwrap_or(0);
+    let mantissa = (pcm >> (exponent + 3)) & 0x0F;
+    !(sign | (exponent << 4) | mantissa) as u8
+}
+
+/// Decode a mu-law sample to 16-bit linear
+pub fn mulaw_to_linear(mulaw: u8) -> i16 {
+    let mulaw = !mulaw as i32;
+    let exponent = (mulaw >> 4) & 0x07;
+    let mantissa = mulaw & 0x0F;
+    let magnitude = (((mantissa << 3) + MULAW_BIAS) << exponent) - MULAW_BIAS;
+    if mulaw & 0x80 != 0 {
+        -magnitude as i16
+    } else {
+        magnitude as i16
+    }
+}
+
+/// Encode a 16-bit linear sample as A-law
+pub fn linear_to_alaw(sample: i16) -> u8 {
+    let mut pcm = sample as i32 >> 3;
+    let mask = if pcm >= 0 {
+        0xD5
+    } else {
+        pcm = -pcm - 1;
+        0x55
+    };
+    let encoded = match ALAW_SEGMENT_ENDS.iter().position(|&end| pcm <= end) {
+        Some(segment) => {
+            let shift = if segment < 2 { 1 } else { segment };
+            ((segment as i32) << 4) | ((pcm >> shift) & 0x0F)
+        }
+        None => 0x7F,
+    };
+    (encoded ^ mask) as u8
+}
+
+/// Decode an A-law sample to 16-bit linear
+pub fn alaw_to_linear(alaw: u8) -> i16 {
+    let alaw = (alaw ^ 0x55) as i32;
+    let segment = (alaw & 0x70) >> 4;
+    let magnitude = match segment {
+        0 => ((alaw & 0x0F) << 4) + 8,
+        _ => (((alaw & 0x0F) << 4) + 0x108) << (segment - 1),
+    };
+    if alaw & 0x80 != 0 {
+        magnitude as i16
+    } else {
+        -magnitude as i16
+    }
+}
+
+/// Encode 16-bit linear samples as mu-law
+pub fn encode_mulaw(samples: &[i16]) -> Vec<u8> {
+    samples
+        .iter()
+        .map(|&sample| linear_to_mulaw(sample))
+        .collect()
+}
+
+/// Decode mu-law samples to 16-bit linear
+pub fn decode_mulaw(encoded: &[u8]) -> Vec<i16> {
+    encoded
+        .iter()
+        .map(|&sample| mulaw_to_linear(sample))
+        .collect()
+}
+
+/// Encode 16-bit linear samples as A-law
+pub fn encode_alaw(samples: &[i16]) -> Vec<u8> {
+    samples
+        .iter()
+        .map(|&sample| linear_to_alaw(sample))
+        .collect()
+}
+
+/// Decode A-law samples to 16-bit linear
+pub fn decode_alaw(encoded: &[u8]) -> Vec<i16> {
+    encoded
+        .iter()
+        .map(|&sample| alaw_to_linear(sample))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mulaw_known_values() {
+        assert_eq!(linear_to_mulaw(0), 0xFF);
+        assert_eq!(linear_to_mulaw(i16::MAX), 0x80);
+        assert_eq!(linear_to_mulaw(i16::MIN), 0x00);
+        assert_eq!(mulaw_to_linear(0xFF), 0);
+        assert_eq!(mulaw_to_linear(0x80), 32124);
+        assert_eq!(mulaw_to_linear(0x00), -32124);
+    }
+
+    #[test]
+    fn alaw_known_values() {
+        assert_eq!(linear_to_alaw(0), 0xD5);
+        assert_eq!(linear_to_alaw(i16::MAX), 0xAA);
+        assert_eq!(linear_to_alaw(i16::MIN), 0x2A);
+        assert_eq!(alaw_to_linear(0xD5), 8);
+        assert_eq!(alaw_to_linear(0xAA), 32256);
+        assert_eq!(alaw_to_linear(0x2A), -32256);
+    }
+
+    #[test]
+    fn round_trip_within_quantization_error() {
+        for sample in (i16::MIN..=i16::MAX).step_by(7) {
+            // Both laws keep about 13 bits, so the error grows with the magnitude of the sample
+            let tolerance = (sample as i32).abs() / 16 + 16;
+            let mulaw = mulaw_to_linear(linear_to_mulaw(sample)) as i32;
+            assert!(
+                (mulaw - sample as i32).abs() <= tolerance.max(132),
+                "mu-law {}",
+                sample
+            );
+            let alaw = alaw_to_linear(linear_to_alaw(sample)) as i32;
+            assert!(
+                (alaw - sample as i32).abs() <= tolerance,
+                "A-law {}",
+                sample
+            );
+        }
+        // Decoding then encoding is lossless
+        for encoded in 0..=u8::MAX {
+            // Negative zero decodes to zero, which encodes as positive zero
+            let mulaw = if encoded == 0x7F { 0xFF } else { encoded };
+            assert_eq!(linear_to_mulaw(mulaw_to_linear(encoded)), mulaw);
+            assert_eq!(linear_to_alaw(alaw_to_linear(encoded)), encoded);
+        }
+    }
+
+    #[test]
+    fn encode_and_decode_slices() {
+        let samples = [0, 1000, -1000, 20000];
+        assert_eq!(decode_mulaw(&encode_mulaw(&samples)).len(), 4);
+        assert_eq!(
+            decode_alaw(&encode_alaw(&samples)),
+            vec![8, 1008, -1008, 19968]
+        );
+    }
+}
@@ -0,0 +1,155 @@
+/// The root mean square level of samples, from 0 for silence to 1 for a full scale square wave
+/// @param samples The samples, e.g. a 20ms frame
+pub fn rms(samples: &[i16]) -> f64 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let sum: f64 = samples
+        .iter()
+        .map(|&sample| (sample as f64 / i16::MAX as f64).powi(2))
+        .sum();
+    (sum / samples.len() as f64).sqrt().min(1.0)
+}
+
+/// The level of samples in decibels relative to full scale, `f64::NEG_INFINITY` for silence
+/// @param samples The samples, e.g. a 20ms frame
+pub fn dbfs(samples: &[i16]) -> f64 {
+    20.0 * rms(samples).log10()
+}
+
+/// Voice Activity
+/// A change reported by the VoiceActivityDetector
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoiceActivity {
+    /// The caller started speaking
+    Started,
+    /// The caller stopped speaking
+    Stopped,
+}
+
+/// Voice Activity Detector
+/// Detects speech from the level of each frame, speech starts after a number of loud frames and stops after a number
+/// of quiet frames, so short noises and pauses between words are ignored
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::voice::audio::{VoiceActivity, VoiceActivityDetector};
+/// let mut vad = VoiceActivityDetector::new().start_frames(1);
+/// assert_eq!(vad.process(&[8000; 320]), Some(VoiceActivity::Started));
+/// assert!(vad.is_speaking());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct VoiceActivityDetector {
+    threshold: f64,
+    start_frames: usize,
+    stop_frames: usize,
+    speaking: bool,
+    /// Consecutive frames on the other side of the threshold from the current state
+    run: usize,
+}
+
+impl Default for VoiceActivityDetector {
+    fn default() -> Self {
+        VoiceActivityDetector {
+            threshold: -40.0,
+            start_frames: 3,
+            stop_frames: 25,
+            speaking: false,
+            run: 0,
+        }
+    }
+}
+
+impl VoiceActivityDetector {
+    /// Create a new VoiceActivityDetector, frames above -40 dBFS are speech, starting after 60ms and stopping after
+    /// 500ms of 20ms frames
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the level in dBFS above which a frame is speech
+    pub fn threshold(mut self, dbfs: f64) -> Self {
+        self.threshold = dbfs;
+        self
+    }
+
+    /// Set the number of frames of speech before speech starts
+    pub fn start_frames(mut self, frames: usize) -> Self {
+        self.start_frames = frames.max(1);
+        self
+    }
+
+    /// Set the number of quiet frames before speech stops
+    pub fn stop_frames(mut self, frames: usize) -> Self {
+        self.stop_frames = frames.max(1);
+        self
+    }
+
+    pub fn is_speaking(&self) -> bool {
+        self.speaking
+    }
+
+    /// Process the next frame
+    /// @param frame The samples of the frame
+    /// @return The change in activity, if the frame changed it
+    pub fn process(&mut self, frame: &[i16]) -> Option<VoiceActivity> {
+        let loud = dbfs(frame) > self.threshold;
+        if loud == self.speaking {
+            self.run = 0;
+            return None;
+        }
+        self.run += 1;
+        let needed = if self.speaking {
+            self.stop_frames
+        } else {
+            self.start_frames
+        };
+        if self.run < needed {
+            return None;
+        }
+        self.run = 0;
+        self.speaking = loud;
+        Some(if loud {
+            VoiceActivity::Started
+        } else {
+            VoiceActivity::Stopped
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_levels() {
+        assert_eq!(rms(&[]), 0.0);
+        assert_eq!(rms(&[0; 160]), 0.0);
+        assert_eq!(dbfs(&[0; 160]), f64::NEG_INFINITY);
+        assert!((rms(&[i16::MAX, -i16::MAX]) - 1.0).abs() < 1e-9);
+        assert!((dbfs(&[i16::MAX / 10; 160]) + 20.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn detect_voice_activity() {
+        let mut vad = VoiceActivityDetector::new().start_frames(2).stop_frames(3);
+        let speech = [3000; 320];
+        let silence = [10; 320];
+
+        assert_eq!(vad.process(&speech), None);
+        // A single loud frame is noise
+        assert_eq!(vad.process(&silence), None);
+        assert_eq!(vad.process(&speech), None);
+        assert_eq!(vad.process(&speech), Some(VoiceActivity::Started));
+        assert!(vad.is_speaking());
+
+        // A pause between words
+        assert_eq!(vad.process(&silence), None);
+        assert_eq!(vad.process(&silence), None);
+        assert_eq!(vad.process(&speech), None);
+        assert_eq!(vad.process(&silence), None);
+        assert_eq!(vad.process(&silence), None);
+        assert_eq!(vad.process(&silence), Some(VoiceActivity::Stopped));
+        assert!(!vad.is_speaking());
+    }
+}
@@ -0,0 +1,14 @@
+//! Audio
+//! Conversions for the 16-bit linear PCM (L16) audio of websocket legs, all in memory with no I/O but the WAV writer
+mod g711;
+mod level;
+mod resample;
+mod wav;
+
+pub use g711::{
+    alaw_to_linear, decode_alaw, decode_mulaw, encode_alaw, encode_mulaw, linear_to_alaw,
+    linear_to_mulaw, mulaw_to_linear,
+};
+pub use level::{dbfs, rms, VoiceActivity, VoiceActivityDetector};
+pub use resample::{resample, Resampler};
+pub use wav::WavWriter;
@@ -0,0 +1,118 @@
+use crate::voice::AudioFormat;
+
+/// Resampler
+/// Converts a stream of L16 frames between 8kHz and 16kHz, keeping state between frames so there are no clicks at frame edges
+///
+/// Upsampling interpolates between samples, downsampling smooths with a `[1, 2, 1]` filter before dropping every other
+/// sample, which is enough for speech but is not a studio quality filter
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::voice::audio::Resampler;
+/// use vonage_client::AudioFormat;
+/// let mut resampler = Resampler::new(AudioFormat::L16_8K, AudioFormat::L16_16K);
+/// assert_eq!(resampler.process(&[0; 160]).len(), 320);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resampler {
+    from: AudioFormat,
+    to: AudioFormat,
+    /// The last input sample seen
+    last: i16,
+    /// An input sample waiting for the next one, when downsampling an odd number of samples
+    pending: Option<i16>,
+}
+
+impl Resampler {
+    /// Create a new Resampler
+    /// @param from The format of the input
+    /// @param to The format of the output
+    pub fn new(from: AudioFormat, to: AudioFormat) -> Self {
+        Resampler {
+            from,
+            to,
+            last: 0,
+            pending: None,
+        }
+    }
+
+    /// Resample the next samples of the stream
+    /// @param samples The input samples
+    /// @return The output samples, when downsampling an odd sample is held back until the next call
+    pub fn process(&mut self, samples: &[i16]) -> Vec<i16> {
+        match (self.from, self.to) {
+            (AudioFormat::L16_8K, AudioFormat::L16_16K) => {
+                let mut output = Vec::with_capacity(samples.len() * 2);
+                for &sample in samples {
+                    output.push(mean(&[self.last, sample]));
+                    output.push(sample);
+                    self.last = sample;
+                }
+                output
+            }
+            (AudioFormat::L16_16K, AudioFormat::L16_8K) => {
+                let mut output = Vec::with_capacity(samples.len() / 2 + 1);
+                for &sample in samples {
+                    match self.pending.take() {
+                        None => self.pending = Some(sample),
+                        Some(kept) => {
+                            output.push(mean(&[self.last, kept, kept, sample]));
+                            self.last = sample;
+                        }
+                    }
+                }
+                output
+            }
+            _ => samples.to_vec(),
+        }
+    }
+}
+
+fn mean(samples: &[i16]) -> i16 {
+    (samples.iter().map(|&sample| sample as i32).sum::<i32>() / samples.len() as i32) as i16
+}
+
+/// Resample a whole clip
+/// @param samples The input samples
+/// @param from The format of the input
+/// @param to The format of the output
+/// @return The output samples
+pub fn resample(samples: &[i16], from: AudioFormat, to: AudioFormat) -> Vec<i16> {
+    Resampler::new(from, to).process(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resample_8k_to_16k() {
+        let mut resampler = Resampler::new(AudioFormat::L16_8K, AudioFormat::L16_16K);
+        assert_eq!(resampler.process(&[100, 200]), vec![50, 100, 150, 200]);
+        // The first sample of the next frame is interpolated from the last of this one
+        assert_eq!(resampler.process(&[400]), vec![300, 400]);
+    }
+
+    #[test]
+    fn resample_16k_to_8k() {
+        let mut resampler = Resampler::new(AudioFormat::L16_16K, AudioFormat::L16_8K);
+        assert_eq!(resampler.process(&[100, 100, 100, 100, 100]), vec![75, 100]);
+        assert_eq!(resampler.process(&[100]), vec![100]);
+        assert_eq!(resampler.process(&[0; 320]).len(), 160);
+
+        // A tone at the Nyquist frequency of 8kHz is removed
+        let tone: Vec<i16> = (0..320)
+            .map(|n| if n % 2 == 0 { 1000 } else { -1000 })
+            .collect();
+        let output = resample(&tone, AudioFormat::L16_16K, AudioFormat::L16_8K);
+        assert!(output[1..].iter().all(|&sample| sample == 0));
+    }
+
+    #[test]
+    fn resample_same_rate() {
+        assert_eq!(
+            resample(&[1, 2, 3], AudioFormat::L16_8K, AudioFormat::L16_8K),
+            vec![1, 2, 3]
+        );
+    }
+}
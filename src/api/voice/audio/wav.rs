@@ -0,0 +1,161 @@
+use std::fs::File;
+use std::io::{BufWriter, ErrorKind, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use crate::voice::AudioFormat;
+
+/// The size of a canonical PCM WAV header
+const HEADER_SIZE: u32 = 44;
+/// The most audio a WAV file can hold, the RIFF size of the file has to fit in 32 bits
+const MAX_DATA_SIZE: u32 = u32::MAX - (HEADER_SIZE - 8);
+
+/// WAV Writer
+/// Writes frames of L16 audio to a mono 16-bit PCM WAV file, the sizes in the header are filled in by `finish`
+///
+/// ## Example
+/// ```rust
+/// use std::io::Cursor;
+/// use vonage_client::voice::audio::WavWriter;
+/// use vonage_client::AudioFormat;
+/// let mut wav = WavWriter::new(Cursor::new(Vec::new()), AudioFormat::L16_16K).unwrap();
+/// wav.write_frame(&[0; 320]).unwrap();
+/// let bytes = wav.finish().unwrap().into_inner();
+/// assert_eq!(bytes.len(), 44 + 640);
+/// ```
+#[derive(Debug)]
+pub struct WavWriter<W: Write + Seek> {
+    writer: W,
+    data_size: u32,
+}
+
+impl WavWriter<BufWriter<File>> {
+    /// Create a WAV file, replacing any file at the path
+    /// @param path The path of the file
+    /// @param format The format of the frames
+    pub fn create(path: impl AsRef<Path>, format: AudioFormat) -> std::io::Result<Self> {
+        WavWriter::new(BufWriter::new(File::create(path)?), format)
+    }
+}
+
+impl<W: Write + Seek> WavWriter<W> {
+    /// Start a WAV file on a writer
+    /// @param writer The writer, positioned where the file starts
+    /// @param format The format of the frames
+    pub fn new(mut writer: W, format: AudioFormat) -> std::io::Result<Self> {
+        let sample_rate = format.sample_rate();
+        writer.write_all(b"RIFF")?;
+        // The RIFF and data sizes are written by `finish`
+        writer.write_all(&0u32.to_le_bytes())?;
+        writer.write_all(b"WAVEfmt ")?;
+        writer.write_all(&16u32.to_le_bytes())?;
+        // PCM, mono
+        writer.write_all(&1u16.to_le_bytes())?;
+        writer.write_all(&1u16.to_le_bytes())?;
+        writer.write_all(&sample_rate.to_le_bytes())?;
+        // Byte rate, block align and bits per sample
+        writer.write_all(&(sample_rate * 2).to_le_bytes())?;
+        writer.write_all(&2u16.to_le_bytes())?;
+        writer.write_all(&16u16.to_le_bytes())?;
+        writer.write_all(b"data")?;
+        writer.write_all(&0u32.to_le_bytes())?;
+        Ok(WavWriter {
+            writer,
+            data_size: 0,
+        })
+    }
+
+    /// Append a frame of samples
+    /// A frame that would take the file past the 4 GiB limit of WAV is rejected and nothing is written
+    /// @param samples The samples, at the sample rate of the format
+    pub fn write_frame(&mut self, samples: &[i16]) -> std::io::Result<()> {
+        let bytes: Vec<u8> = samples
+            .iter()
+            .flat_map(|sample| sample.to_le_bytes())
+            .collect();
+        let data_size = u32::try_from(bytes.len())
+            .ok()
+            .and_then(|len| self.data_size.checked_add(len))
+            .filter(|size| *size <= MAX_DATA_SIZE)
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    ErrorKind::FileTooLarge,
+                    "WAV audio would exceed the 4 GiB RIFF limit",
+                )
+            })?;
+        self.writer.write_all(&bytes)?;
+        self.data_size = data_size;
+        Ok(())
+    }
+
+    /// Fill in the sizes in the header and flush
+    /// @return The writer, positioned at the end of the file
+    pub fn finish(mut self) -> std::io::Result<W> {
+        let end = self.writer.stream_position()?;
+        let riff_size = (HEADER_SIZE - 8)
+            .checked_add(self.data_size)
+            .ok_or_else(|| invalid_data("WAV audio exceeds the 4 GiB RIFF limit"))?;
+        let start = end
+            .checked_sub(u64::from(HEADER_SIZE) + u64::from(self.data_size))
+            .ok_or_else(|| invalid_data("WAV writer is positioned before the end of its audio"))?;
+        self.writer.seek(SeekFrom::Start(start + 4))?;
+        self.writer.write_all(&riff_size.to_le_bytes())?;
+        self.writer.seek(SeekFrom::Start(start + 40))?;
+        self.writer.write_all(&self.data_size.to_le_bytes())?;
+        self.writer.seek(SeekFrom::Start(end))?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+fn invalid_data(message: &str) -> std::io::Error {
+    std::io::Error::new(ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn write_wav() {
+        let mut wav = WavWriter::new(Cursor::new(Vec::new()), AudioFormat::L16_8K).unwrap();
+        wav.write_frame(&[1, -1]).unwrap();
+        wav.write_frame(&[256]).unwrap();
+        let bytes = wav.finish().unwrap().into_inner();
+
+        assert_eq!(&bytes[0..4], b"RIFF");
+        assert_eq!(u32::from_le_bytes(bytes[4..8].try_into().unwrap()), 42);
+        assert_eq!(&bytes[8..16], b"WAVEfmt ");
+        assert_eq!(u32::from_le_bytes(bytes[24..28].try_into().unwrap()), 8000);
+        assert_eq!(u32::from_le_bytes(bytes[28..32].try_into().unwrap()), 16000);
+        assert_eq!(&bytes[36..40], b"data");
+        assert_eq!(u32::from_le_bytes(bytes[40..44].try_into().unwrap()), 6);
+        assert_eq!(&bytes[44..], &[1, 0, 0xFF, 0xFF, 0, 1]);
+    }
+
+    #[test]
+    fn create_wav_file() {
+        let path = std::env::temp_dir().join(format!("vonage-audio-{}.wav", std::process::id()));
+        let mut wav = WavWriter::create(&path, AudioFormat::L16_16K).unwrap();
+        for _ in 0..50 {
+            wav.write_frame(&[0; 320]).unwrap();
+        }
+        wav.finish().unwrap();
+        assert_eq!(std::fs::metadata(&path).unwrap().len(), 44 + 32000);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn reject_audio_past_riff_limit() {
+        let mut wav = WavWriter::new(Cursor::new(Vec::new()), AudioFormat::L16_8K).unwrap();
+        wav.data_size = MAX_DATA_SIZE - 4;
+        wav.write_frame(&[1, 2]).unwrap();
+        let error = wav.write_frame(&[3]).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::FileTooLarge);
+        assert_eq!(wav.data_size, MAX_DATA_SIZE);
+        assert_eq!(wav.writer.get_ref().len(), 44 + 4);
+
+        // The sizes no longer match what was written, so the header cannot be filled in
+        assert_eq!(wav.finish().unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use crate::client::client_types::redact_number;
 use crate::voice::{
     webhooks::Direction, AudioFormat, EventMethod, MachineDetection, SipHeaders,
     SipStandardHeaders, WebsocketHeaders, NCCO,
@@ -385,7 +386,7 @@ pub enum CreateCallTo {
     Vbc(ToVbc),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CallToPhone {
     number: String,
@@ -393,6 +394,15 @@ pub struct CallToPhone {
     dtmf_answer: Option<String>,
 }
 
+impl std::fmt::Debug for CallToPhone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CallToPhone")
+            .field("number", &redact_number(&self.number))
+            .field("dtmf_answer", &self.dtmf_answer)
+            .finish()
+    }
+}
+
 impl CallToPhone {
     pub fn new(number: String) -> Self {
         Self {
@@ -487,12 +497,20 @@ impl ToVbc {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
 #[serde(tag = "type", rename = "phone", rename_all = "camelCase")]
 pub struct From {
     number: String,
 }
 
+impl std::fmt::Debug for From {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("From")
+            .field("number", &redact_number(&self.number))
+            .finish()
+    }
+}
+
 impl From {
     pub fn new(number: String) -> Self {
         Self { number }
@@ -524,7 +542,15 @@ pub enum CreateCallStatus {
     Ringing,
     Answered,
     Completed,
-    // TODO: Add more statuses for Failed calls
+    Busy,
+    Cancelled,
+    Failed,
+    Rejected,
+    Timeout,
+    Unanswered,
+    /// A status not modeled yet
+    #[serde(other)]
+    Unknown,
 }
 
 #[cfg(test)]
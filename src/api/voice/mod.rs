@@ -1,12 +1,27 @@
 mod create_call;
 mod ncco;
+mod recording;
+mod store;
+mod tracker;
 
+pub mod audio;
 pub mod webhooks;
+#[cfg(feature = "websocket")]
+pub mod websocket;
 use async_trait::async_trait;
 pub use create_call::*;
 pub use ncco::*;
+pub use recording::RecordingDownload;
+pub use store::*;
+pub use tracker::*;
 
-use crate::client::{VonageClient, VonageClientError};
+use std::path::Path;
+
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, RANGE};
+use reqwest::{Method, StatusCode};
+
+use crate::client::path::ApiPath;
+use crate::client::{ApiFamily, VonageClient, VonageClientError};
 
 #[async_trait]
 pub trait VoiceApi {
@@ -19,6 +34,39 @@ pub trait VoiceApi {
         &mut self,
         create_call: CreateCall,
     ) -> Result<CreateCallResponse, VonageClientError>;
+
+    /// Download Recording
+    /// This function starts downloading a recording with the application JWT, the body is streamed as it is read
+    /// @param recording_url The `recording_url` of a record or conversation recording webhook, on a Vonage host
+    /// @return The download
+    async fn download_recording(
+        &mut self,
+        recording_url: &str,
+    ) -> Result<RecordingDownload, VonageClientError>;
+
+    /// Download Recording From
+    /// This function downloads a recording from a byte offset, to resume an interrupted download
+    /// Check `RecordingDownload::offset`, the whole recording is sent if the host does not support ranges
+    /// @param recording_url The `recording_url` of a record or conversation recording webhook, on a Vonage host
+    /// @param offset The number of bytes already downloaded
+    /// @return The download
+    async fn download_recording_from(
+        &mut self,
+        recording_url: &str,
+        offset: u64,
+    ) -> Result<RecordingDownload, VonageClientError>;
+
+    /// Download Recording To File
+    /// This function streams a recording to a file, resuming from the end of the file if it already exists
+    /// The whole recording is downloaded again when the host resumes elsewhere or the file is longer than the recording
+    /// @param recording_url The `recording_url` of a record or conversation recording webhook, on a Vonage host
+    /// @param file_path The path of the file
+    /// @return The size of the file
+    async fn download_recording_to_file(
+        &mut self,
+        recording_url: &str,
+        file_path: &Path,
+    ) -> Result<u64, VonageClientError>;
 }
 
 #[async_trait]
@@ -29,7 +77,93 @@ impl VoiceApi for VonageClient {
         create_call: CreateCall,
     ) -> Result<CreateCallResponse, VonageClientError> {
         log::debug!("Creating outbound call: {:?}", create_call);
-        let path = Self::API_PATH;
+        let path = ApiPath::new(ApiFamily::Voice, Self::API_PATH);
         self.post(path, create_call).await
     }
+
+    async fn download_recording(
+        &mut self,
+        recording_url: &str,
+    ) -> Result<RecordingDownload, VonageClientError> {
+        self.download_recording_from(recording_url, 0).await
+    }
+
+    async fn download_recording_from(
+        &mut self,
+        recording_url: &str,
+        offset: u64,
+    ) -> Result<RecordingDownload, VonageClientError> {
+        log::debug!("Downloading recording: {} from {}", recording_url, offset);
+        let path = recording::recording_path(recording_url).ok_or_else(|| {
+            log::error!("Not a Vonage recording URL: {}", recording_url);
+            VonageClientError::InvalidDownloadUrl(recording_url.to_string())
+        })?;
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_static("*/*"));
+        if offset > 0 {
+            if let Ok(range) = HeaderValue::try_from(format!("bytes={}-", offset)) {
+                headers.insert(RANGE, range);
+            }
+        }
+        self.send_with_headers(Method::GET, path, None, headers)
+            .await
+            .map(RecordingDownload::new)
+    }
+
+    async fn download_recording_to_file(
+        &mut self,
+        recording_url: &str,
+        file_path: &Path,
+    ) -> Result<u64, VonageClientError> {
+        let existing = match tokio::fs::metadata(file_path).await {
+            Ok(metadata) => metadata.len(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => 0,
+            Err(e) => return Err(VonageClientError::Io(e)),
+        };
+        // The download and the position in the file its body starts at
+        let (download, start) = match self.download_recording_from(recording_url, existing).await {
+            Err(VonageClientError::RequestError(StatusCode::RANGE_NOT_SATISFIABLE, response)) => {
+                match recording::unsatisfied_range_total(&response) {
+                    Some(total) if total == existing => {
+                        log::debug!("Recording already downloaded: {}", recording_url);
+                        return Ok(existing);
+                    }
+                    total => {
+                        log::warn!(
+                            "File of {} bytes does not match the recording of {:?} bytes, downloading it again",
+                            existing,
+                            total
+                        );
+                        (self.download_recording(recording_url).await?, 0)
+                    }
+                }
+            }
+            Ok(download) if download.is_partial() && download.offset() != existing => {
+                log::warn!(
+                    "Recording resumed at {} rather than {}, downloading it again",
+                    download.offset(),
+                    existing
+                );
+                (self.download_recording(recording_url).await?, 0)
+            }
+            result => (result?, existing),
+        };
+        if download.is_partial() && download.offset() != start {
+            log::error!("Partial download of recording: {}", recording_url);
+            return Err(VonageClientError::PartialDownload(
+                recording_url.to_string(),
+            ));
+        }
+        let resume = start > 0 && download.is_partial();
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(resume)
+            .truncate(!resume)
+            .open(file_path)
+            .await
+            .map_err(VonageClientError::Io)?;
+        let written = download.write_to(&mut file).await?;
+        Ok(if resume { start + written } else { written })
+    }
 }
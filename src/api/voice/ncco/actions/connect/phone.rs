@@ -1,4 +1,5 @@
 use super::{ConnectOptions, ConnectType, Endpoint};
+use crate::client::client_types::redact_number;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, Default, Clone)]
@@ -24,7 +25,7 @@ impl ConnectType<PhoneEndpoint> for PhoneConnect {
     }
 }
 
-#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Clone)]
 #[serde(rename_all = "camelCase", tag = "type", rename = "phone")]
 pub struct PhoneEndpoint {
     pub number: String,
@@ -34,6 +35,16 @@ pub struct PhoneEndpoint {
     pub on_answer: Option<OnAnswer>,
 }
 
+impl std::fmt::Debug for PhoneEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PhoneEndpoint")
+            .field("number", &redact_number(&self.number))
+            .field("dtmf_answer", &self.dtmf_answer)
+            .field("on_answer", &self.on_answer)
+            .finish()
+    }
+}
+
 impl Endpoint for PhoneEndpoint {}
 impl PhoneEndpoint {
     pub fn dtmf_answer(&mut self, dtmf_answer: String) -> &mut Self {
@@ -42,7 +42,7 @@ impl WebsocketEndpoint {
 
 pub type WebsocketHeaders = HashMap<String, String>;
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AudioFormat {
     #[serde(rename = "audio/l16;rate=16000")]
     L16_16K,
@@ -50,6 +50,29 @@ pub enum AudioFormat {
     L16_8K,
 }
 
+impl AudioFormat {
+    /// Vonage sends and expects audio in 20ms frames
+    pub const FRAME_MILLIS: u64 = 20;
+
+    /// The sample rate in Hz
+    pub fn sample_rate(&self) -> u32 {
+        match self {
+            AudioFormat::L16_16K => 16000,
+            AudioFormat::L16_8K => 8000,
+        }
+    }
+
+    /// The number of 16-bit samples in a 20ms frame
+    pub fn frame_samples(&self) -> usize {
+        self.sample_rate() as usize * Self::FRAME_MILLIS as usize / 1000
+    }
+
+    /// The number of bytes in a 20ms frame
+    pub fn frame_bytes(&self) -> usize {
+        self.frame_samples() * 2
+    }
+}
+
 impl Default for AudioFormat {
     fn default() -> Self {
         AudioFormat::L16_16K
@@ -0,0 +1,357 @@
+use bytes::Bytes;
+use futures::Stream;
+use reqwest::header::{CONTENT_RANGE, CONTENT_TYPE};
+use reqwest::{Response, StatusCode};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use url::Url;
+
+use crate::client::path::ApiPath;
+use crate::client::{is_vonage_url, ApiFamily, VonageClient, VonageClientError};
+
+/// The path of a recording URL from a record or conversation recording webhook
+/// Only HTTPS URLs on a Vonage host are accepted, so the application JWT is never sent elsewhere
+pub(crate) fn recording_path(recording_url: &str) -> Option<ApiPath> {
+    let url = Url::parse(recording_url).ok()?;
+    if !is_vonage_url(&url) {
+        return None;
+    }
+    let origin = Url::parse(&url.origin().ascii_serialization()).ok()?;
+    let mut segments = url.path_segments()?;
+    let template = match (segments.next(), segments.next()) {
+        (Some("v1"), Some("files")) => "/v1/files/{file_id}",
+        (Some("v3"), Some("media")) => "/v3/media/{file_id}",
+        _ => return None,
+    };
+    match (segments.next(), segments.next()) {
+        (Some(file_id), None) if !file_id.is_empty() => Some(
+            ApiPath::new(ApiFamily::Voice, template)
+                .param("file_id", file_id)
+                .origin(origin),
+        ),
+        _ => None,
+    }
+}
+
+/// Recording Download
+/// A recording being downloaded, the body is read as it is consumed rather than held in memory
+#[derive(Debug)]
+pub struct RecordingDownload {
+    response: Response,
+    offset: u64,
+    total_size: Option<u64>,
+}
+
+impl RecordingDownload {
+    pub(crate) fn new(response: Response) -> Self {
+        // `bytes <start>-<end>/<total>` on a partial response
+        let range = response
+            .headers()
+            .get(CONTENT_RANGE)
+            .and_then(|range| range.to_str().ok())
+            .and_then(|range| range.strip_prefix("bytes "))
+            .and_then(|range| range.split_once('/'))
+            .map(|(span, total)| {
+                let start = span
+                    .split_once('-')
+                    .and_then(|(start, _)| start.parse().ok());
+                (start, total.parse().ok())
+            });
+        let (offset, total_size) = match (response.status(), range) {
+            (StatusCode::PARTIAL_CONTENT, Some((start, total))) => (start.unwrap_or(0), total),
+            _ => (0, response.content_length()),
+        };
+        RecordingDownload {
+            response,
+            offset,
+            total_size,
+        }
+    }
+
+    /// Whether the body is only part of the recording, answering a range
+    pub fn is_partial(&self) -> bool {
+        self.response.status() == StatusCode::PARTIAL_CONTENT
+    }
+
+    /// The position in the recording the body starts at, 0 unless a range was requested and honoured
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// The length of the body, if known
+    pub fn content_length(&self) -> Option<u64> {
+        self.response.content_length()
+    }
+
+    /// The size of the whole recording, if known
+    pub fn total_size(&self) -> Option<u64> {
+        self.total_size
+    }
+
+    /// The media type of the recording, e.g. `audio/mpeg`
+    pub fn content_type(&self) -> Option<&str> {
+        self.response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+    }
+
+    /// Read the next chunk of the body
+    /// @return The chunk, or `None` at the end of the body
+    pub async fn chunk(&mut self) -> Result<Option<Bytes>, VonageClientError> {
+        self.response
+            .chunk()
+            .await
+            .map_err(VonageClient::map_parse_error)
+    }
+
+    /// Stream the body in chunks
+    pub fn into_stream(self) -> impl Stream<Item = Result<Bytes, VonageClientError>> + Send {
+        futures::stream::try_unfold(self, |mut download| async move {
+            Ok(download.chunk().await?.map(|chunk| (chunk, download)))
+        })
+    }
+
+    /// Read the whole body into memory
+    pub async fn bytes(mut self) -> Result<Vec<u8>, VonageClientError> {
+        let mut bytes = Vec::with_capacity(self.content_length().unwrap_or(0) as usize);
+        while let Some(chunk) = self.chunk().await? {
+            bytes.extend_from_slice(&chunk);
+        }
+        Ok(bytes)
+    }
+
+    /// Write the body to a writer as it arrives
+    /// @param writer The writer, e.g. a file
+    /// @return The number of bytes written
+    pub async fn write_to<W>(mut self, writer: &mut W) -> Result<u64, VonageClientError>
+    where
+        W: AsyncWrite + Unpin + Send,
+    {
+        let mut written = 0;
+        while let Some(chunk) = self.chunk().await? {
+            writer
+                .write_all(&chunk)
+                .await
+                .map_err(VonageClientError::Io)?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await.map_err(VonageClientError::Io)?;
+        Ok(written)
+    }
+}
+
+/// The size of the recording from the `bytes */<total>` range of a 416 response
+pub(crate) fn unsatisfied_range_total(response: &Response) -> Option<u64> {
+    response
+        .headers()
+        .get(CONTENT_RANGE)?
+        .to_str()
+        .ok()?
+        .strip_prefix("bytes */")?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use crate::client::mock::MockServer;
+    use crate::voice::VoiceApi;
+    use futures::TryStreamExt;
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    const RECORDING_URL: &str =
+        "https://api-us.nexmo.com/v1/files/aaaaaaaa-bbbb-cccc-dddd-0123456789ab";
+
+    #[test]
+    fn validate_recording_urls() {
+        init();
+        let path = recording_path(RECORDING_URL).unwrap();
+        assert_eq!(
+            path.path_and_query(),
+            "/v1/files/aaaaaaaa-bbbb-cccc-dddd-0123456789ab"
+        );
+        assert_eq!(
+            path.origin_url().unwrap().as_str(),
+            "https://api-us.nexmo.com/"
+        );
+        assert!(recording_path("https://api.vonage.com/v3/media/file-1").is_some());
+        for url in [
+            "http://api.nexmo.com/v1/files/aaa",
+            "https://api.nexmo.com.example.com/v1/files/aaa",
+            "https://evilnexmo.com/v1/files/aaa",
+            "https://api.nexmo.com/v1/calls/aaa",
+            "https://api.nexmo.com/v1/files/",
+            "https://api.nexmo.com/v1/files/aaa/../../calls",
+            "not a url",
+        ] {
+            assert!(recording_path(url).is_none(), "{}", url);
+        }
+    }
+
+    #[tokio::test]
+    async fn download_recording() {
+        init();
+        let server = MockServer::default()
+            .respond_with_headers(
+                200,
+                &[("content-type", "audio/mpeg")],
+                b"ID3 recording".to_vec(),
+            )
+            .respond_bytes(200, b"ID3 recording".to_vec());
+        let mut client = server.client();
+
+        let download = client.download_recording(RECORDING_URL).await.unwrap();
+        assert_eq!(download.offset(), 0);
+        assert_eq!(download.total_size(), Some(13));
+        assert_eq!(download.content_type(), Some("audio/mpeg"));
+        assert_eq!(download.bytes().await.unwrap(), b"ID3 recording");
+
+        let chunks: Vec<Bytes> = client
+            .download_recording(RECORDING_URL)
+            .await
+            .unwrap()
+            .into_stream()
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(chunks.concat(), b"ID3 recording");
+
+        let request = &server.requests()[0];
+        assert_eq!(request.url.as_str(), RECORDING_URL);
+        assert_eq!(request.headers["authorization"], "Bearer token");
+        assert!(request.headers.get("range").is_none());
+
+        assert!(matches!(
+            client
+                .download_recording("https://example.com/v1/files/aaa")
+                .await,
+            Err(VonageClientError::InvalidDownloadUrl(_))
+        ));
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn resume_recording_download() {
+        init();
+        let server = MockServer::default()
+            .respond_with_headers(
+                206,
+                &[("content-range", "bytes 4-12/13")],
+                b"recording".to_vec(),
+            )
+            .respond_with_headers(416, &[("content-range", "bytes */13")], Vec::new())
+            .respond_bytes(200, b"ID3 recording".to_vec());
+        let mut client = server.client();
+        let file_path =
+            std::env::temp_dir().join(format!("vonage-recording-{}.mp3", std::process::id()));
+        tokio::fs::write(&file_path, b"ID3 ").await.unwrap();
+
+        let size = client
+            .download_recording_to_file(RECORDING_URL, &file_path)
+            .await
+            .unwrap();
+        assert_eq!(size, 13);
+        assert_eq!(tokio::fs::read(&file_path).await.unwrap(), b"ID3 recording");
+        assert_eq!(server.requests()[0].headers["range"], "bytes=4-");
+
+        // Complete files are left alone
+        let size = client
+            .download_recording_to_file(RECORDING_URL, &file_path)
+            .await
+            .unwrap();
+        assert_eq!(size, 13);
+
+        // A host that ignores the range sends the whole recording, which replaces the file
+        let download = client
+            .download_recording_from(RECORDING_URL, 4)
+            .await
+            .unwrap();
+        assert_eq!(download.offset(), 0);
+        let mut file = tokio::fs::File::create(&file_path).await.unwrap();
+        assert_eq!(download.write_to(&mut file).await.unwrap(), 13);
+        tokio::fs::remove_file(&file_path).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn restart_mismatched_recording_download() {
+        init();
+        let server = MockServer::default()
+            // Resumed from the wrong position
+            .respond_with_headers(
+                206,
+                &[("content-range", "bytes 2-12/13")],
+                b"3 recording".to_vec(),
+            )
+            .respond_bytes(200, b"ID3 recording".to_vec())
+            // The file is longer than the recording
+            .respond_with_headers(416, &[("content-range", "bytes */13")], Vec::new())
+            .respond_bytes(200, b"ID3 recording".to_vec())
+            // A range that is not answered with a total
+            .respond_bytes(416, Vec::new())
+            .respond_bytes(200, b"ID3 recording".to_vec());
+        let mut client = server.client();
+        let file_path = std::env::temp_dir().join(format!(
+            "vonage-recording-restart-{}.mp3",
+            std::process::id()
+        ));
+
+        for existing in [&b"ID3 "[..], b"ID3 recording and more", b"ID3 recording"] {
+            tokio::fs::write(&file_path, existing).await.unwrap();
+            let size = client
+                .download_recording_to_file(RECORDING_URL, &file_path)
+                .await
+                .unwrap();
+            assert_eq!(size, 13);
+            assert_eq!(tokio::fs::read(&file_path).await.unwrap(), b"ID3 recording");
+        }
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 6);
+        assert_eq!(requests[0].headers["range"], "bytes=4-");
+        assert_eq!(requests[2].headers["range"], "bytes=22-");
+        assert_eq!(requests[4].headers["range"], "bytes=13-");
+        for restart in [&requests[1], &requests[3], &requests[5]] {
+            assert!(restart.headers.get("range").is_none());
+        }
+        tokio::fs::remove_file(&file_path).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn reject_partial_restart() {
+        init();
+        let server = MockServer::default()
+            .respond_with_headers(
+                206,
+                &[("content-range", "bytes 2-12/13")],
+                b"3 recording".to_vec(),
+            )
+            .respond_with_headers(
+                206,
+                &[("content-range", "bytes 2-12/13")],
+                b"3 recording".to_vec(),
+            );
+        let file_path = std::env::temp_dir().join(format!(
+            "vonage-recording-partial-{}.mp3",
+            std::process::id()
+        ));
+        tokio::fs::write(&file_path, b"ID3 ").await.unwrap();
+
+        assert!(matches!(
+            server
+                .client()
+                .download_recording_to_file(RECORDING_URL, &file_path)
+                .await,
+            Err(VonageClientError::PartialDownload(_))
+        ));
+        // The file is left as it was
+        assert_eq!(tokio::fs::read(&file_path).await.unwrap(), b"ID3 ");
+        tokio::fs::remove_file(&file_path).await.unwrap();
+    }
+}
@@ -0,0 +1,435 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use super::CreateCallResponse;
+
+/// Event Key
+/// Identifies a webhook delivery, Vonage sends the same uuid, status and timestamp when it retries an event
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EventKey {
+    pub uuid: String,
+    /// The `status` of the event, or its `type` for events without one
+    pub status: String,
+    pub timestamp: String,
+}
+
+impl EventKey {
+    pub fn new(uuid: &str, status: &str, timestamp: &str) -> Self {
+        EventKey {
+            uuid: uuid.to_string(),
+            status: status.to_string(),
+            timestamp: timestamp.to_string(),
+        }
+    }
+
+    /// The key of a raw event, events without a uuid or timestamp cannot be deduplicated
+    /// Input and transfer events have neither a status nor a type, they are keyed as `input` and `transfer`
+    /// @param raw The JSON of the event
+    pub fn from_raw(raw: &Value) -> Option<Self> {
+        let uuid = raw["uuid"].as_str()?;
+        let timestamp = raw["timestamp"].as_str()?;
+        let status = raw["status"].as_str().or(raw["type"].as_str()).unwrap_or(
+            if raw.get("conversation_uuid_to").is_some() {
+                "transfer"
+            } else {
+                "input"
+            },
+        );
+        Some(EventKey::new(uuid, status, timestamp))
+    }
+}
+
+impl std::fmt::Display for EventKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}/{}", self.uuid, self.status, self.timestamp)
+    }
+}
+
+/// Call Record
+/// Something that happened to a call, the store appends these and the call state is folded from them
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CallRecord {
+    /// The response of `create_outbound_call`
+    Call(CreateCallResponse),
+    /// The JSON of a webhook event
+    Event(Value),
+}
+
+/// Call Store Error
+#[derive(Debug)]
+pub enum CallStoreError {
+    Io(std::io::Error),
+    /// A stored call could not be encoded or decoded
+    Serde(serde_json::Error),
+}
+
+impl std::fmt::Display for CallStoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CallStoreError::Io(e) => write!(f, "call store io error: {}", e),
+            CallStoreError::Serde(e) => write!(f, "call store encoding error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for CallStoreError {}
+
+impl From<std::io::Error> for CallStoreError {
+    fn from(e: std::io::Error) -> Self {
+        CallStoreError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for CallStoreError {
+    fn from(e: serde_json::Error) -> Self {
+        CallStoreError::Serde(e)
+    }
+}
+
+/// Call Store
+/// Persists webhook event keys and call records, so retried webhooks are skipped and calls survive restarts
+/// Share one store between replicas to deduplicate across them, records are only appended so replicas never overwrite each other
+#[async_trait]
+pub trait CallStore: Send + Sync + std::fmt::Debug + 'static {
+    /// Record an event key
+    /// @param key The event key
+    /// @return `false` if the key was already recorded, i.e. the event is a duplicate
+    async fn insert_event(&self, key: &EventKey) -> Result<bool, CallStoreError>;
+
+    /// Whether an event key was recorded
+    /// @param key The event key
+    async fn has_event(&self, key: &EventKey) -> Result<bool, CallStoreError>;
+
+    /// Append a record to a call
+    /// @param uuid The call UUID
+    /// @param record The record
+    async fn append_record(&self, uuid: &str, record: &CallRecord) -> Result<(), CallStoreError>;
+
+    /// Load the records of a call in the order they were appended
+    /// @param uuid The call UUID
+    async fn load_records(&self, uuid: &str) -> Result<Vec<CallRecord>, CallStoreError>;
+
+    /// Load the records of every stored call
+    async fn load_calls(&self) -> Result<Vec<Vec<CallRecord>>, CallStoreError>;
+
+    /// Remove the records and event keys of a call, once Vonage has stopped retrying its events
+    /// @param uuid The call UUID
+    async fn remove_call(&self, uuid: &str) -> Result<(), CallStoreError>;
+}
+
+#[derive(Debug, Default)]
+struct MemoryCalls {
+    events: HashMap<String, HashSet<EventKey>>,
+    calls: HashMap<String, Vec<CallRecord>>,
+}
+
+/// Memory Call Store
+/// Keeps event keys and call records in memory, for a single process
+#[derive(Debug, Default)]
+pub struct MemoryCallStore {
+    calls: Mutex<MemoryCalls>,
+}
+
+impl MemoryCallStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MemoryCalls> {
+        self.calls
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[async_trait]
+impl CallStore for MemoryCallStore {
+    async fn insert_event(&self, key: &EventKey) -> Result<bool, CallStoreError> {
+        Ok(self
+            .lock()
+            .events
+            .entry(key.uuid.clone())
+            .or_default()
+            .insert(key.clone()))
+    }
+
+    async fn has_event(&self, key: &EventKey) -> Result<bool, CallStoreError> {
+        Ok(self
+            .lock()
+            .events
+            .get(&key.uuid)
+            .is_some_and(|keys| keys.contains(key)))
+    }
+
+    async fn append_record(&self, uuid: &str, record: &CallRecord) -> Result<(), CallStoreError> {
+        self.lock()
+            .calls
+            .entry(uuid.to_string())
+            .or_default()
+            .push(record.clone());
+        Ok(())
+    }
+
+    async fn load_records(&self, uuid: &str) -> Result<Vec<CallRecord>, CallStoreError> {
+        Ok(self.lock().calls.get(uuid).cloned().unwrap_or_default())
+    }
+
+    async fn load_calls(&self) -> Result<Vec<Vec<CallRecord>>, CallStoreError> {
+        Ok(self.lock().calls.values().cloned().collect())
+    }
+
+    async fn remove_call(&self, uuid: &str) -> Result<(), CallStoreError> {
+        let mut calls = self.lock();
+        calls.events.remove(uuid);
+        calls.calls.remove(uuid);
+        Ok(())
+    }
+}
+
+/// File Call Store
+/// Keeps event keys and call records as files in a directory, which can be shared between replicas
+///
+/// - `calls/<hash>/<index>.json` holds a record of a call, linked into place so a replica never replaces a record of another
+/// - `events/<hash>/<hash>` marks an event key as seen, created with `create_new` so only one replica records it
+///
+/// Names are SHA-256 hashes so UUIDs from webhooks never become paths
+///
+/// ## Example
+/// ```rust
+/// use std::sync::Arc;
+/// use vonage_client::voice::{CallTracker, FileCallStore};
+/// let tracker = CallTracker::new().with_store(Arc::new(FileCallStore::new("/var/lib/my-app/calls")));
+/// ```
+#[derive(Debug)]
+pub struct FileCallStore {
+    root: PathBuf,
+}
+
+static TEMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+fn hash(value: &str) -> String {
+    format!("{:x}", Sha256::digest(value.as_bytes()))
+}
+
+impl FileCallStore {
+    /// Create a new FileCallStore, directories are created on first use
+    /// @param root The directory to store files in
+    pub fn new(root: impl AsRef<Path>) -> Self {
+        FileCallStore {
+            root: root.as_ref().to_path_buf(),
+        }
+    }
+
+    fn calls_dir(&self) -> PathBuf {
+        self.root.join("calls")
+    }
+
+    fn call_dir(&self, uuid: &str) -> PathBuf {
+        self.calls_dir().join(hash(uuid))
+    }
+
+    fn events_dir(&self, uuid: &str) -> PathBuf {
+        self.root.join("events").join(hash(uuid))
+    }
+}
+
+#[async_trait]
+impl CallStore for FileCallStore {
+    async fn insert_event(&self, key: &EventKey) -> Result<bool, CallStoreError> {
+        let dir = self.events_dir(&key.uuid);
+        tokio::fs::create_dir_all(&dir).await?;
+        let created = tokio::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(dir.join(hash(&key.to_string())))
+            .await;
+        match created {
+            Ok(_) => Ok(true),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn has_event(&self, key: &EventKey) -> Result<bool, CallStoreError> {
+        let path = self.events_dir(&key.uuid).join(hash(&key.to_string()));
+        Ok(tokio::fs::try_exists(path).await?)
+    }
+
+    async fn append_record(&self, uuid: &str, record: &CallRecord) -> Result<(), CallStoreError> {
+        let dir = self.call_dir(uuid);
+        tokio::fs::create_dir_all(&dir).await?;
+        // Write then link to the next free index, linking fails if another replica took it first
+        let temp = dir.join(format!(
+            "{}-{}.tmp",
+            std::process::id(),
+            TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        tokio::fs::write(&temp, serde_json::to_vec(record)?).await?;
+        let mut index = record_paths(&dir).await?.len();
+        let linked = loop {
+            match tokio::fs::hard_link(&temp, dir.join(format!("{:020}.json", index))).await {
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => index += 1,
+                linked => break linked,
+            }
+        };
+        tokio::fs::remove_file(&temp).await?;
+        Ok(linked?)
+    }
+
+    async fn load_records(&self, uuid: &str) -> Result<Vec<CallRecord>, CallStoreError> {
+        load_records(&self.call_dir(uuid)).await
+    }
+
+    async fn load_calls(&self) -> Result<Vec<Vec<CallRecord>>, CallStoreError> {
+        let mut entries = match tokio::fs::read_dir(self.calls_dir()).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut calls = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            let records = load_records(&entry.path()).await?;
+            if !records.is_empty() {
+                calls.push(records);
+            }
+        }
+        Ok(calls)
+    }
+
+    async fn remove_call(&self, uuid: &str) -> Result<(), CallStoreError> {
+        for removed in [
+            tokio::fs::remove_dir_all(self.call_dir(uuid)).await,
+            tokio::fs::remove_dir_all(self.events_dir(uuid)).await,
+        ] {
+            match removed {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The record files of a call directory in the order they were appended
+async fn record_paths(dir: &Path) -> Result<Vec<PathBuf>, CallStoreError> {
+    let mut entries = match tokio::fs::read_dir(dir).await {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+    let mut paths = Vec::new();
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            paths.push(path);
+        }
+    }
+    // Indexes are zero padded so they sort by name
+    paths.sort();
+    Ok(paths)
+}
+
+async fn load_records(dir: &Path) -> Result<Vec<CallRecord>, CallStoreError> {
+    let mut records = Vec::new();
+    for path in record_paths(dir).await? {
+        records.push(serde_json::from_slice(&tokio::fs::read(path).await?)?);
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use serde_json::json;
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    async fn check_store(store: &dyn CallStore) {
+        let key = EventKey::new("aaa", "answered", "2024-01-01T00:00:00.000Z");
+        assert!(!store.has_event(&key).await.unwrap());
+        assert!(store.insert_event(&key).await.unwrap());
+        assert!(store.has_event(&key).await.unwrap());
+        assert!(!store.insert_event(&key).await.unwrap());
+        let other = EventKey::new("aaa", "completed", "2024-01-01T00:01:00.000Z");
+        assert!(store.insert_event(&other).await.unwrap());
+
+        let call: CreateCallResponse = serde_json::from_value(json!({
+            "uuid": "aaa",
+            "conversation_uuid": "CON-bbb",
+            "status": "started",
+            "direction": "outbound"
+        }))
+        .unwrap();
+        let records = vec![
+            CallRecord::Call(call),
+            CallRecord::Event(json!({ "uuid": "aaa", "status": "answered" })),
+            CallRecord::Event(json!({ "uuid": "aaa", "status": "completed" })),
+        ];
+        assert!(store.load_records("aaa").await.unwrap().is_empty());
+        for record in &records {
+            store.append_record("aaa", record).await.unwrap();
+        }
+        assert_eq!(store.load_records("aaa").await.unwrap(), records);
+        assert_eq!(store.load_calls().await.unwrap(), vec![records]);
+
+        store.remove_call("aaa").await.unwrap();
+        assert!(store.load_records("aaa").await.unwrap().is_empty());
+        assert!(store.insert_event(&key).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn memory_call_store() {
+        init();
+        check_store(&MemoryCallStore::new()).await;
+    }
+
+    #[tokio::test]
+    async fn file_call_store() {
+        init();
+        let root = std::env::temp_dir().join(format!("vonage-call-store-{}", std::process::id()));
+        check_store(&FileCallStore::new(&root)).await;
+        // A second store on the same directory sees the keys of the first, as a replica would
+        let key = EventKey::new("ccc", "started", "2024-01-01T00:00:00.000Z");
+        assert!(FileCallStore::new(&root).insert_event(&key).await.unwrap());
+        assert!(!FileCallStore::new(&root).insert_event(&key).await.unwrap());
+        tokio::fs::remove_dir_all(&root).await.unwrap();
+    }
+
+    #[test]
+    fn event_key_from_raw() {
+        init();
+        let key = EventKey::from_raw(&json!({
+            "uuid": "aaa",
+            "conversation_uuid_from": "CON-a",
+            "conversation_uuid_to": "CON-b",
+            "timestamp": "2024-01-01T00:00:00.000Z"
+        }))
+        .unwrap();
+        assert_eq!(key.to_string(), "aaa/transfer/2024-01-01T00:00:00.000Z");
+        let key = EventKey::from_raw(&json!({
+            "uuid": "aaa",
+            "type": "talk",
+            "status": "finished",
+            "timestamp": "2024-01-01T00:00:00.000Z"
+        }))
+        .unwrap();
+        assert_eq!(key.status, "finished");
+        assert_eq!(EventKey::from_raw(&json!({ "status": "started" })), None);
+    }
+}
@@ -0,0 +1,781 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::Notify;
+
+use super::webhooks::{
+    CallEventKind, CallEventPayload, CallStatusEvent, Direction, InputEvent, NotifyEvent,
+    PlayEvent, RecordEvent,
+};
+use super::{CallRecord, CallStore, CallStoreError, CreateCallResponse, CreateCallStatus};
+
+impl From<&CreateCallStatus> for CallEventKind {
+    fn from(status: &CreateCallStatus) -> Self {
+        match status {
+            CreateCallStatus::Started => CallEventKind::Started,
+            CreateCallStatus::Ringing => CallEventKind::Ringing,
+            CreateCallStatus::Answered => CallEventKind::Answered,
+            CreateCallStatus::Completed => CallEventKind::Completed,
+            CreateCallStatus::Busy => CallEventKind::Busy,
+            CreateCallStatus::Cancelled => CallEventKind::Cancelled,
+            CreateCallStatus::Failed => CallEventKind::Failed,
+            CreateCallStatus::Rejected => CallEventKind::Rejected,
+            CreateCallStatus::Timeout => CallEventKind::Timeout,
+            CreateCallStatus::Unanswered => CallEventKind::Unanswered,
+            CreateCallStatus::Unknown => CallEventKind::Unknown,
+        }
+    }
+}
+
+/// Call Timeline Entry
+/// An event seen for a call
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CallTimelineEntry {
+    pub kind: CallEventKind,
+    /// The timestamp sent with the event, not set for the create call response
+    pub timestamp: Option<String>,
+}
+
+/// Call State
+/// Everything known about a call leg from its create call response and webhook events
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CallState {
+    pub uuid: String,
+    pub conversation_uuid: Option<String>,
+    pub direction: Option<Direction>,
+    /// The latest call status, input, transfer and play events do not change it
+    pub status: CallEventKind,
+    /// Every event seen for the call in the order it was tracked
+    pub timeline: Vec<CallTimelineEntry>,
+    /// The length of the call in seconds, set once it completes
+    pub duration: Option<usize>,
+    /// The per minute rate
+    pub rate: Option<f64>,
+    /// The total price of the call, set once it completes
+    pub price: Option<f64>,
+}
+
+impl CallState {
+    fn new(uuid: &str, status: CallEventKind) -> Self {
+        CallState {
+            uuid: uuid.to_string(),
+            conversation_uuid: None,
+            direction: None,
+            status,
+            timeline: Vec::new(),
+            duration: None,
+            rate: None,
+            price: None,
+        }
+    }
+
+    /// Whether the call has ended
+    pub fn is_finished(&self) -> bool {
+        self.status.is_finished()
+    }
+
+    /// Whether an event of this kind has been seen for the call
+    pub fn has_seen(&self, kind: CallEventKind) -> bool {
+        self.status == kind || self.timeline.iter().any(|entry| entry.kind == kind)
+    }
+
+    fn record(&mut self, kind: CallEventKind, timestamp: Option<&str>) {
+        self.timeline.push(CallTimelineEntry {
+            kind,
+            timestamp: timestamp.map(str::to_string),
+        });
+    }
+
+    fn set_status(&mut self, status: CallEventKind) {
+        // Events can arrive out of order, a late ringing must not reopen a completed call
+        if !self.status.is_finished() {
+            self.status = status;
+        }
+    }
+}
+
+/// Call Tracker Error
+/// Why waiting for a call status failed
+#[derive(Debug)]
+pub enum CallTrackerError {
+    /// The call ended without reaching the status, with its final state
+    CallEnded(Box<CallState>),
+}
+
+impl std::fmt::Display for CallTrackerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CallTrackerError::CallEnded(state) => {
+                write!(f, "call {} ended with {:?}", state.uuid, state.status)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CallTrackerError {}
+
+#[derive(Debug, Default)]
+struct TrackedCalls {
+    calls: HashMap<String, CallState>,
+    /// The leg UUIDs of each conversation
+    legs: HashMap<String, Vec<String>>,
+}
+
+impl TrackedCalls {
+    fn call(&mut self, uuid: &str, status: CallEventKind) -> &mut CallState {
+        self.calls
+            .entry(uuid.to_string())
+            .or_insert_with(|| CallState::new(uuid, status))
+    }
+
+    fn restore(&mut self, call: CallState) {
+        let uuid = call.uuid.clone();
+        let conversation_uuid = call.conversation_uuid.clone();
+        self.calls.insert(uuid.clone(), call);
+        if let Some(conversation_uuid) = conversation_uuid {
+            self.join(&uuid, &conversation_uuid);
+        }
+    }
+
+    fn track_call(&mut self, response: &CreateCallResponse) {
+        let status = CallEventKind::from(response.status());
+        self.join(response.uuid(), response.conversation_uuid());
+        let call = self.call(response.uuid(), status);
+        call.direction = Some(response.direction().clone());
+        call.record(status, None);
+        call.set_status(status);
+    }
+
+    fn track_event(&mut self, event: &CallEventPayload) {
+        let kind = event.kind();
+        match event {
+            CallEventPayload::CallStatus(status) => track_status(self, kind, status),
+            CallEventPayload::Input(
+                InputEvent::DTMF {
+                    uuid,
+                    conversation_uuid,
+                    timestamp,
+                    ..
+                }
+                | InputEvent::Speech {
+                    uuid,
+                    conversation_uuid,
+                    timestamp,
+                    ..
+                },
+            )
+            | CallEventPayload::Play(
+                PlayEvent::Talk {
+                    uuid,
+                    conversation_uuid,
+                    timestamp,
+                    ..
+                }
+                | PlayEvent::Stream {
+                    uuid,
+                    conversation_uuid,
+                    timestamp,
+                    ..
+                },
+            ) => {
+                self.join(uuid, conversation_uuid);
+                self.call(uuid, CallEventKind::Unknown)
+                    .record(kind, Some(timestamp));
+            }
+            CallEventPayload::Transfer(transfer) => {
+                self.join(&transfer.uuid, &transfer.conversation_uuid_to);
+                self.call(&transfer.uuid, CallEventKind::Unknown)
+                    .record(kind, Some(&transfer.timestamp));
+            }
+            CallEventPayload::Record(RecordEvent {
+                conversation_uuid, ..
+            })
+            | CallEventPayload::Notify(NotifyEvent {
+                conversation_uuid, ..
+            }) => log::debug!(
+                "Not tracking event of conversation {} without a uuid",
+                conversation_uuid
+            ),
+            CallEventPayload::Input(InputEvent::Unknown(raw))
+            | CallEventPayload::Play(PlayEvent::Unknown(raw))
+            | CallEventPayload::Unknown(raw) => match raw["uuid"].as_str() {
+                Some(uuid) => self
+                    .call(uuid, CallEventKind::Unknown)
+                    .record(kind, raw["timestamp"].as_str()),
+                None => log::debug!("Not tracking event without a uuid: {}", raw),
+            },
+        }
+    }
+
+    fn join(&mut self, uuid: &str, conversation_uuid: &str) {
+        let previous = self
+            .call(uuid, CallEventKind::Unknown)
+            .conversation_uuid
+            .replace(conversation_uuid.to_string());
+        if let Some(previous) = previous.filter(|previous| previous != conversation_uuid) {
+            if let Some(legs) = self.legs.get_mut(&previous) {
+                legs.retain(|leg| leg != uuid);
+            }
+        }
+        let legs = self.legs.entry(conversation_uuid.to_string()).or_default();
+        if !legs.iter().any(|leg| leg == uuid) {
+            legs.push(uuid.to_string());
+        }
+    }
+}
+
+/// Call Tracker
+/// Aggregates create call responses and webhook events into the state of each call, keyed by call UUID
+/// Clones share the same state, so one tracker can be fed from the event webhook and awaited elsewhere
+/// With a `CallStore`, use the `record_` methods to append each change to the store, the state of the call is then
+/// folded from every record in the store so it survives restarts and includes changes recorded by other replicas
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::voice::webhooks::CallEventKind;
+/// use vonage_client::voice::CallTracker;
+/// # async fn example(tracker: CallTracker) {
+/// // after create_outbound_call, with the event webhook forwarding events to `tracker.track_event`
+/// let answered = tokio::time::timeout(
+///     std::time::Duration::from_secs(60),
+///     tracker.wait_for("aaaaaaaa-bbbb-cccc-dddd-0123456789ab", CallEventKind::Answered),
+/// )
+/// .await;
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CallTracker {
+    calls: Arc<Mutex<TrackedCalls>>,
+    changed: Arc<Notify>,
+    store: Option<Arc<dyn CallStore>>,
+}
+
+impl CallTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Save call state to a store
+    /// @param store The store
+    pub fn with_store(mut self, store: Arc<dyn CallStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Load every call in the store, replacing tracked state for those calls
+    /// @return The number of calls loaded
+    pub async fn restore(&self) -> Result<usize, CallStoreError> {
+        let Some(store) = &self.store else {
+            return Ok(0);
+        };
+        let calls = store.load_calls().await?;
+        let count = calls.len();
+        log::debug!("Restoring {} calls", count);
+        let folded: Vec<_> = calls.into_iter().flat_map(fold).collect();
+        self.update(|tracked| folded.into_iter().for_each(|call| tracked.restore(call)));
+        Ok(count)
+    }
+
+    /// Track a call created with `create_outbound_call` and append it to the store
+    /// @param response The create call response
+    pub async fn record_call(&self, response: &CreateCallResponse) -> Result<(), CallStoreError> {
+        match &self.store {
+            Some(store) => {
+                store
+                    .append_record(response.uuid(), &CallRecord::Call(response.clone()))
+                    .await?;
+                self.refresh(store.as_ref(), response.uuid()).await
+            }
+            None => {
+                self.track_call(response);
+                Ok(())
+            }
+        }
+    }
+
+    /// Track a webhook event and append it to the store
+    /// The call is then folded from the store, so events recorded by other replicas are included
+    /// Retried deliveries are tracked again, use `VoiceWebhooks::deduplicate` to skip them
+    /// @param event The event
+    pub async fn record_event(&self, event: &CallEventPayload) -> Result<(), CallStoreError> {
+        let Some(store) = &self.store else {
+            self.track_event(event);
+            return Ok(());
+        };
+        let raw = serde_json::to_value(event)?;
+        let Some(uuid) = raw["uuid"].as_str().map(str::to_string) else {
+            log::debug!("Not storing event without a uuid: {}", raw);
+            self.track_event(event);
+            return Ok(());
+        };
+        store.append_record(&uuid, &CallRecord::Event(raw)).await?;
+        self.refresh(store.as_ref(), &uuid).await
+    }
+
+    /// Track a call created with `create_outbound_call`
+    /// @param response The create call response
+    pub fn track_call(&self, response: &CreateCallResponse) {
+        log::debug!("Tracking call {}", response.uuid());
+        self.update(|calls| calls.track_call(response));
+    }
+
+    /// Track a webhook event, events without a call UUID are ignored
+    /// @param event The event
+    pub fn track_event(&self, event: &CallEventPayload) {
+        self.update(|calls| calls.track_event(event));
+    }
+
+    /// The state of a call
+    /// @param uuid The call UUID
+    pub fn call(&self, uuid: &str) -> Option<CallState> {
+        self.lock().calls.get(uuid).cloned()
+    }
+
+    /// The UUIDs of the legs in a conversation, in the order they joined
+    /// @param conversation_uuid The conversation UUID
+    pub fn legs(&self, conversation_uuid: &str) -> Vec<String> {
+        self.lock()
+            .legs
+            .get(conversation_uuid)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Stop tracking a call and return its final state
+    /// @param uuid The call UUID
+    pub fn remove(&self, uuid: &str) -> Option<CallState> {
+        let mut calls = self.lock();
+        let state = calls.calls.remove(uuid)?;
+        if let Some(legs) = state
+            .conversation_uuid
+            .as_ref()
+            .and_then(|conversation_uuid| calls.legs.get_mut(conversation_uuid))
+        {
+            legs.retain(|leg| leg != uuid);
+        }
+        Some(state)
+    }
+
+    /// Wait until a call has seen an event of a kind, returns at once if it already has
+    /// The call does not need to be tracked yet, wrap this in `tokio::time::timeout` to bound the wait
+    /// @param uuid The call UUID
+    /// @param kind The kind of event to wait for, e.g. `CallEventKind::Answered`
+    /// @return The state of the call once the event is seen, or an error if the call finished without it
+    pub async fn wait_for(
+        &self,
+        uuid: &str,
+        kind: CallEventKind,
+    ) -> Result<CallState, CallTrackerError> {
+        loop {
+            // Register interest before checking so an update between the check and the await is not missed
+            let changed = self.changed.notified();
+            tokio::pin!(changed);
+            changed.as_mut().enable();
+            if let Some(state) = self.call(uuid) {
+                if state.has_seen(kind) {
+                    return Ok(state);
+                }
+                if state.is_finished() {
+                    return Err(CallTrackerError::CallEnded(Box::new(state)));
+                }
+            }
+            changed.await;
+        }
+    }
+
+    /// Replace the tracked state of a call with the state folded from its records in the store
+    async fn refresh(&self, store: &dyn CallStore, uuid: &str) -> Result<(), CallStoreError> {
+        let folded = fold(store.load_records(uuid).await?);
+        self.update(|tracked| folded.into_iter().for_each(|call| tracked.restore(call)));
+        Ok(())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, TrackedCalls> {
+        // The state is only mutated in short sections without panics, a poisoned lock is still consistent
+        self.calls
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn update(&self, update: impl FnOnce(&mut TrackedCalls)) {
+        update(&mut self.lock());
+        self.changed.notify_waiters();
+    }
+}
+
+/// Fold the records of a call into its state
+fn fold(records: Vec<CallRecord>) -> Vec<CallState> {
+    let mut calls = TrackedCalls::default();
+    for record in records {
+        match record {
+            CallRecord::Call(response) => calls.track_call(&response),
+            CallRecord::Event(raw) => match serde_json::from_value(raw) {
+                Ok(event) => calls.track_event(&event),
+                Err(e) => log::error!("Error decoding stored event: {}", e),
+            },
+        }
+    }
+    calls.calls.into_values().collect()
+}
+
+fn track_status(calls: &mut TrackedCalls, kind: CallEventKind, event: &CallStatusEvent) {
+    match event {
+        CallStatusEvent::Started {
+            uuid,
+            conversation_uuid,
+            direction,
+            timestamp,
+            ..
+        }
+        | CallStatusEvent::Ringing {
+            uuid,
+            conversation_uuid,
+            direction,
+            timestamp,
+            ..
+        }
+        | CallStatusEvent::Busy {
+            uuid,
+            conversation_uuid,
+            direction,
+            timestamp,
+            ..
+        }
+        | CallStatusEvent::Cancelled {
+            uuid,
+            conversation_uuid,
+            direction,
+            timestamp,
+            ..
+        }
+        | CallStatusEvent::Unanswered {
+            uuid,
+            conversation_uuid,
+            direction,
+            timestamp,
+            ..
+        }
+        | CallStatusEvent::Disconnected {
+            uuid,
+            conversation_uuid,
+            direction,
+            timestamp,
+            ..
+        }
+        | CallStatusEvent::Redirected {
+            uuid,
+            conversation_uuid,
+            direction,
+            timestamp,
+            ..
+        }
+        | CallStatusEvent::Rejected {
+            uuid,
+            conversation_uuid,
+            direction,
+            timestamp,
+            ..
+        }
+        | CallStatusEvent::Failed {
+            uuid,
+            conversation_uuid,
+            direction,
+            timestamp,
+            ..
+        }
+        | CallStatusEvent::Human {
+            uuid,
+            conversation_uuid,
+            direction,
+            timestamp,
+            ..
+        }
+        | CallStatusEvent::Machine {
+            uuid,
+            conversation_uuid,
+            direction,
+            timestamp,
+            ..
+        }
+        | CallStatusEvent::Timeout {
+            uuid,
+            conversation_uuid,
+            direction,
+            timestamp,
+            ..
+        } => {
+            let call = track_call_status(calls, kind, uuid, conversation_uuid, timestamp);
+            call.direction = Some(direction.clone());
+        }
+        CallStatusEvent::Answered {
+            uuid,
+            conversation_uuid,
+            direction,
+            timestamp,
+            rate,
+            ..
+        } => {
+            let call = track_call_status(calls, kind, uuid, conversation_uuid, timestamp);
+            call.direction = Some(direction.clone());
+            call.rate = rate.or(call.rate);
+        }
+        CallStatusEvent::Completed {
+            uuid,
+            conversation_uuid,
+            direction,
+            timestamp,
+            duration,
+            rate,
+            price,
+            ..
+        } => {
+            let call = track_call_status(calls, kind, uuid, conversation_uuid, timestamp);
+            call.direction = Some(direction.clone());
+            call.duration = *duration;
+            call.rate = rate.or(call.rate);
+            call.price = *price;
+        }
+        CallStatusEvent::Unknown(raw) => match raw["uuid"].as_str() {
+            Some(uuid) => calls
+                .call(uuid, CallEventKind::Unknown)
+                .record(kind, raw["timestamp"].as_str()),
+            None => log::debug!("Not tracking status without a uuid: {}", raw),
+        },
+    }
+}
+
+fn track_call_status<'a>(
+    calls: &'a mut TrackedCalls,
+    kind: CallEventKind,
+    uuid: &str,
+    conversation_uuid: &str,
+    timestamp: &str,
+) -> &'a mut CallState {
+    calls.join(uuid, conversation_uuid);
+    let call = calls.call(uuid, kind);
+    call.record(kind, Some(timestamp));
+    call.set_status(kind);
+    call
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use serde_json::json;
+    use std::time::Duration;
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    fn status(status: &str, uuid: &str, extra: serde_json::Value) -> CallEventPayload {
+        let mut event = json!({
+            "status": status,
+            "from": "447700900001",
+            "to": "447700900000",
+            "uuid": uuid,
+            "conversation_uuid": "CON-bbb",
+            "direction": "outbound",
+            "timestamp": format!("2024-01-01T00:00:0{}.000Z", status.len()),
+        });
+        if let (Some(event), Some(extra)) = (event.as_object_mut(), extra.as_object()) {
+            event.extend(extra.clone());
+        }
+        serde_json::from_value(event).unwrap()
+    }
+
+    #[tokio::test]
+    async fn track_call_events() {
+        init();
+        let tracker = CallTracker::new();
+        let response: CreateCallResponse = serde_json::from_value(json!({
+            "uuid": "aaa",
+            "conversation_uuid": "CON-bbb",
+            "status": "started",
+            "direction": "outbound"
+        }))
+        .unwrap();
+        tracker.track_call(&response);
+
+        let waiter = tokio::spawn({
+            let tracker = tracker.clone();
+            async move { tracker.wait_for("aaa", CallEventKind::Answered).await }
+        });
+        tracker.track_event(&status("ringing", "aaa", json!({})));
+        tracker.track_event(&status("answered", "aaa", json!({ "rate": "0.012" })));
+        tracker.track_event(&status("answered", "ccc", json!({})));
+        let answered = tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(answered.status, CallEventKind::Answered);
+        assert_eq!(answered.rate, Some(0.012));
+
+        tracker.track_event(&status(
+            "completed",
+            "aaa",
+            json!({ "duration": "60", "price": "0.012" }),
+        ));
+        // A late event does not reopen the call
+        tracker.track_event(&status("ringing", "aaa", json!({})));
+
+        let call = tracker.call("aaa").unwrap();
+        assert_eq!(call.status, CallEventKind::Completed);
+        assert_eq!(call.duration, Some(60));
+        assert_eq!(call.price, Some(0.012));
+        assert_eq!(
+            call.timeline
+                .iter()
+                .map(|entry| entry.kind)
+                .collect::<Vec<_>>(),
+            vec![
+                CallEventKind::Started,
+                CallEventKind::Ringing,
+                CallEventKind::Answered,
+                CallEventKind::Completed,
+                CallEventKind::Ringing,
+            ]
+        );
+        assert_eq!(call.timeline[0].timestamp, None);
+        assert_eq!(tracker.legs("CON-bbb"), vec!["aaa", "ccc"]);
+
+        let answered = tracker.wait_for("aaa", CallEventKind::Answered).await;
+        assert!(answered.is_ok());
+    }
+
+    #[tokio::test]
+    async fn wait_for_finished_call() {
+        init();
+        let tracker = CallTracker::new();
+        let waiter = tokio::spawn({
+            let tracker = tracker.clone();
+            async move { tracker.wait_for("aaa", CallEventKind::Answered).await }
+        });
+        tokio::task::yield_now().await;
+        tracker.track_event(&status("busy", "aaa", json!({})));
+        match tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .unwrap()
+            .unwrap()
+        {
+            Err(CallTrackerError::CallEnded(state)) => {
+                assert_eq!(state.status, CallEventKind::Busy)
+            }
+            other => panic!("Unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn track_transfers() {
+        init();
+        let tracker = CallTracker::new();
+        tracker.track_event(&status("answered", "aaa", json!({})));
+        let transfer = serde_json::from_value(json!({
+            "conversation_uuid_from": "CON-bbb",
+            "conversation_uuid_to": "CON-ddd",
+            "uuid": "aaa",
+            "timestamp": "2024-01-01T00:01:00.000Z"
+        }))
+        .unwrap();
+        tracker.track_event(&transfer);
+
+        assert!(tracker.legs("CON-bbb").is_empty());
+        assert_eq!(tracker.legs("CON-ddd"), vec!["aaa"]);
+        let call = tracker.remove("aaa").unwrap();
+        assert_eq!(call.conversation_uuid.as_deref(), Some("CON-ddd"));
+        assert_eq!(call.status, CallEventKind::Answered);
+        assert!(tracker.legs("CON-ddd").is_empty());
+    }
+
+    #[tokio::test]
+    async fn restore_from_store() {
+        init();
+        let store: Arc<dyn CallStore> = Arc::new(crate::voice::MemoryCallStore::new());
+        let tracker = CallTracker::new().with_store(store.clone());
+        tracker
+            .record_event(&status("answered", "aaa", json!({})))
+            .await
+            .unwrap();
+        assert_eq!(store.load_records("aaa").await.unwrap().len(), 1);
+
+        // Another replica picks up where the first left off
+        let replica = CallTracker::new().with_store(store.clone());
+        replica
+            .record_event(&status("completed", "aaa", json!({ "duration": "60" })))
+            .await
+            .unwrap();
+        let call = replica.call("aaa").unwrap();
+        assert_eq!(call.status, CallEventKind::Completed);
+        assert_eq!(call.timeline.len(), 2);
+
+        let restarted = CallTracker::new().with_store(store);
+        assert_eq!(restarted.restore().await.unwrap(), 1);
+        assert_eq!(restarted.call("aaa").unwrap().duration, Some(60));
+        assert_eq!(restarted.legs("CON-bbb"), vec!["aaa"]);
+    }
+
+    async fn interleave_replicas(store: Arc<dyn CallStore>) {
+        let first = CallTracker::new().with_store(store.clone());
+        let second = CallTracker::new().with_store(store.clone());
+        let response: CreateCallResponse = serde_json::from_value(json!({
+            "uuid": "aaa",
+            "conversation_uuid": "CON-bbb",
+            "status": "started",
+            "direction": "outbound"
+        }))
+        .unwrap();
+        first.record_call(&response).await.unwrap();
+        second
+            .record_event(&status("ringing", "aaa", json!({})))
+            .await
+            .unwrap();
+        // The first replica has not seen the ringing event, its answered event must not drop it
+        first
+            .record_event(&status("answered", "aaa", json!({ "rate": "0.012" })))
+            .await
+            .unwrap();
+        // Both replicas append at once
+        let machine = status("machine", "aaa", json!({}));
+        let completed = status("completed", "aaa", json!({ "duration": "60" }));
+        let (machine, completed) = tokio::join!(
+            first.record_event(&machine),
+            second.record_event(&completed)
+        );
+        machine.unwrap();
+        completed.unwrap();
+
+        let restarted = CallTracker::new().with_store(store);
+        restarted.restore().await.unwrap();
+        let call = restarted.call("aaa").unwrap();
+        let kinds: Vec<_> = call.timeline.iter().map(|entry| entry.kind).collect();
+        assert_eq!(
+            kinds[..3],
+            [
+                CallEventKind::Started,
+                CallEventKind::Ringing,
+                CallEventKind::Answered
+            ]
+        );
+        assert_eq!(kinds.len(), 5);
+        assert!(kinds[3..].contains(&CallEventKind::Machine));
+        assert!(kinds[3..].contains(&CallEventKind::Completed));
+        assert_eq!(call.status, CallEventKind::Completed);
+        assert_eq!(call.rate, Some(0.012));
+        assert_eq!(call.duration, Some(60));
+        assert_eq!(second.call("aaa").unwrap().rate, Some(0.012));
+    }
+
+    #[tokio::test]
+    async fn interleaved_replicas_keep_every_event() {
+        init();
+        interleave_replicas(Arc::new(crate::voice::MemoryCallStore::new())).await;
+        let root = std::env::temp_dir().join(format!("vonage-call-tracker-{}", std::process::id()));
+        interleave_replicas(Arc::new(crate::voice::FileCallStore::new(&root))).await;
+        tokio::fs::remove_dir_all(&root).await.unwrap();
+    }
+}
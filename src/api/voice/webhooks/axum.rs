@@ -0,0 +1,198 @@
+//! Serve Voice webhooks with axum
+//!
+//! ## Example
+//! ```rust,ignore
+//! use vonage_client::voice::webhooks::{axum::voice_router, VoiceWebhooks};
+//! let app = axum::Router::new().nest("/voice", voice_router(VoiceWebhooks::new(Greeter)));
+//! ```
+
+use ::axum::body::Bytes;
+use ::axum::extract::{FromRequest, Request, State};
+use ::axum::http::header::AUTHORIZATION;
+use ::axum::http::{HeaderMap, Method, StatusCode, Uri};
+use ::axum::response::{IntoResponse, Response};
+use ::axum::routing::get;
+use ::axum::{Json, Router};
+use serde::de::DeserializeOwned;
+
+use super::{VoiceWebhook, VoiceWebhookHandler, VoiceWebhooks, WebhookError, WebhookRequest};
+use crate::voice::EventMethod;
+
+impl IntoResponse for WebhookError {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status_code()).unwrap_or(StatusCode::BAD_REQUEST);
+        (status, self.to_string()).into_response()
+    }
+}
+
+/// Voice Webhook Extractor
+/// Decodes a webhook payload from the query string of a GET request or the JSON body of a POST request,
+/// signatures are not checked, use `voice_router` with `VoiceWebhooks::verify_signatures` for that
+pub struct VoiceWebhookPayload<T>(pub T);
+
+impl<S, T> FromRequest<S> for VoiceWebhookPayload<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let method = req.method().clone();
+        let uri = req.uri().clone();
+        let body = Bytes::from_request(req, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+        WebhookRequest {
+            method: event_method(&method),
+            query: uri.query(),
+            body: &body,
+            authorization: None,
+        }
+        .decode()
+        .map(VoiceWebhookPayload)
+        .map_err(IntoResponse::into_response)
+    }
+}
+
+fn event_method(method: &Method) -> EventMethod {
+    if method == Method::GET {
+        EventMethod::GET
+    } else {
+        EventMethod::POST
+    }
+}
+
+fn webhook_request<'a>(
+    method: &Method,
+    uri: &'a Uri,
+    headers: &'a HeaderMap,
+    body: &'a [u8],
+) -> WebhookRequest<'a> {
+    WebhookRequest {
+        method: event_method(method),
+        query: uri.query(),
+        body,
+        authorization: headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok()),
+    }
+}
+
+async fn handle<H: VoiceWebhookHandler>(
+    webhooks: VoiceWebhooks<H>,
+    webhook: VoiceWebhook,
+    method: Method,
+    uri: Uri,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    match webhooks
+        .handle(webhook, webhook_request(&method, &uri, &headers, &body))
+        .await
+    {
+        Ok(Some(ncco)) => Json(ncco).into_response(),
+        Ok(None) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => {
+            log::warn!("Rejected {:?} webhook: {}", webhook, e);
+            e.into_response()
+        }
+    }
+}
+
+/// Voice Router
+/// Routes `/answer`, `/event` and `/fallback` to a handler, each accepting both GET and POST webhooks
+/// @param webhooks The handler and signature settings
+/// @return A router to nest under the path of the webhook URLs
+pub fn voice_router<H: VoiceWebhookHandler>(webhooks: VoiceWebhooks<H>) -> Router {
+    let route = |webhook: VoiceWebhook| {
+        let handler = move |State(webhooks): State<VoiceWebhooks<H>>,
+                            method: Method,
+                            uri: Uri,
+                            headers: HeaderMap,
+                            body: Bytes| {
+            handle(webhooks, webhook, method, uri, headers, body)
+        };
+        get(handler).post(handler)
+    };
+    Router::new()
+        .route("/answer", route(VoiceWebhook::Answer))
+        .route("/event", route(VoiceWebhook::Event))
+        .route("/fallback", route(VoiceWebhook::Fallback))
+        .with_state(webhooks)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use crate::voice::webhooks::VoiceAnswerPayload;
+    use crate::voice::NCCO;
+    use async_trait::async_trait;
+    use tower::ServiceExt;
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    struct Greeter;
+
+    #[async_trait]
+    impl VoiceWebhookHandler for Greeter {
+        async fn answer(&self, _payload: VoiceAnswerPayload) -> NCCO {
+            NCCO::new().talk("Hello".to_string())
+        }
+    }
+
+    async fn send(app: &Router, request: ::axum::http::Request<String>) -> (StatusCode, String) {
+        let response = app
+            .clone()
+            .oneshot(request.map(::axum::body::Body::from))
+            .await
+            .unwrap();
+        let status = response.status();
+        let body = ::axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, String::from_utf8_lossy(&body).into_owned())
+    }
+
+    #[tokio::test]
+    async fn route_voice_webhooks() {
+        init();
+        let app = Router::new().nest("/voice", voice_router(VoiceWebhooks::new(Greeter)));
+
+        let answer = ::axum::http::Request::get("/voice/answer?to=447700900000&from=447700900001&uuid=aaa&conversation_uuid=CON-bbb&region_url=https%3A%2F%2Fapi-us-3.vonage.com")
+            .body(String::new())
+            .unwrap();
+        let (status, body) = send(&app, answer).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, r#"[{"action":"talk","text":"Hello"}]"#);
+
+        let event = ::axum::http::Request::post("/voice/event")
+            .header("content-type", "application/json")
+            .body(r#"{"conversation_uuid_from":"CON-a","conversation_uuid_to":"CON-b","uuid":"aaa","timestamp":"2024-01-01T00:00:00.000Z"}"#.to_string())
+            .unwrap();
+        let (status, _) = send(&app, event).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let invalid = ::axum::http::Request::post("/voice/answer")
+            .body("not json".to_string())
+            .unwrap();
+        let (status, _) = send(&app, invalid).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn reject_unsigned_webhooks() {
+        init();
+        let app = voice_router(VoiceWebhooks::new(Greeter).verify_signatures("secret".into()));
+        let event = ::axum::http::Request::post("/event")
+            .body(r#"{"status":"started"}"#.to_string())
+            .unwrap();
+        let (status, _) = send(&app, event).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+}
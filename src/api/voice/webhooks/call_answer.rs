@@ -1,3 +1,4 @@
+use super::lenient::{sip_headers, without_sip_headers};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -12,6 +13,11 @@ use std::collections::HashMap;
 ///
 /// The payload for an inbound call event from PSTN, SIP, Websocket, or VBC.
 ///
+/// ## Unknown
+///
+/// Any other payload, kept as raw JSON so a new call type does not fail the webhook.
+///
+/// Fields that are not modeled are kept in the `extra` map.
 ///
 /// More Information:
 ///  [Vonage Voice API Reference](https://developer.vonage.com/en/voice/voice-api/webhook-reference#answer-webhook)
@@ -22,12 +28,15 @@ use std::collections::HashMap;
 /// use crate::types::webhooks::{CallAnswerPayload, CallAnswerPayload::*};
 /// fn handle_call_answer(payload: CallAnswerPayload) {
 ///    match payload {
-///       ServerCall { to, from_user, uuid, conversation_uuid, region_url, custom_data } => {
+///       ServerCall { to, from_user, uuid, conversation_uuid, region_url, custom_data, .. } => {
 ///          println!("Server call from {} to {}", from_user, to);
 ///      },
-///     InboundCall { to, from, uuid, conversation_uuid, region_url, sip_headers } => {
+///     InboundCall { to, from, uuid, conversation_uuid, region_url, sip_headers, .. } => {
 ///        println!("Inbound call from {} to {}", from, to);
 ///     }
+///     Unknown(raw) => {
+///        println!("Unknown call {}", raw);
+///     }
 ///   }
 /// }
 /// ```
@@ -48,7 +57,11 @@ pub enum VoiceAnswerPayload {
         /// The region URL for the call
         region_url: String,
         /// Custom data sent with the server call context in the Client SDKs
+        #[serde(default)]
         custom_data: HashMap<String, Value>,
+        /// Fields not modeled above
+        #[serde(flatten)]
+        extra: HashMap<String, Value>,
     },
     InboundCall {
         /// The number the call was made to, in E.164 format this will always be a LVN
@@ -61,10 +74,15 @@ pub enum VoiceAnswerPayload {
         conversation_uuid: String,
         /// The region URL for the call
         region_url: String,
-        /// SIP headers sent with the inbound call
-        #[serde(flatten)]
+        /// SIP headers sent with the inbound call, as `SipHeader_` fields
+        #[serde(flatten, deserialize_with = "sip_headers")]
         sip_headers: HashMap<String, String>,
+        /// Fields not modeled above, other than SIP headers
+        #[serde(flatten, deserialize_with = "without_sip_headers")]
+        extra: HashMap<String, Value>,
     },
+    /// A call that does not match a known payload
+    Unknown(Value),
 }
 
 #[cfg(test)]
@@ -108,6 +126,7 @@ mod tests {
                 conversation_uuid,
                 region_url,
                 custom_data,
+                ..
             } => {
                 assert_eq!(to, "447700900000");
                 assert_eq!(from_user, "alice");
@@ -149,6 +168,7 @@ mod tests {
                 conversation_uuid,
                 region_url,
                 sip_headers,
+                ..
             } => {
                 assert_eq!(to, "447700900000");
                 assert_eq!(from, "447700900001");
@@ -1,12 +1,29 @@
+use super::lenient::{
+    from_str_or_value, input_object, object_with_status, object_with_type,
+    option_from_str_or_value,
+};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 
+/// Webhook payload for a call event from the Vonage Voice API
+///
+/// Events that do not match a known shape, such as a status added after this release, are kept as `Unknown`
+/// and fields that are not modeled are kept in the `extra` map of each event.
+///
+/// More Information:
+///  [Vonage Voice API Reference](https://developer.vonage.com/en/voice/voice-api/webhook-reference#event-webhook)
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(untagged)]
 pub enum CallEventPayload {
-    CallStatus(CallStatusEvent),
     Input(InputEvent),
     Transfer(TransferEvent),
+    Record(RecordEvent),
+    Notify(NotifyEvent),
+    // Talk and stream events carry a `status` too, so they are tried before call status events
     Play(PlayEvent),
+    CallStatus(CallStatusEvent),
+    Unknown(Value),
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -19,6 +36,8 @@ pub enum CallStatusEvent {
         conversation_uuid: String,
         direction: Direction,
         timestamp: String,
+        #[serde(flatten)]
+        extra: HashMap<String, Value>,
     },
     Ringing {
         from: String,
@@ -27,6 +46,8 @@ pub enum CallStatusEvent {
         conversation_uuid: String,
         direction: Direction,
         timestamp: String,
+        #[serde(flatten)]
+        extra: HashMap<String, Value>,
     },
     Answered {
         from: String,
@@ -35,8 +56,13 @@ pub enum CallStatusEvent {
         conversation_uuid: String,
         direction: Direction,
         timestamp: String,
-        rate: f64,
+        /// The per minute rate, not sent for app and websocket legs
+        #[serde(default, deserialize_with = "option_from_str_or_value")]
+        rate: Option<f64>,
         network: Option<String>,
+        start_time: Option<String>,
+        #[serde(flatten)]
+        extra: HashMap<String, Value>,
     },
     Busy {
         from: String,
@@ -45,6 +71,8 @@ pub enum CallStatusEvent {
         conversation_uuid: String,
         direction: Direction,
         timestamp: String,
+        #[serde(flatten)]
+        extra: HashMap<String, Value>,
     },
     Cancelled {
         from: String,
@@ -53,6 +81,8 @@ pub enum CallStatusEvent {
         conversation_uuid: String,
         direction: Direction,
         timestamp: String,
+        #[serde(flatten)]
+        extra: HashMap<String, Value>,
     },
     Unanswered {
         from: String,
@@ -61,7 +91,9 @@ pub enum CallStatusEvent {
         conversation_uuid: String,
         direction: Direction,
         timestamp: String,
-        detail: UnansweredDetail,
+        detail: Option<UnansweredDetail>,
+        #[serde(flatten)]
+        extra: HashMap<String, Value>,
     },
     Disconnected {
         from: String,
@@ -70,7 +102,11 @@ pub enum CallStatusEvent {
         conversation_uuid: String,
         direction: Direction,
         timestamp: String,
-        duration: String,
+        /// The length of the call in seconds
+        #[serde(default, deserialize_with = "option_from_str_or_value")]
+        duration: Option<usize>,
+        #[serde(flatten)]
+        extra: HashMap<String, Value>,
     },
     Redirected {
         from: String,
@@ -79,7 +115,9 @@ pub enum CallStatusEvent {
         conversation_uuid: String,
         direction: Direction,
         timestamp: String,
-        detail: RejectedDetail,
+        detail: Option<RejectedDetail>,
+        #[serde(flatten)]
+        extra: HashMap<String, Value>,
     },
     Rejected {
         from: String,
@@ -88,7 +126,9 @@ pub enum CallStatusEvent {
         conversation_uuid: String,
         direction: Direction,
         timestamp: String,
-        detail: RejectedDetail,
+        detail: Option<RejectedDetail>,
+        #[serde(flatten)]
+        extra: HashMap<String, Value>,
     },
     Failed {
         from: String,
@@ -97,7 +137,9 @@ pub enum CallStatusEvent {
         conversation_uuid: String,
         direction: Direction,
         timestamp: String,
-        detail: FailureDetail,
+        detail: Option<FailureDetail>,
+        #[serde(flatten)]
+        extra: HashMap<String, Value>,
     },
     Human {
         from: String,
@@ -106,7 +148,9 @@ pub enum CallStatusEvent {
         conversation_uuid: String,
         direction: Direction,
         timestamp: String,
-        sub_state: HumanMachineSubState,
+        sub_state: Option<HumanMachineSubState>,
+        #[serde(flatten)]
+        extra: HashMap<String, Value>,
     },
     Machine {
         from: String,
@@ -115,7 +159,9 @@ pub enum CallStatusEvent {
         conversation_uuid: String,
         direction: Direction,
         timestamp: String,
-        sub_state: HumanMachineSubState,
+        sub_state: Option<HumanMachineSubState>,
+        #[serde(flatten)]
+        extra: HashMap<String, Value>,
     },
     Timeout {
         from: String,
@@ -124,6 +170,8 @@ pub enum CallStatusEvent {
         conversation_uuid: String,
         direction: Direction,
         timestamp: String,
+        #[serde(flatten)]
+        extra: HashMap<String, Value>,
     },
     Completed {
         from: String,
@@ -132,13 +180,23 @@ pub enum CallStatusEvent {
         conversation_uuid: String,
         direction: Direction,
         timestamp: String,
-        duration: usize,
+        /// The length of the call in seconds
+        #[serde(default, deserialize_with = "option_from_str_or_value")]
+        duration: Option<usize>,
         network: Option<String>,
-        end_time: String,
-        rate: f64,
-        price: f64,
-        disconnected_by: CompletedByDisconnectionBy,
+        start_time: Option<String>,
+        end_time: Option<String>,
+        #[serde(default, deserialize_with = "option_from_str_or_value")]
+        rate: Option<f64>,
+        #[serde(default, deserialize_with = "option_from_str_or_value")]
+        price: Option<f64>,
+        disconnected_by: Option<CompletedByDisconnectionBy>,
+        #[serde(flatten)]
+        extra: HashMap<String, Value>,
     },
+    /// An event with a status that is not modeled yet
+    #[serde(untagged, deserialize_with = "object_with_status")]
+    Unknown(Value),
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone, Eq)]
@@ -146,89 +204,115 @@ pub enum CallStatusEvent {
 pub enum Direction {
     Inbound,
     Outbound,
+    #[serde(other)]
+    Unknown,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum UnansweredDetail {
     Timeout,
     Unavailable,
+    #[serde(other)]
+    Unknown,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum RejectedDetail {
     InvalidNumber,
     Declined,
     Restricted,
+    #[serde(other)]
+    Unknown,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum FailureDetail {
     CannotRoute,
     NumberOutOfService,
     InternalError,
+    #[serde(other)]
+    Unknown,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum HumanMachineSubState {
     BeepStart,
     BeepTimeout,
+    #[serde(other)]
+    Unknown,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum CompletedByDisconnectionBy {
     User,
     Platform,
+    #[serde(other)]
+    Unknown,
 }
 // Input Events
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(untagged)]
 pub enum InputEvent {
-    DTMF {
+    // Speech input also carries an empty `dtmf` object, so it is tried first
+    Speech {
         from: String,
         to: String,
         uuid: String,
         conversation_uuid: String,
         timestamp: String,
-        dtmf: DTMFPayload,
+        speech: SpeechPayload,
+        #[serde(flatten)]
+        extra: HashMap<String, Value>,
     },
-    Speech {
+    DTMF {
         from: String,
         to: String,
         uuid: String,
         conversation_uuid: String,
         timestamp: String,
-        speech: SpeechPayload,
+        dtmf: DTMFPayload,
+        #[serde(flatten)]
+        extra: HashMap<String, Value>,
     },
+    /// An input event of a type that is not modeled yet
+    #[serde(deserialize_with = "input_object")]
+    Unknown(Value),
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct DTMFPayload {
-    dtmf: String,
+    /// The digits pressed, documented as `digits` but also accepted as `dtmf`
+    #[serde(rename = "digits", alias = "dtmf")]
+    dtmf: Option<String>,
+    #[serde(default, deserialize_with = "from_str_or_value")]
     timed_out: bool,
 }
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(untagged)]
 pub enum SpeechPayload {
-    Success {
-        recording_url: String,
-        timeout_reason: Option<String>,
-        result: Option<Vec<SpeechResult>>,
-    },
     Failure {
-        recording_url: String,
+        recording_url: Option<String>,
         timeout_reason: Option<String>,
         error: String,
     },
+    Success {
+        recording_url: Option<String>,
+        timeout_reason: Option<String>,
+        #[serde(alias = "results")]
+        result: Option<Vec<SpeechResult>>,
+    },
+    Unknown(Value),
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct SpeechResult {
     text: String,
+    #[serde(deserialize_with = "from_str_or_value")]
     confidence: f64,
 }
 
@@ -239,6 +323,39 @@ pub struct TransferEvent {
     pub conversation_uuid_to: String,
     pub uuid: String,
     pub timestamp: String,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+// Record Events
+/// Sent when a recording is ready to download, by the record action and by a conversation that is recorded
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RecordEvent {
+    pub recording_uuid: String,
+    pub recording_url: String,
+    pub conversation_uuid: String,
+    pub timestamp: String,
+    /// Not sent for conversation recordings
+    pub start_time: Option<String>,
+    /// Not sent for conversation recordings
+    pub end_time: Option<String>,
+    /// The size of the recording in bytes
+    #[serde(default, deserialize_with = "option_from_str_or_value")]
+    pub size: Option<usize>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+// Notify Events
+/// Sent by the notify action to its event URL
+#[derive(Serialize, Deserialize, Debug)]
+pub struct NotifyEvent {
+    /// The payload set on the notify action
+    pub payload: Value,
+    pub conversation_uuid: String,
+    pub timestamp: String,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
 // Talk/Stream Events
@@ -250,21 +367,30 @@ pub enum PlayEvent {
         uuid: String,
         conversation_uuid: String,
         status: PlayStatus,
+        #[serde(flatten)]
+        extra: HashMap<String, Value>,
     },
     Stream {
         timestamp: String,
         uuid: String,
         conversation_uuid: String,
         status: PlayStatus,
+        #[serde(flatten)]
+        extra: HashMap<String, Value>,
     },
+    /// A play event of a type that is not modeled yet
+    #[serde(untagged, deserialize_with = "object_with_type")]
+    Unknown(Value),
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
 pub enum PlayStatus {
     Stopped,
     Finished,
     Interrupted,
+    #[serde(other)]
+    Unknown,
 }
 
 #[cfg(test)]
@@ -307,6 +433,7 @@ mod tests {
                 conversation_uuid,
                 direction,
                 timestamp,
+                ..
             }) => {
                 assert_eq!(from, "447700900000");
                 assert_eq!(to, "447700900001");
@@ -346,6 +473,7 @@ mod tests {
                 conversation_uuid,
                 direction,
                 timestamp,
+                ..
             }) => {
                 assert_eq!(from, "447700900000");
                 assert_eq!(to, "447700900001");
@@ -380,6 +508,7 @@ mod tests {
                 conversation_uuid_to,
                 uuid,
                 timestamp,
+                ..
             }) => {
                 assert_eq!(conversation_uuid_from, "yyy-yyy-yyy-yyy");
                 assert_eq!(conversation_uuid_to, "zzz-zzz-zzz-zzz");
@@ -0,0 +1,60 @@
+/// Webhook Error
+/// Why a webhook request was rejected
+#[derive(Debug)]
+pub enum WebhookError {
+    /// Signatures are verified but the request has no `Authorization: Bearer` header
+    MissingSignature,
+    InvalidSignature(jsonwebtoken::errors::Error),
+    /// The signature has no `iat` or was issued longer ago than the max age, so it may be replayed
+    StaleSignature,
+    /// The request has no body, the signature does not cover the query string of a GET webhook
+    UnsignedPayload,
+    /// The body does not match the `payload_hash` of the signature
+    PayloadHashMismatch,
+    /// The body could not be read or was too large
+    UnreadableBody,
+    InvalidQuery(serde_urlencoded::de::Error),
+    InvalidPayload(serde_json::Error),
+    /// The event could not be checked against the call store
+    Store(crate::voice::CallStoreError),
+}
+
+impl WebhookError {
+    /// The HTTP status to respond with
+    pub fn status_code(&self) -> u16 {
+        match self {
+            WebhookError::MissingSignature
+            | WebhookError::InvalidSignature(_)
+            | WebhookError::StaleSignature
+            | WebhookError::UnsignedPayload
+            | WebhookError::PayloadHashMismatch => 401,
+            WebhookError::UnreadableBody
+            | WebhookError::InvalidQuery(_)
+            | WebhookError::InvalidPayload(_) => 400,
+            // Vonage retries the event, which is what we want if the store is unavailable
+            WebhookError::Store(_) => 500,
+        }
+    }
+}
+
+impl std::fmt::Display for WebhookError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookError::MissingSignature => write!(f, "webhook is not signed"),
+            WebhookError::InvalidSignature(e) => write!(f, "invalid webhook signature: {}", e),
+            WebhookError::StaleSignature => write!(f, "webhook signature is too old"),
+            WebhookError::UnsignedPayload => {
+                write!(f, "webhook has no body covered by its signature")
+            }
+            WebhookError::PayloadHashMismatch => {
+                write!(f, "webhook body does not match its signature")
+            }
+            WebhookError::UnreadableBody => write!(f, "webhook body could not be read"),
+            WebhookError::InvalidQuery(e) => write!(f, "invalid webhook query: {}", e),
+            WebhookError::InvalidPayload(e) => write!(f, "invalid webhook payload: {}", e),
+            WebhookError::Store(e) => write!(f, "webhook could not be deduplicated: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for WebhookError {}
@@ -0,0 +1,466 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use super::{
+    decode_query, CallEventKind, CallEventPayload, SignatureVerifier, VoiceAnswerPayload,
+    WebhookError,
+};
+use crate::client::client_types::ApiSecret;
+use crate::voice::{CallStore, EventKey, EventMethod, NCCO};
+
+/// Voice Webhook Handler
+/// Implement this to answer calls and react to call events, then serve it with the `axum` or `rocket` features
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::voice::webhooks::{VoiceAnswerPayload, VoiceWebhookHandler};
+/// use vonage_client::NCCO;
+///
+/// struct Greeter;
+///
+/// #[async_trait::async_trait]
+/// impl VoiceWebhookHandler for Greeter {
+///     async fn answer(&self, _payload: VoiceAnswerPayload) -> NCCO {
+///         NCCO::new().talk("Hello from Rust".to_string())
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait VoiceWebhookHandler: Send + Sync + 'static {
+    /// Answer a call
+    /// @param payload The answer webhook
+    /// @return The NCCO controlling the call
+    async fn answer(&self, payload: VoiceAnswerPayload) -> NCCO;
+
+    /// React to a call event, the default ignores every event
+    /// @param payload The event webhook
+    /// @return An NCCO to replace the current one, only used by events that expect one such as `input`
+    async fn event(&self, _payload: CallEventPayload) -> Option<NCCO> {
+        None
+    }
+
+    /// React to an event that could not be decoded, such as a status added after this release
+    /// The default ignores the event, it is still acknowledged so Vonage does not retry it
+    /// @param raw The JSON of the event
+    /// @return An NCCO to replace the current one
+    async fn unknown_event(&self, raw: Value) -> Option<NCCO> {
+        log::warn!("Ignoring unknown event: {}", payload_ids(&raw));
+        None
+    }
+
+    /// Answer a call after the answer or event URL failed, the default ends the call
+    /// @param payload The webhook that could not be delivered, either an answer or an event
+    /// @return The NCCO controlling the call
+    async fn fallback(&self, _payload: Value) -> NCCO {
+        NCCO::new()
+    }
+}
+
+/// Voice Webhook
+/// Which of the webhooks of an application a request was sent to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoiceWebhook {
+    Answer,
+    Event,
+    Fallback,
+}
+
+/// Webhook Request
+/// The parts of an HTTP request a webhook is decoded from, independent of the web framework
+#[derive(Debug, Clone)]
+pub struct WebhookRequest<'a> {
+    pub method: EventMethod,
+    /// The query string without the leading `?`
+    pub query: Option<&'a str>,
+    pub body: &'a [u8],
+    /// The value of the `Authorization` header
+    pub authorization: Option<&'a str>,
+}
+
+impl WebhookRequest<'_> {
+    /// Decode the payload from the query string of a GET request or the JSON body of a POST request
+    pub fn decode<T: DeserializeOwned>(&self) -> Result<T, WebhookError> {
+        match self.method {
+            EventMethod::GET => decode_query(self.query.unwrap_or_default()),
+            EventMethod::POST => {
+                serde_json::from_slice(self.body).map_err(WebhookError::InvalidPayload)
+            }
+        }
+    }
+}
+
+/// Voice Webhooks
+/// Verifies, decodes and dispatches webhooks to a `VoiceWebhookHandler`, the web framework integrations are built on this
+#[derive(Debug)]
+pub struct VoiceWebhooks<H> {
+    handler: Arc<H>,
+    verifier: Option<SignatureVerifier>,
+    store: Option<Arc<dyn CallStore>>,
+}
+
+impl<H> Clone for VoiceWebhooks<H> {
+    fn clone(&self) -> Self {
+        VoiceWebhooks {
+            handler: self.handler.clone(),
+            verifier: self.verifier.clone(),
+            store: self.store.clone(),
+        }
+    }
+}
+
+impl<H: VoiceWebhookHandler> VoiceWebhooks<H> {
+    pub fn new(handler: H) -> Self {
+        VoiceWebhooks {
+            handler: Arc::new(handler),
+            verifier: None,
+            store: None,
+        }
+    }
+
+    /// Reject webhooks that are not signed with the signature secret of the account
+    /// Only the body is signed, so the webhooks must use POST, GET webhooks are rejected
+    /// @param signature_secret The signature secret from the API settings of the dashboard
+    pub fn verify_signatures(self, signature_secret: ApiSecret) -> Self {
+        self.signature_verifier(SignatureVerifier::new(signature_secret))
+    }
+
+    /// Reject webhooks that fail a configured `SignatureVerifier`, such as one with a longer max age
+    /// @param verifier The signature verifier
+    pub fn signature_verifier(mut self, verifier: SignatureVerifier) -> Self {
+        self.verifier = Some(verifier);
+        self
+    }
+
+    /// Skip event webhooks that were already delivered, keyed on their uuid, status and timestamp
+    /// A duplicate is answered with no content without calling the handler
+    /// An event is only marked as delivered once the handler returns, so a retry of a failed attempt is handled,
+    /// deliveries that arrive while the first is still being handled may reach the handler too
+    /// @param store The store of event keys, shared between replicas to deduplicate across them
+    pub fn deduplicate(mut self, store: Arc<dyn CallStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    /// Handle a webhook request
+    /// @param webhook The webhook the request was sent to
+    /// @param request The request
+    /// @return The NCCO to respond with, if any
+    pub async fn handle(
+        &self,
+        webhook: VoiceWebhook,
+        request: WebhookRequest<'_>,
+    ) -> Result<Option<NCCO>, WebhookError> {
+        if let Some(verifier) = &self.verifier {
+            verifier.verify(request.authorization, request.body)?;
+        }
+        match webhook {
+            VoiceWebhook::Answer => {
+                let raw: Value = request.decode()?;
+                log::debug!("Answer webhook: {}", payload_ids(&raw));
+                let payload = serde_json::from_value(raw).map_err(WebhookError::InvalidPayload)?;
+                Ok(Some(self.handler.answer(payload).await))
+            }
+            VoiceWebhook::Event => {
+                let raw: Value = request.decode()?;
+                let dedup = self.store.as_ref().zip(EventKey::from_raw(&raw));
+                if let Some((store, key)) = &dedup {
+                    if store.has_event(key).await.map_err(WebhookError::Store)? {
+                        log::debug!("Skipping duplicate event {}", key);
+                        return Ok(None);
+                    }
+                }
+                let ncco = match serde_json::from_value::<CallEventPayload>(raw.clone()) {
+                    Ok(payload) if payload.kind() != CallEventKind::Unknown => {
+                        log::debug!("Event webhook {:?}: {}", payload.kind(), payload_ids(&raw));
+                        self.handler.event(payload).await
+                    }
+                    Ok(_) => {
+                        log::debug!("Unknown event webhook: {}", payload_ids(&raw));
+                        self.handler.unknown_event(raw).await
+                    }
+                    Err(e) => {
+                        log::debug!("Unknown event webhook ({}): {}", e, payload_ids(&raw));
+                        self.handler.unknown_event(raw).await
+                    }
+                };
+                // Only a handled event is marked as delivered, a retry after a failure reaches the handler again
+                if let Some((store, key)) = &dedup {
+                    store.insert_event(key).await.map_err(WebhookError::Store)?;
+                }
+                Ok(ncco)
+            }
+            VoiceWebhook::Fallback => {
+                let payload: Value = request.decode()?;
+                log::debug!("Fallback webhook: {}", payload_ids(&payload));
+                Ok(Some(self.handler.fallback(payload).await))
+            }
+        }
+    }
+}
+
+/// The ids of a webhook payload for logging, the rest of the payload holds the numbers of the call
+fn payload_ids(raw: &Value) -> String {
+    let field = |name| raw.get(name).and_then(Value::as_str).unwrap_or("-");
+    format!(
+        "uuid {}, conversation {}",
+        field("uuid"),
+        field("conversation_uuid")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use crate::client::client_types::SensitiveUnwrap;
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    struct Greeter;
+
+    #[async_trait]
+    impl VoiceWebhookHandler for Greeter {
+        async fn answer(&self, payload: VoiceAnswerPayload) -> NCCO {
+            match payload {
+                VoiceAnswerPayload::InboundCall { from, .. } => {
+                    NCCO::new().talk(format!("Hello {}", from))
+                }
+                VoiceAnswerPayload::ServerCall { from_user, .. } => {
+                    NCCO::new().talk(format!("Hello {}", from_user))
+                }
+                VoiceAnswerPayload::Unknown(_) => NCCO::new(),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn dispatch_get_and_post_webhooks() {
+        init();
+        let webhooks = VoiceWebhooks::new(Greeter);
+
+        let query = "to=447700900000&from=447700900001&uuid=aaa&conversation_uuid=CON-bbb&region_url=https%3A%2F%2Fapi-us-3.vonage.com";
+        let ncco = webhooks
+            .handle(
+                VoiceWebhook::Answer,
+                WebhookRequest {
+                    method: EventMethod::GET,
+                    query: Some(query),
+                    body: &[],
+                    authorization: None,
+                },
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(ncco).unwrap(),
+            serde_json::json!([{"action": "talk", "text": "Hello 447700900001"}])
+        );
+
+        let body = br#"{"conversation_uuid_from":"CON-a","conversation_uuid_to":"CON-b","uuid":"aaa","timestamp":"2024-01-01T00:00:00.000Z"}"#;
+        let ncco = webhooks
+            .handle(
+                VoiceWebhook::Event,
+                WebhookRequest {
+                    method: EventMethod::POST,
+                    query: None,
+                    body,
+                    authorization: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert!(ncco.is_none());
+
+        // Events that cannot be decoded are acknowledged rather than rejected
+        let ncco = webhooks
+            .handle(
+                VoiceWebhook::Event,
+                WebhookRequest {
+                    method: EventMethod::POST,
+                    query: None,
+                    body: br#"{"status":"teleported"}"#,
+                    authorization: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert!(ncco.is_none());
+
+        let err = webhooks
+            .handle(
+                VoiceWebhook::Answer,
+                WebhookRequest {
+                    method: EventMethod::POST,
+                    query: None,
+                    body: b"not json",
+                    authorization: None,
+                },
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.status_code(), 400);
+    }
+
+    #[tokio::test]
+    async fn reject_unsigned_webhooks() {
+        init();
+        let secret: ApiSecret = "signature-secret".into();
+        let webhooks = VoiceWebhooks::new(Greeter).verify_signatures(secret.clone());
+        let body = br#"{"to":"447700900000","from":"447700900001","uuid":"aaa","conversation_uuid":"CON-bbb","region_url":"https://api-us-3.vonage.com"}"#;
+        let request = |authorization| WebhookRequest {
+            method: EventMethod::POST,
+            query: None,
+            body,
+            authorization,
+        };
+
+        let err = webhooks
+            .handle(VoiceWebhook::Answer, request(None))
+            .await
+            .unwrap_err();
+        assert_eq!(err.status_code(), 401);
+
+        let token = super::super::signature::tests::sign(&secret.unwrap(), body);
+        let authorization = format!("Bearer {}", token);
+        assert!(webhooks
+            .handle(VoiceWebhook::Answer, request(Some(&authorization)))
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn reject_signed_get_webhooks() {
+        init();
+        let secret: ApiSecret = "signature-secret".into();
+        let webhooks = VoiceWebhooks::new(Greeter).verify_signatures(secret.clone());
+        let token = super::super::signature::tests::sign(&secret.unwrap(), b"");
+        let authorization = format!("Bearer {}", token);
+
+        // The query is not covered by the signature, so a tampered one must not be accepted
+        let err = webhooks
+            .handle(
+                VoiceWebhook::Answer,
+                WebhookRequest {
+                    method: EventMethod::GET,
+                    query: Some("to=447700900000&from=447700900666&uuid=aaa&conversation_uuid=CON-bbb&region_url=https%3A%2F%2Fapi-us-3.vonage.com"),
+                    body: b"",
+                    authorization: Some(&authorization),
+                },
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, WebhookError::UnsignedPayload));
+        assert_eq!(err.status_code(), 401);
+    }
+
+    #[derive(Default)]
+    struct Counter(std::sync::atomic::AtomicUsize);
+
+    #[async_trait]
+    impl VoiceWebhookHandler for Counter {
+        async fn answer(&self, _payload: VoiceAnswerPayload) -> NCCO {
+            NCCO::new()
+        }
+
+        async fn event(&self, _payload: CallEventPayload) -> Option<NCCO> {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            None
+        }
+    }
+
+    /// Panics on the first event, as a handler whose process dies mid-event would
+    #[derive(Default)]
+    struct FailsOnce(std::sync::atomic::AtomicUsize);
+
+    #[async_trait]
+    impl VoiceWebhookHandler for FailsOnce {
+        async fn answer(&self, _payload: VoiceAnswerPayload) -> NCCO {
+            NCCO::new()
+        }
+
+        async fn event(&self, _payload: CallEventPayload) -> Option<NCCO> {
+            if self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+                panic!("handler failed");
+            }
+            None
+        }
+    }
+
+    #[tokio::test]
+    async fn retry_failed_events() {
+        init();
+        let webhooks = VoiceWebhooks::new(FailsOnce::default())
+            .deduplicate(Arc::new(crate::voice::MemoryCallStore::new()));
+        let request = WebhookRequest {
+            method: EventMethod::POST,
+            query: None,
+            body: br#"{"status":"started","from":"447700900001","to":"447700900000","uuid":"aaa","conversation_uuid":"CON-bbb","direction":"outbound","timestamp":"2024-01-01T00:00:00.000Z"}"#,
+            authorization: None,
+        };
+
+        let failed = tokio::spawn({
+            let webhooks = webhooks.clone();
+            let request = request.clone();
+            async move { webhooks.handle(VoiceWebhook::Event, request).await }
+        })
+        .await;
+        assert!(failed.unwrap_err().is_panic());
+
+        // Vonage retries the event, it reaches the handler again and is then marked as delivered
+        for _ in 0..2 {
+            assert!(webhooks
+                .handle(VoiceWebhook::Event, request.clone())
+                .await
+                .unwrap()
+                .is_none());
+        }
+        assert_eq!(
+            webhooks
+                .handler()
+                .0
+                .load(std::sync::atomic::Ordering::SeqCst),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn skip_duplicate_events() {
+        init();
+        let webhooks = VoiceWebhooks::new(Counter::default())
+            .deduplicate(Arc::new(crate::voice::MemoryCallStore::new()));
+        let started = br#"{"status":"started","from":"447700900001","to":"447700900000","uuid":"aaa","conversation_uuid":"CON-bbb","direction":"outbound","timestamp":"2024-01-01T00:00:00.000Z"}"#;
+        let ringing = br#"{"status":"ringing","from":"447700900001","to":"447700900000","uuid":"aaa","conversation_uuid":"CON-bbb","direction":"outbound","timestamp":"2024-01-01T00:00:01.000Z"}"#;
+        for body in [&started[..], &started[..], &ringing[..]] {
+            let request = WebhookRequest {
+                method: EventMethod::POST,
+                query: None,
+                body,
+                authorization: None,
+            };
+            assert!(webhooks
+                .handle(VoiceWebhook::Event, request)
+                .await
+                .unwrap()
+                .is_none());
+        }
+        assert_eq!(
+            webhooks
+                .handler()
+                .0
+                .load(std::sync::atomic::Ordering::SeqCst),
+            2
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use super::query::SIP_HEADER_PREFIX;
+use serde::de::{self, Deserializer};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ValueOrString<T> {
+    Value(T),
+    String(String),
+}
+
+/// Deserialize a number or boolean that may arrive as a string, as it does in the query of a GET webhook
+pub(super) fn from_str_or_value<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: Display,
+{
+    match ValueOrString::<T>::deserialize(deserializer)? {
+        ValueOrString::Value(value) => Ok(value),
+        ValueOrString::String(value) => value.parse().map_err(de::Error::custom),
+    }
+}
+
+/// Deserialize an optional number that may arrive as a string, an empty string is treated as missing
+pub(super) fn option_from_str_or_value<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+    T::Err: Display,
+{
+    match Option::<ValueOrString<T>>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(ValueOrString::Value(value)) => Ok(Some(value)),
+        Some(ValueOrString::String(value)) if value.is_empty() => Ok(None),
+        Some(ValueOrString::String(value)) => value.parse().map(Some).map_err(de::Error::custom),
+    }
+}
+
+/// Deserialize an object that has at least one of the keys, so catch-all variants only match their own events
+fn object_with_any<'de, D>(deserializer: D, keys: &[&str]) -> Result<Value, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Value::deserialize(deserializer)?;
+    match &value {
+        Value::Object(fields) if keys.iter().any(|key| fields.contains_key(*key)) => Ok(value),
+        _ => Err(de::Error::custom(format!(
+            "expected an object with {:?}",
+            keys
+        ))),
+    }
+}
+
+pub(super) fn object_with_status<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Value, D::Error> {
+    object_with_any(deserializer, &["status"])
+}
+
+pub(super) fn object_with_type<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Value, D::Error> {
+    object_with_any(deserializer, &["type"])
+}
+
+pub(super) fn input_object<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Value, D::Error> {
+    object_with_any(deserializer, &["dtmf", "speech"])
+}
+
+/// Keep only the `SipHeader_` fields of a flattened payload, headers that look like JSON are kept as their text
+pub(super) fn sip_headers<'de, D>(deserializer: D) -> Result<HashMap<String, String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let fields = HashMap::<String, Value>::deserialize(deserializer)?;
+    Ok(fields
+        .into_iter()
+        .filter(|(key, _)| key.starts_with(SIP_HEADER_PREFIX))
+        .map(|(key, value)| match value {
+            Value::String(value) => (key, value),
+            value => (key, value.to_string()),
+        })
+        .collect())
+}
+
+/// Keep every field of a flattened payload other than the `SipHeader_` fields
+pub(super) fn without_sip_headers<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<String, Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let mut fields = HashMap::<String, Value>::deserialize(deserializer)?;
+    fields.retain(|key, _| !key.starts_with(SIP_HEADER_PREFIX));
+    Ok(fields)
+}
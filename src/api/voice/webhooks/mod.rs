@@ -1,5 +1,23 @@
 mod call_answer;
 mod call_event;
+mod error;
+mod handler;
+mod lenient;
+mod query;
+mod router;
+mod signature;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "axum")]
+pub mod axum;
+#[cfg(feature = "rocket")]
+pub mod rocket;
 
 pub use call_answer::*;
 pub use call_event::*;
+pub use error::WebhookError;
+pub use handler::{VoiceWebhook, VoiceWebhookHandler, VoiceWebhooks, WebhookRequest};
+pub use query::decode_query;
+pub use router::{CallEventKind, EventRouter};
+pub use signature::{SignatureVerifier, SignedWebhookClaims};
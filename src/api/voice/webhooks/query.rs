@@ -0,0 +1,151 @@
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use super::WebhookError;
+
+/// SIP headers arrive as top level `SipHeader_` parameters and are always kept as strings
+pub(super) const SIP_HEADER_PREFIX: &str = "SipHeader_";
+
+/// The documented nested fields, sent as URL encoded JSON, every other value stays a string
+const JSON_FIELDS: [&str; 3] = ["custom_data", "dtmf", "speech"];
+
+/// Decode a webhook sent with the GET method from its query string
+/// The payload is the same as the JSON body of a POST webhook:
+/// - the nested `custom_data`, `dtmf` and `speech` fields may be URL encoded JSON or use `custom_data[key]=value` brackets
+/// - numbers and booleans are kept as strings and parsed by the fields that expect them
+/// - SIP headers stay flattened as `SipHeader_` fields
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::voice::webhooks::{decode_query, VoiceAnswerPayload};
+/// let payload: VoiceAnswerPayload = decode_query(
+///     "to=447700900000&from_user=alice&uuid=aaa&conversation_uuid=CON-bbb&region_url=https%3A%2F%2Fapi-us-3.vonage.com&custom_data=%7B%22plan%22%3A%22gold%22%7D",
+/// ).unwrap();
+/// ```
+/// @param query The query string without the leading `?`
+/// @return The payload
+pub fn decode_query<T: DeserializeOwned>(query: &str) -> Result<T, WebhookError> {
+    let pairs: Vec<(String, String)> =
+        serde_urlencoded::from_str(query).map_err(WebhookError::InvalidQuery)?;
+    let mut fields = Map::new();
+    for (name, value) in pairs {
+        let value = if JSON_FIELDS.contains(&name.as_str()) {
+            decode_json(value)
+        } else {
+            Value::String(value)
+        };
+        insert(&mut fields, &name, value);
+    }
+    serde_json::from_value(Value::Object(fields)).map_err(WebhookError::InvalidPayload)
+}
+
+/// Decode a nested field sent as a URL encoded JSON object or array, anything else stays a string
+fn decode_json(value: String) -> Value {
+    match serde_json::from_str::<Value>(&value) {
+        Ok(json) if json.is_object() || json.is_array() => json,
+        _ => Value::String(value),
+    }
+}
+
+/// Insert a field, following `name[key][key]` brackets into nested objects
+fn insert(fields: &mut Map<String, Value>, name: &str, value: Value) {
+    let Some((head, rest)) = name
+        .split_once('[')
+        .filter(|(head, rest)| !head.is_empty() && rest.ends_with(']'))
+    else {
+        fields.insert(name.to_string(), value);
+        return;
+    };
+    let (key, rest) = rest.split_once(']').unwrap_or((rest, ""));
+    let nested = fields
+        .entry(head.to_string())
+        .or_insert_with(|| Value::Object(Map::new()));
+    if !nested.is_object() {
+        *nested = Value::Object(Map::new());
+    }
+    if let Value::Object(nested) = nested {
+        insert(nested, &format!("{}{}", key, rest), value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::voice::webhooks::{
+        CallEventPayload, CallStatusEvent, InputEvent, VoiceAnswerPayload,
+    };
+
+    #[test]
+    fn decode_server_call_answer() {
+        let query = "to=447700900000&from_user=alice&uuid=aaa&conversation_uuid=CON-bbb&region_url=https%3A%2F%2Fapi-us-3.vonage.com&custom_data=%7B%22plan%22%3A%22gold%22%2C%22seats%22%3A3%7D";
+        match decode_query::<VoiceAnswerPayload>(query).unwrap() {
+            VoiceAnswerPayload::ServerCall {
+                from_user,
+                custom_data,
+                ..
+            } => {
+                assert_eq!(from_user, "alice");
+                assert_eq!(custom_data["plan"], "gold");
+                assert_eq!(custom_data["seats"], 3);
+            }
+            payload => panic!("Unexpected payload: {:?}", payload),
+        }
+
+        let query = "to=447700900000&from_user=alice&uuid=aaa&conversation_uuid=CON-bbb&region_url=https%3A%2F%2Fapi-us-3.vonage.com&custom_data[plan]=gold";
+        match decode_query::<VoiceAnswerPayload>(query).unwrap() {
+            VoiceAnswerPayload::ServerCall { custom_data, .. } => {
+                assert_eq!(custom_data["plan"], "gold")
+            }
+            payload => panic!("Unexpected payload: {:?}", payload),
+        }
+    }
+
+    #[test]
+    fn decode_only_nested_fields_as_json() {
+        let query = "to=447700900000&from_user=%5B%22alice%22%5D&uuid=aaa&conversation_uuid=CON-bbb&region_url=https%3A%2F%2Fapi-us-3.vonage.com&nickname=%7B%22admin%22%3Atrue%7D";
+        match decode_query::<VoiceAnswerPayload>(query).unwrap() {
+            VoiceAnswerPayload::ServerCall {
+                from_user, extra, ..
+            } => {
+                assert_eq!(from_user, r#"["alice"]"#);
+                assert_eq!(extra["nickname"], r#"{"admin":true}"#);
+            }
+            payload => panic!("Unexpected payload: {:?}", payload),
+        }
+    }
+
+    #[test]
+    fn decode_inbound_sip_answer() {
+        let query = "to=447700900000&from=447700900001&uuid=aaa&conversation_uuid=CON-bbb&region_url=https%3A%2F%2Fapi-us-3.vonage.com&SipHeader_X-UserId=%7B42%7D&SipHeader_X-Tier=gold";
+        match decode_query::<VoiceAnswerPayload>(query).unwrap() {
+            VoiceAnswerPayload::InboundCall {
+                from, sip_headers, ..
+            } => {
+                assert_eq!(from, "447700900001");
+                assert_eq!(sip_headers["SipHeader_X-UserId"], "{42}");
+                assert_eq!(sip_headers["SipHeader_X-Tier"], "gold");
+            }
+            payload => panic!("Unexpected payload: {:?}", payload),
+        }
+    }
+
+    #[test]
+    fn decode_events_with_numbers_as_strings() {
+        let query = "status=completed&from=447700900001&to=447700900000&uuid=aaa&conversation_uuid=CON-bbb&direction=outbound&timestamp=2024-01-01T00%3A01%3A00.000Z&duration=60&end_time=2024-01-01T00%3A01%3A00.000Z&rate=0.0120&price=0.0120&disconnected_by=user";
+        match decode_query::<CallEventPayload>(query).unwrap() {
+            CallEventPayload::CallStatus(CallStatusEvent::Completed {
+                duration, price, ..
+            }) => {
+                assert_eq!(duration, Some(60));
+                assert_eq!(price, Some(0.012));
+            }
+            payload => panic!("Unexpected payload: {:?}", payload),
+        }
+
+        let query = "from=447700900001&to=447700900000&uuid=aaa&conversation_uuid=CON-bbb&timestamp=2024-01-01T00%3A01%3A00.000Z&dtmf[dtmf]=1234&dtmf[timed_out]=true";
+        assert!(matches!(
+            decode_query::<CallEventPayload>(query).unwrap(),
+            CallEventPayload::Input(InputEvent::DTMF { .. })
+        ));
+    }
+}
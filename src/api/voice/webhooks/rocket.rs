@@ -0,0 +1,219 @@
+//! Serve Voice webhooks with Rocket
+//!
+//! ## Example
+//! ```rust,ignore
+//! use vonage_client::voice::webhooks::{rocket::voice_routes, VoiceWebhooks};
+//! rocket::build().mount("/voice", voice_routes(VoiceWebhooks::new(Greeter)))
+//! ```
+
+use ::rocket::data::{Data, FromData, Limits, Outcome as DataOutcome};
+use ::rocket::http::{Method, Status};
+use ::rocket::route::{Handler, Outcome, Route};
+use ::rocket::serde::json::Json;
+use ::rocket::Request;
+use serde::de::DeserializeOwned;
+
+use super::{VoiceWebhook, VoiceWebhookHandler, VoiceWebhooks, WebhookError, WebhookRequest};
+use crate::voice::EventMethod;
+
+fn event_method(method: Method) -> EventMethod {
+    if method == Method::Get {
+        EventMethod::GET
+    } else {
+        EventMethod::POST
+    }
+}
+
+/// Read the body of a request up to the JSON limit
+async fn read_body<'r>(req: &'r Request<'_>, data: Data<'r>) -> Result<Vec<u8>, Status> {
+    let limit = req.limits().get("json").unwrap_or(Limits::JSON);
+    match data.open(limit).into_bytes().await {
+        Ok(body) if body.is_complete() => Ok(body.into_inner()),
+        Ok(_) => Err(Status::PayloadTooLarge),
+        Err(e) => {
+            log::error!("Error reading webhook body: {}", e);
+            Err(Status::BadRequest)
+        }
+    }
+}
+
+/// Voice Webhook Data Guard
+/// Decodes a webhook payload from the query string of a GET request or the JSON body of a POST request,
+/// signatures are not checked, use `voice_routes` with `VoiceWebhooks::verify_signatures` for that
+pub struct VoiceWebhookPayload<T>(pub T);
+
+#[::rocket::async_trait]
+impl<'r, T: DeserializeOwned> FromData<'r> for VoiceWebhookPayload<T> {
+    type Error = WebhookError;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> DataOutcome<'r, Self> {
+        let body = match read_body(req, data).await {
+            Ok(body) => body,
+            Err(status) => return DataOutcome::Error((status, WebhookError::UnreadableBody)),
+        };
+        let request = WebhookRequest {
+            method: event_method(req.method()),
+            query: req.uri().query().map(|query| query.as_str()),
+            body: &body,
+            authorization: None,
+        };
+        match request.decode() {
+            Ok(payload) => DataOutcome::Success(VoiceWebhookPayload(payload)),
+            Err(e) => DataOutcome::Error((Status::new(e.status_code()), e)),
+        }
+    }
+}
+
+struct VoiceWebhookRoute<H> {
+    webhooks: VoiceWebhooks<H>,
+    webhook: VoiceWebhook,
+}
+
+impl<H> Clone for VoiceWebhookRoute<H> {
+    fn clone(&self) -> Self {
+        VoiceWebhookRoute {
+            webhooks: self.webhooks.clone(),
+            webhook: self.webhook,
+        }
+    }
+}
+
+#[::rocket::async_trait]
+impl<H: VoiceWebhookHandler> Handler for VoiceWebhookRoute<H> {
+    async fn handle<'r>(&self, req: &'r Request<'_>, data: Data<'r>) -> Outcome<'r> {
+        let body = match read_body(req, data).await {
+            Ok(body) => body,
+            Err(status) => return Outcome::Error(status),
+        };
+        let request = WebhookRequest {
+            method: event_method(req.method()),
+            query: req.uri().query().map(|query| query.as_str()),
+            body: &body,
+            authorization: req.headers().get_one("Authorization"),
+        };
+        match self.webhooks.handle(self.webhook, request).await {
+            Ok(Some(ncco)) => Outcome::from(req, Json(ncco)),
+            Ok(None) => Outcome::from(req, Status::NoContent),
+            Err(e) => {
+                log::warn!("Rejected {:?} webhook: {}", self.webhook, e);
+                Outcome::Error(Status::new(e.status_code()))
+            }
+        }
+    }
+}
+
+/// Voice Routes
+/// Routes `/answer`, `/event` and `/fallback` to a handler, each accepting both GET and POST webhooks
+/// @param webhooks The handler and signature settings
+/// @return The routes to mount under the path of the webhook URLs
+pub fn voice_routes<H: VoiceWebhookHandler>(webhooks: VoiceWebhooks<H>) -> Vec<Route> {
+    [
+        ("/answer", VoiceWebhook::Answer),
+        ("/event", VoiceWebhook::Event),
+        ("/fallback", VoiceWebhook::Fallback),
+    ]
+    .into_iter()
+    .flat_map(|(path, webhook)| {
+        let route = VoiceWebhookRoute {
+            webhooks: webhooks.clone(),
+            webhook,
+        };
+        [
+            Route::new(Method::Get, path, route.clone()),
+            Route::new(Method::Post, path, route),
+        ]
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use crate::voice::webhooks::{CallEventPayload, VoiceAnswerPayload};
+    use crate::voice::NCCO;
+    use ::rocket::local::asynchronous::Client;
+    use async_trait::async_trait;
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    struct Greeter;
+
+    #[async_trait]
+    impl VoiceWebhookHandler for Greeter {
+        async fn answer(&self, _payload: VoiceAnswerPayload) -> NCCO {
+            NCCO::new().talk("Hello".to_string())
+        }
+
+        async fn event(&self, payload: CallEventPayload) -> Option<NCCO> {
+            match payload {
+                CallEventPayload::Input(_) => Some(NCCO::new().talk("Thanks".to_string())),
+                _ => None,
+            }
+        }
+    }
+
+    #[::rocket::post("/payload", data = "<payload>")]
+    fn payload(payload: VoiceWebhookPayload<VoiceAnswerPayload>) -> String {
+        match payload.0 {
+            VoiceAnswerPayload::InboundCall { from, .. } => from,
+            VoiceAnswerPayload::ServerCall { from_user, .. } => from_user,
+            VoiceAnswerPayload::Unknown(_) => String::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn route_voice_webhooks() {
+        init();
+        let rocket = ::rocket::build()
+            .mount("/voice", voice_routes(VoiceWebhooks::new(Greeter)))
+            .mount("/", ::rocket::routes![payload]);
+        let client = Client::tracked(rocket).await.unwrap();
+
+        let response = client
+            .get("/voice/answer?to=447700900000&from=447700900001&uuid=aaa&conversation_uuid=CON-bbb&region_url=https%3A%2F%2Fapi-us-3.vonage.com")
+            .dispatch()
+            .await;
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(
+            response.into_string().await.unwrap(),
+            r#"[{"action":"talk","text":"Hello"}]"#
+        );
+
+        let response = client
+            .post("/voice/event")
+            .body(r#"{"from":"447700900001","to":"447700900000","uuid":"aaa","conversation_uuid":"CON-bbb","timestamp":"2024-01-01T00:00:00.000Z","dtmf":{"dtmf":"1","timed_out":false}}"#)
+            .dispatch()
+            .await;
+        assert_eq!(
+            response.into_string().await.unwrap(),
+            r#"[{"action":"talk","text":"Thanks"}]"#
+        );
+
+        let response = client
+            .post("/payload")
+            .body(r#"{"to":"447700900000","from_user":"alice","uuid":"aaa","conversation_uuid":"CON-bbb","region_url":"https://api-us-3.vonage.com","custom_data":{}}"#)
+            .dispatch()
+            .await;
+        assert_eq!(response.into_string().await.unwrap(), "alice");
+    }
+
+    #[tokio::test]
+    async fn reject_unsigned_webhooks() {
+        init();
+        let webhooks = VoiceWebhooks::new(Greeter).verify_signatures("secret".into());
+        let client = Client::tracked(::rocket::build().mount("/", voice_routes(webhooks)))
+            .await
+            .unwrap();
+        let response = client
+            .post("/answer")
+            .body(r#"{"status":"started"}"#)
+            .dispatch()
+            .await;
+        assert_eq!(response.status(), Status::Unauthorized);
+    }
+}
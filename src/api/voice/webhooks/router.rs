@@ -0,0 +1,291 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{CallEventPayload, CallStatusEvent, InputEvent, PlayEvent, PlayStatus};
+use crate::voice::NCCO;
+
+/// Call Event Kind
+/// The status or type of a call event, used to route events to handlers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CallEventKind {
+    Started,
+    Ringing,
+    Answered,
+    Busy,
+    Cancelled,
+    Unanswered,
+    Disconnected,
+    Redirected,
+    Rejected,
+    Failed,
+    Human,
+    Machine,
+    Timeout,
+    Completed,
+    Dtmf,
+    Speech,
+    Transfer,
+    Record,
+    Notify,
+    Talk(PlayStatus),
+    Stream(PlayStatus),
+    /// An event that is not modeled yet, kept as raw JSON
+    Unknown,
+}
+
+impl CallEventKind {
+    /// Whether the call has ended, no status follows a finished one
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            CallEventKind::Busy
+                | CallEventKind::Cancelled
+                | CallEventKind::Unanswered
+                | CallEventKind::Rejected
+                | CallEventKind::Failed
+                | CallEventKind::Timeout
+                | CallEventKind::Completed
+        )
+    }
+}
+
+impl CallEventPayload {
+    /// The kind of the event
+    pub fn kind(&self) -> CallEventKind {
+        match self {
+            CallEventPayload::CallStatus(event) => match event {
+                CallStatusEvent::Started { .. } => CallEventKind::Started,
+                CallStatusEvent::Ringing { .. } => CallEventKind::Ringing,
+                CallStatusEvent::Answered { .. } => CallEventKind::Answered,
+                CallStatusEvent::Busy { .. } => CallEventKind::Busy,
+                CallStatusEvent::Cancelled { .. } => CallEventKind::Cancelled,
+                CallStatusEvent::Unanswered { .. } => CallEventKind::Unanswered,
+                CallStatusEvent::Disconnected { .. } => CallEventKind::Disconnected,
+                CallStatusEvent::Redirected { .. } => CallEventKind::Redirected,
+                CallStatusEvent::Rejected { .. } => CallEventKind::Rejected,
+                CallStatusEvent::Failed { .. } => CallEventKind::Failed,
+                CallStatusEvent::Human { .. } => CallEventKind::Human,
+                CallStatusEvent::Machine { .. } => CallEventKind::Machine,
+                CallStatusEvent::Timeout { .. } => CallEventKind::Timeout,
+                CallStatusEvent::Completed { .. } => CallEventKind::Completed,
+                CallStatusEvent::Unknown(_) => CallEventKind::Unknown,
+            },
+            CallEventPayload::Input(InputEvent::DTMF { .. }) => CallEventKind::Dtmf,
+            CallEventPayload::Input(InputEvent::Speech { .. }) => CallEventKind::Speech,
+            CallEventPayload::Transfer(_) => CallEventKind::Transfer,
+            CallEventPayload::Record(_) => CallEventKind::Record,
+            CallEventPayload::Notify(_) => CallEventKind::Notify,
+            CallEventPayload::Play(PlayEvent::Talk { status, .. }) => CallEventKind::Talk(*status),
+            CallEventPayload::Play(PlayEvent::Stream { status, .. }) => {
+                CallEventKind::Stream(*status)
+            }
+            CallEventPayload::Input(InputEvent::Unknown(_))
+            | CallEventPayload::Play(PlayEvent::Unknown(_))
+            | CallEventPayload::Unknown(_) => CallEventKind::Unknown,
+        }
+    }
+}
+
+type EventHandler = Arc<dyn Fn(CallEventPayload) -> BoxFuture<'static, Option<NCCO>> + Send + Sync>;
+type FallbackHandler = Arc<dyn Fn(Value) -> BoxFuture<'static, Option<NCCO>> + Send + Sync>;
+
+/// Event Router
+/// Routes call events to an async handler per kind, handlers return an NCCO for events that accept one such as input
+/// To serve it, forward `VoiceWebhookHandler::event` to `dispatch_event` and `unknown_event` to `dispatch`
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::voice::webhooks::{CallEventKind, EventRouter};
+/// use vonage_client::NCCO;
+/// let router = EventRouter::new()
+///     .on(CallEventKind::Completed, |event| async move {
+///         println!("Call completed: {:?}", event);
+///         None
+///     })
+///     .on(CallEventKind::Dtmf, |_| async { Some(NCCO::new().talk("Thanks".to_string())) })
+///     .fallback(|raw| async move {
+///         println!("Unhandled event: {}", raw);
+///         None
+///     });
+/// ```
+#[derive(Clone, Default)]
+pub struct EventRouter {
+    routes: HashMap<CallEventKind, EventHandler>,
+    fallback: Option<FallbackHandler>,
+}
+
+impl std::fmt::Debug for EventRouter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventRouter")
+            .field("routes", &self.routes.keys().collect::<Vec<_>>())
+            .field("fallback", &self.fallback.is_some())
+            .finish()
+    }
+}
+
+impl EventRouter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Handle every event of a kind, replacing any handler already registered for it
+    /// @param kind The kind of event
+    /// @param handler The async handler
+    pub fn on<F, Fut>(mut self, kind: CallEventKind, handler: F) -> Self
+    where
+        F: Fn(CallEventPayload) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Option<NCCO>> + Send + 'static,
+    {
+        self.routes
+            .insert(kind, Arc::new(move |event| handler(event).boxed()));
+        self
+    }
+
+    /// Handle events without a route, including events that could not be decoded, as raw JSON
+    /// @param handler The async handler
+    pub fn fallback<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(Value) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Option<NCCO>> + Send + 'static,
+    {
+        self.fallback = Some(Arc::new(move |raw| handler(raw).boxed()));
+        self
+    }
+
+    /// Dispatch a decoded event
+    /// @param event The event
+    /// @return The NCCO returned by the handler, if any
+    pub async fn dispatch_event(&self, event: CallEventPayload) -> Option<NCCO> {
+        match self.routes.get(&event.kind()) {
+            Some(handler) => handler(event).await,
+            None => match serde_json::to_value(&event) {
+                Ok(raw) => self.dispatch_fallback(raw).await,
+                Err(e) => {
+                    log::error!("Error encoding unrouted event: {}", e);
+                    None
+                }
+            },
+        }
+    }
+
+    /// Dispatch a raw event, events that cannot be decoded go to the fallback
+    /// @param raw The JSON of the event
+    /// @return The NCCO returned by the handler, if any
+    pub async fn dispatch(&self, raw: Value) -> Option<NCCO> {
+        match serde_json::from_value::<CallEventPayload>(raw.clone()) {
+            Ok(event) => self.dispatch_event(event).await,
+            Err(e) => {
+                log::debug!("Unknown event ({}): {}", e, raw);
+                self.dispatch_fallback(raw).await
+            }
+        }
+    }
+
+    async fn dispatch_fallback(&self, raw: Value) -> Option<NCCO> {
+        match &self.fallback {
+            Some(fallback) => fallback(raw).await,
+            None => {
+                log::trace!("Ignoring unrouted event: {}", raw);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    fn completed() -> Value {
+        json!({
+            "status": "completed",
+            "from": "447700900001",
+            "to": "447700900000",
+            "uuid": "aaa",
+            "conversation_uuid": "CON-bbb",
+            "direction": "outbound",
+            "timestamp": "2024-01-01T00:01:00.000Z",
+            "duration": "60",
+            "end_time": "2024-01-01T00:01:00.000Z",
+            "rate": "0.012",
+            "price": "0.012",
+            "disconnected_by": "user"
+        })
+    }
+
+    #[tokio::test]
+    async fn route_events_by_kind() {
+        init();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let unknown = Arc::new(Mutex::new(Vec::new()));
+        let router = {
+            let seen = seen.clone();
+            let unknown = unknown.clone();
+            EventRouter::new()
+                .on(CallEventKind::Completed, move |event| {
+                    let seen = seen.clone();
+                    async move {
+                        seen.lock().unwrap().push(event.kind());
+                        None
+                    }
+                })
+                .on(CallEventKind::Dtmf, |_| async {
+                    Some(NCCO::new().talk("Thanks".to_string()))
+                })
+                .fallback(move |raw| {
+                    let unknown = unknown.clone();
+                    async move {
+                        unknown.lock().unwrap().push(raw);
+                        None
+                    }
+                })
+        };
+
+        assert!(router.dispatch(completed()).await.is_none());
+        assert_eq!(*seen.lock().unwrap(), vec![CallEventKind::Completed]);
+
+        let input = json!({
+            "from": "447700900001",
+            "to": "447700900000",
+            "uuid": "aaa",
+            "conversation_uuid": "CON-bbb",
+            "timestamp": "2024-01-01T00:00:30.000Z",
+            "dtmf": {"dtmf": "1", "timed_out": false}
+        });
+        assert!(router.dispatch(input).await.is_some());
+
+        let ringing = json!({
+            "status": "ringing",
+            "from": "447700900001",
+            "to": "447700900000",
+            "uuid": "aaa",
+            "conversation_uuid": "CON-bbb",
+            "direction": "outbound",
+            "timestamp": "2024-01-01T00:00:00.000Z"
+        });
+        router.dispatch(ringing).await;
+        router
+            .dispatch(json!({"status": "teleported", "uuid": "aaa"}))
+            .await;
+
+        let unknown = unknown.lock().unwrap();
+        assert_eq!(unknown[0]["status"], "ringing");
+        assert_eq!(unknown[1]["status"], "teleported");
+    }
+}
@@ -0,0 +1,270 @@
+use super::*;
+
+extern crate pretty_env_logger;
+
+use log::info;
+use serde_json::Value;
+
+pub fn init() {
+    let _ = pretty_env_logger::formatted_builder()
+        .is_test(true)
+        .try_init();
+}
+
+macro_rules! fixture {
+    ($name:literal) => {
+        include_str!(concat!("fixtures/", $name, ".json"))
+    };
+}
+
+fn event(fixture: &str) -> CallEventPayload {
+    serde_json::from_str(fixture).expect("fixture should decode")
+}
+
+fn answer(fixture: &str) -> VoiceAnswerPayload {
+    serde_json::from_str(fixture).expect("fixture should decode")
+}
+
+#[test]
+fn answer_fixtures() {
+    init();
+    info!("Testing answer_fixtures");
+
+    match answer(fixture!("answer_inbound")) {
+        VoiceAnswerPayload::InboundCall {
+            from,
+            sip_headers,
+            extra,
+            ..
+        } => {
+            assert_eq!(from, "447700900001");
+            assert!(sip_headers.is_empty());
+            assert_eq!(extra["endpoint_type"], "phone");
+        }
+        other => panic!("Unexpected payload: {:?}", other),
+    }
+
+    match answer(fixture!("answer_sip")) {
+        VoiceAnswerPayload::InboundCall {
+            sip_headers, extra, ..
+        } => {
+            assert_eq!(sip_headers["SipHeader_X-UserId"], "42");
+            assert_eq!(sip_headers["SipHeader_X-Tier"], "gold");
+            assert!(extra.is_empty());
+        }
+        other => panic!("Unexpected payload: {:?}", other),
+    }
+
+    match answer(fixture!("answer_server_call")) {
+        VoiceAnswerPayload::ServerCall {
+            from_user,
+            custom_data,
+            ..
+        } => {
+            assert_eq!(from_user, "alice");
+            assert_eq!(custom_data["ticket"], "T-1");
+        }
+        other => panic!("Unexpected payload: {:?}", other),
+    }
+
+    match answer(fixture!("answer_unknown")) {
+        VoiceAnswerPayload::Unknown(raw) => assert_eq!(raw["channel"], "whatsapp"),
+        other => panic!("Unexpected payload: {:?}", other),
+    }
+}
+
+#[test]
+fn call_status_fixtures() {
+    init();
+    info!("Testing call_status_fixtures");
+
+    let fixtures = [
+        (fixture!("event_started"), CallEventKind::Started),
+        (fixture!("event_ringing"), CallEventKind::Ringing),
+        (fixture!("event_answered"), CallEventKind::Answered),
+        (fixture!("event_answered_app"), CallEventKind::Answered),
+        (fixture!("event_busy"), CallEventKind::Busy),
+        (fixture!("event_cancelled"), CallEventKind::Cancelled),
+        (fixture!("event_unanswered"), CallEventKind::Unanswered),
+        (fixture!("event_disconnected"), CallEventKind::Disconnected),
+        (fixture!("event_redirected"), CallEventKind::Redirected),
+        (fixture!("event_rejected"), CallEventKind::Rejected),
+        (fixture!("event_failed"), CallEventKind::Failed),
+        (fixture!("event_human"), CallEventKind::Human),
+        (fixture!("event_machine"), CallEventKind::Machine),
+        (fixture!("event_timeout"), CallEventKind::Timeout),
+        (fixture!("event_completed"), CallEventKind::Completed),
+    ];
+    for (fixture, kind) in fixtures {
+        assert_eq!(event(fixture).kind(), kind, "{}", fixture);
+    }
+}
+
+#[test]
+fn other_event_fixtures() {
+    init();
+    info!("Testing other_event_fixtures");
+
+    let fixtures = [
+        (fixture!("event_input_dtmf"), CallEventKind::Dtmf),
+        (fixture!("event_input_speech"), CallEventKind::Speech),
+        (fixture!("event_transfer"), CallEventKind::Transfer),
+        (
+            fixture!("event_talk"),
+            CallEventKind::Talk(PlayStatus::Finished),
+        ),
+        (
+            fixture!("event_stream"),
+            CallEventKind::Stream(PlayStatus::Stopped),
+        ),
+        (fixture!("event_record"), CallEventKind::Record),
+        (fixture!("event_record_conversation"), CallEventKind::Record),
+        (fixture!("event_notify"), CallEventKind::Notify),
+    ];
+    for (fixture, kind) in fixtures {
+        assert_eq!(event(fixture).kind(), kind, "{}", fixture);
+    }
+}
+
+#[test]
+fn optional_fields_fixtures() {
+    init();
+    info!("Testing optional_fields_fixtures");
+
+    match event(fixture!("event_answered_app")) {
+        CallEventPayload::CallStatus(CallStatusEvent::Answered { rate, network, .. }) => {
+            assert_eq!(rate, None);
+            assert_eq!(network, None);
+        }
+        other => panic!("Unexpected event: {:?}", other),
+    }
+
+    match event(fixture!("event_completed")) {
+        CallEventPayload::CallStatus(CallStatusEvent::Completed {
+            duration,
+            price,
+            disconnected_by,
+            ..
+        }) => {
+            assert_eq!(duration, Some(60));
+            assert_eq!(price, Some(0.012));
+            assert_eq!(disconnected_by, Some(CompletedByDisconnectionBy::User));
+        }
+        other => panic!("Unexpected event: {:?}", other),
+    }
+
+    match event(fixture!("event_disconnected")) {
+        CallEventPayload::CallStatus(CallStatusEvent::Disconnected { duration, .. }) => {
+            assert_eq!(duration, Some(24));
+        }
+        other => panic!("Unexpected event: {:?}", other),
+    }
+
+    match event(fixture!("event_record")) {
+        CallEventPayload::Record(RecordEvent {
+            recording_uuid,
+            start_time,
+            end_time,
+            size,
+            ..
+        }) => {
+            assert_eq!(recording_uuid, "rec");
+            assert_eq!(start_time.as_deref(), Some("2024-01-01T12:00:00Z"));
+            assert_eq!(end_time.as_deref(), Some("2024-01-01T12:01:00Z"));
+            assert_eq!(size, Some(12345));
+        }
+        other => panic!("Unexpected event: {:?}", other),
+    }
+
+    match event(fixture!("event_record_conversation")) {
+        CallEventPayload::Record(RecordEvent {
+            recording_url,
+            start_time,
+            end_time,
+            size,
+            extra,
+            ..
+        }) => {
+            assert!(recording_url.ends_with("/ffffffff-0000-1111-2222-333333333333"));
+            assert_eq!(start_time, None);
+            assert_eq!(end_time, None);
+            assert_eq!(size, Some(20480));
+            assert!(extra.is_empty());
+        }
+        other => panic!("Unexpected event: {:?}", other),
+    }
+
+    match event(fixture!("event_notify")) {
+        CallEventPayload::Notify(NotifyEvent {
+            payload,
+            conversation_uuid,
+            ..
+        }) => {
+            assert_eq!(payload["step"], "menu");
+            assert_eq!(payload["attempt"], 2);
+            assert_eq!(
+                conversation_uuid,
+                "CON-aaaaaaaa-bbbb-cccc-dddd-0123456789ab"
+            );
+        }
+        other => panic!("Unexpected event: {:?}", other),
+    }
+
+    match event(fixture!("event_human")) {
+        CallEventPayload::CallStatus(CallStatusEvent::Human {
+            sub_state, extra, ..
+        }) => {
+            assert_eq!(sub_state, None);
+            assert!(extra.contains_key("call_uuid"));
+        }
+        other => panic!("Unexpected event: {:?}", other),
+    }
+}
+
+#[test]
+fn unknown_fixtures() {
+    init();
+    info!("Testing unknown_fixtures");
+
+    match event(fixture!("event_unknown_status")) {
+        CallEventPayload::CallStatus(CallStatusEvent::Unknown(raw)) => {
+            assert_eq!(raw["status"], "hangup_pending");
+            assert_eq!(raw["reason"], "carrier");
+        }
+        other => panic!("Unexpected event: {:?}", other),
+    }
+
+    let mut raw: Value = serde_json::from_str(fixture!("event_talk")).unwrap();
+    raw["status"] = "paused".into();
+    match serde_json::from_value::<CallEventPayload>(raw).unwrap() {
+        CallEventPayload::Play(PlayEvent::Talk { status, .. }) => {
+            assert_eq!(status, PlayStatus::Unknown)
+        }
+        other => panic!("Unexpected event: {:?}", other),
+    }
+
+    let mut raw: Value = serde_json::from_str(fixture!("event_failed")).unwrap();
+    raw["detail"] = "carrier_congestion".into();
+    raw["direction"] = "sideways".into();
+    match serde_json::from_value::<CallEventPayload>(raw).unwrap() {
+        CallEventPayload::CallStatus(CallStatusEvent::Failed {
+            detail, direction, ..
+        }) => {
+            assert_eq!(detail, Some(FailureDetail::Unknown));
+            assert_eq!(direction, Direction::Unknown);
+        }
+        other => panic!("Unexpected event: {:?}", other),
+    }
+}
+
+#[test]
+fn extra_fields_round_trip() {
+    init();
+    info!("Testing extra_fields_round_trip");
+
+    let mut raw: Value = serde_json::from_str(fixture!("event_ringing")).unwrap();
+    raw["headers"] = serde_json::json!({ "X-Trace": "abc" });
+    let payload: CallEventPayload = serde_json::from_value(raw.clone()).unwrap();
+    assert_eq!(payload.kind(), CallEventKind::Ringing);
+    assert_eq!(serde_json::to_value(&payload).unwrap(), raw);
+}
@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::time::Duration;
+
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio_tungstenite::tungstenite::{self, Message};
+
+use super::{AudioSender, WebsocketError};
+use crate::voice::AudioFormat;
+
+/// Websocket Metadata
+/// The `websocket:connected` frame Vonage sends when the websocket leg connects
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebsocketMetadata {
+    pub event: String,
+    /// The format of the audio frames in both directions
+    #[serde(rename = "content-type")]
+    pub content_type: AudioFormat,
+    /// The headers set on the websocket endpoint
+    #[serde(flatten)]
+    pub headers: HashMap<String, Value>,
+}
+
+/// Audio Frame
+/// A frame of 16-bit linear PCM audio from the call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AudioFrame {
+    pub format: AudioFormat,
+    pub samples: Vec<i16>,
+}
+
+impl AudioFrame {
+    /// Decode a frame of little-endian samples, a trailing odd byte is dropped
+    pub fn from_bytes(format: AudioFormat, bytes: &[u8]) -> Self {
+        AudioFrame {
+            format,
+            samples: bytes
+                .chunks_exact(2)
+                .map(|sample| i16::from_le_bytes([sample[0], sample[1]]))
+                .collect(),
+        }
+    }
+
+    /// The length of the audio, 20ms for frames from Vonage
+    pub fn duration(&self) -> Duration {
+        Duration::from_micros(
+            self.samples.len() as u64 * 1_000_000 / self.format.sample_rate() as u64,
+        )
+    }
+}
+
+/// Websocket Event
+/// A frame received from a websocket leg
+#[derive(Debug, Clone, PartialEq)]
+pub enum WebsocketEvent {
+    Audio(AudioFrame),
+    /// A key pressed by the caller
+    Dtmf {
+        digit: String,
+        /// How long the key was held in milliseconds
+        duration: Option<u64>,
+    },
+    /// The payload of a notify action, sent once the audio queued before it has played
+    Notify(Value),
+    /// Buffered audio was dropped after a clear action
+    Cleared,
+    /// A text frame that is not modeled yet
+    Unknown(Value),
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "event")]
+enum TextEvent {
+    #[serde(rename = "websocket:dtmf")]
+    Dtmf {
+        digit: String,
+        duration: Option<u64>,
+    },
+    #[serde(rename = "websocket:notify")]
+    Notify {
+        #[serde(default)]
+        payload: Value,
+    },
+    #[serde(rename = "websocket:cleared")]
+    Cleared,
+}
+
+impl WebsocketEvent {
+    fn from_text(text: &str) -> Option<Self> {
+        let raw: Value = match serde_json::from_str(text) {
+            Ok(raw) => raw,
+            Err(e) => {
+                log::warn!(
+                    "Ignoring websocket text frame that is not JSON ({}): {}",
+                    e,
+                    text
+                );
+                return None;
+            }
+        };
+        Some(match serde_json::from_value(raw.clone()) {
+            Ok(TextEvent::Dtmf { digit, duration }) => WebsocketEvent::Dtmf { digit, duration },
+            Ok(TextEvent::Notify { payload }) => WebsocketEvent::Notify(payload),
+            Ok(TextEvent::Cleared) => WebsocketEvent::Cleared,
+            Err(_) => WebsocketEvent::Unknown(raw),
+        })
+    }
+}
+
+type MessageStream = Pin<Box<dyn Stream<Item = Result<Message, tungstenite::Error>> + Send>>;
+
+/// Websocket Call
+/// A websocket leg connected by a `connect` action or a call to a websocket endpoint
+///
+/// ## Example
+/// ```rust,no_run
+/// use vonage_client::voice::websocket::{WebsocketCall, WebsocketEvent};
+/// async fn echo(mut call: WebsocketCall) {
+///     let sender = call.sender();
+///     while let Some(Ok(event)) = call.recv().await {
+///         if let WebsocketEvent::Audio(frame) = event {
+///             sender.send_audio(&frame.samples).unwrap();
+///         }
+///     }
+/// }
+/// ```
+pub struct WebsocketCall {
+    uri: String,
+    metadata: WebsocketMetadata,
+    messages: MessageStream,
+    sender: AudioSender,
+}
+
+impl std::fmt::Debug for WebsocketCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebsocketCall")
+            .field("uri", &self.uri)
+            .field("metadata", &self.metadata)
+            .finish()
+    }
+}
+
+impl WebsocketCall {
+    pub(super) async fn connect(
+        uri: String,
+        mut messages: MessageStream,
+        sender: impl FnOnce(AudioFormat) -> AudioSender,
+    ) -> Result<Self, WebsocketError> {
+        let metadata = loop {
+            match messages.next().await.transpose()? {
+                Some(Message::Text(text)) => {
+                    break serde_json::from_str::<WebsocketMetadata>(text.as_str())
+                        .map_err(WebsocketError::InvalidMetadata)?;
+                }
+                Some(Message::Close(_)) | None => return Err(WebsocketError::MissingMetadata),
+                Some(message) => log::trace!("Ignoring frame before metadata: {:?}", message),
+            }
+        };
+        log::debug!("Websocket connected to {}: {:?}", uri, metadata);
+        Ok(WebsocketCall {
+            uri,
+            sender: sender(metadata.content_type),
+            metadata,
+            messages,
+        })
+    }
+
+    /// The path and query the websocket was connected to, as set in the endpoint `uri`
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    pub fn metadata(&self) -> &WebsocketMetadata {
+        &self.metadata
+    }
+
+    /// The format of the call audio
+    pub fn format(&self) -> AudioFormat {
+        self.metadata.content_type
+    }
+
+    /// A sender for audio back to the call
+    pub fn sender(&self) -> AudioSender {
+        self.sender.clone()
+    }
+
+    /// Receive the next event
+    /// @return The event, or `None` once the call has hung up
+    pub async fn recv(&mut self) -> Option<Result<WebsocketEvent, WebsocketError>> {
+        loop {
+            let message = match self.messages.next().await? {
+                Ok(message) => message,
+                Err(e) => return Some(Err(e.into())),
+            };
+            match message {
+                Message::Binary(bytes) => {
+                    return Some(Ok(WebsocketEvent::Audio(AudioFrame::from_bytes(
+                        self.format(),
+                        &bytes,
+                    ))))
+                }
+                Message::Text(text) => {
+                    if let Some(event) = WebsocketEvent::from_text(text.as_str()) {
+                        return Some(Ok(event));
+                    }
+                }
+                Message::Close(_) => return None,
+                Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => {}
+            }
+        }
+    }
+}
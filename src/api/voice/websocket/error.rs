@@ -0,0 +1,48 @@
+use tokio_tungstenite::tungstenite;
+
+/// Websocket Error
+/// Why a websocket connection from Vonage failed
+#[derive(Debug)]
+pub enum WebsocketError {
+    Io(std::io::Error),
+    /// The websocket handshake or connection failed
+    Websocket(Box<tungstenite::Error>),
+    /// The connection closed before the `websocket:connected` metadata frame
+    MissingMetadata,
+    InvalidMetadata(serde_json::Error),
+    /// The handshake and metadata frame did not arrive within the connect timeout
+    Timeout,
+    /// The connection is closed, no more audio can be sent
+    Closed,
+}
+
+impl std::fmt::Display for WebsocketError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebsocketError::Io(e) => write!(f, "websocket io error: {}", e),
+            WebsocketError::Websocket(e) => write!(f, "websocket error: {}", e),
+            WebsocketError::MissingMetadata => {
+                write!(f, "websocket closed before its metadata frame")
+            }
+            WebsocketError::InvalidMetadata(e) => write!(f, "invalid websocket metadata: {}", e),
+            WebsocketError::Timeout => {
+                write!(f, "websocket handshake and metadata timed out")
+            }
+            WebsocketError::Closed => write!(f, "websocket is closed"),
+        }
+    }
+}
+
+impl std::error::Error for WebsocketError {}
+
+impl From<std::io::Error> for WebsocketError {
+    fn from(e: std::io::Error) -> Self {
+        WebsocketError::Io(e)
+    }
+}
+
+impl From<tungstenite::Error> for WebsocketError {
+    fn from(e: tungstenite::Error) -> Self {
+        WebsocketError::Websocket(Box::new(e))
+    }
+}
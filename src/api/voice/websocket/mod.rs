@@ -0,0 +1,366 @@
+//! Websocket
+//! A server for websocket legs, connected with `NCCO::connect_websocket` or a `CallToWebsocket`
+//!
+//! Vonage sends a `websocket:connected` metadata frame, then 20ms frames of 16-bit linear PCM in the format of the
+//! endpoint along with text frames for DTMF and notify events, audio sent back is paced in 20ms frames
+mod call;
+mod error;
+mod sender;
+
+use std::future::Future;
+use std::io::ErrorKind;
+use std::time::Duration;
+
+use futures::StreamExt;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::{TcpListener, ToSocketAddrs};
+use tokio_tungstenite::tungstenite::handshake::server::{
+    Callback, ErrorResponse, Request, Response,
+};
+
+pub use call::{AudioFrame, WebsocketCall, WebsocketEvent, WebsocketMetadata};
+pub use error::WebsocketError;
+pub use sender::AudioSender;
+
+/// How long a connection has to complete the handshake and send its metadata frame
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+/// How long to wait before accepting again after the listener fails, such as when out of file descriptors
+const ACCEPT_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Keeps the path and query of the handshake request
+struct RecordUri<'a>(&'a mut String);
+
+impl Callback for RecordUri<'_> {
+    fn on_request(self, request: &Request, response: Response) -> Result<Response, ErrorResponse> {
+        *self.0 = request.uri().to_string();
+        Ok(response)
+    }
+}
+
+/// Accept a websocket leg on a connection, completing the handshake and reading the metadata frame
+/// There is no timeout, wrap it in `tokio::time::timeout` so a silent peer cannot hold the task forever
+/// @param stream The connection, e.g. a `TcpStream` or a TLS stream
+/// @return The connected call
+pub async fn accept<S>(stream: S) -> Result<WebsocketCall, WebsocketError>
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let mut uri = String::new();
+    let socket = tokio_tungstenite::accept_hdr_async(stream, RecordUri(&mut uri)).await?;
+    let (sink, messages) = socket.split();
+    WebsocketCall::connect(uri, Box::pin(messages), |format| {
+        AudioSender::spawn(format, sink)
+    })
+    .await
+}
+
+/// Websocket Server
+/// Listens for websocket legs from Vonage, TLS is expected to be terminated in front of it
+///
+/// ## Example
+/// ```rust,no_run
+/// use vonage_client::voice::websocket::{WebsocketEvent, WebsocketServer};
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let server = WebsocketServer::bind("0.0.0.0:8080").await?;
+/// server
+///     .serve(|mut call| async move {
+///         while let Some(Ok(event)) = call.recv().await {
+///             if let WebsocketEvent::Dtmf { digit, .. } = event {
+///                 println!("Pressed {}", digit);
+///             }
+///         }
+///     })
+///     .await;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct WebsocketServer {
+    listener: TcpListener,
+    connect_timeout: Duration,
+}
+
+impl WebsocketServer {
+    /// Listen on an address
+    /// @param address The address to listen on
+    pub async fn bind(address: impl ToSocketAddrs) -> Result<Self, WebsocketError> {
+        Ok(WebsocketServer {
+            listener: TcpListener::bind(address).await?,
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+        })
+    }
+
+    /// Set how long a connection has to complete the handshake and send its metadata frame, defaults to 10 seconds
+    /// @param timeout The connect timeout
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    pub fn local_addr(&self) -> Result<std::net::SocketAddr, WebsocketError> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    /// Accept the next websocket leg
+    /// @return The connected call
+    pub async fn accept(&self) -> Result<WebsocketCall, WebsocketError> {
+        let (stream, address) = self.listener.accept().await?;
+        log::debug!("Websocket connection from {}", address);
+        accept_within(self.connect_timeout, stream).await
+    }
+
+    /// Accept websocket legs forever, running the handler for each call on its own task
+    /// Connections that fail the handshake or send no metadata in time are logged and dropped,
+    /// errors from the listener are logged and accepting continues
+    /// @param handler The async handler for each call
+    pub async fn serve<F, Fut>(self, handler: F)
+    where
+        F: Fn(WebsocketCall) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let handler = std::sync::Arc::new(handler);
+        loop {
+            let (stream, address) = match self.listener.accept().await {
+                Ok(connection) => connection,
+                Err(e) => {
+                    log::error!("Error accepting websocket connection: {}", e);
+                    if let Some(backoff) = accept_backoff(&e) {
+                        tokio::time::sleep(backoff).await;
+                    }
+                    continue;
+                }
+            };
+            let handler = handler.clone();
+            let connect_timeout = self.connect_timeout;
+            tokio::spawn(async move {
+                match accept_within(connect_timeout, stream).await {
+                    Ok(call) => handler(call).await,
+                    Err(e) => log::warn!("Rejected websocket connection from {}: {}", address, e),
+                }
+            });
+        }
+    }
+}
+
+/// Accept a websocket leg, failing if the handshake and metadata frame take longer than the timeout
+async fn accept_within<S>(timeout: Duration, stream: S) -> Result<WebsocketCall, WebsocketError>
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    tokio::time::timeout(timeout, accept(stream))
+        .await
+        .map_err(|_| WebsocketError::Timeout)?
+}
+
+/// How long to wait after a failed accept, errors of a single connection are retried straight away
+/// while others, such as running out of file descriptors, last a while
+fn accept_backoff(e: &std::io::Error) -> Option<Duration> {
+    match e.kind() {
+        ErrorKind::ConnectionRefused
+        | ErrorKind::ConnectionAborted
+        | ErrorKind::ConnectionReset => None,
+        _ => Some(ACCEPT_BACKOFF),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use crate::voice::AudioFormat;
+    use futures::SinkExt;
+    use serde_json::json;
+    use std::time::{Duration, Instant};
+    use tokio_tungstenite::tungstenite::Message;
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    #[tokio::test]
+    async fn websocket_call() {
+        init();
+        let server = WebsocketServer::bind("127.0.0.1:0").await.unwrap();
+        let address = server.local_addr().unwrap();
+
+        let vonage = tokio::spawn(async move {
+            let stream = tokio::net::TcpStream::connect(address).await.unwrap();
+            let (mut socket, _) = tokio_tungstenite::client_async(
+                format!("ws://{}/socket?call=aaa", address),
+                stream,
+            )
+            .await
+            .unwrap();
+            socket
+                .send(Message::text(
+                    json!({
+                        "event": "websocket:connected",
+                        "content-type": "audio/l16;rate=8000",
+                        "caller": "447700900001"
+                    })
+                    .to_string(),
+                ))
+                .await
+                .unwrap();
+            let frame: Vec<u8> = (0..160i16)
+                .flat_map(|sample| sample.to_le_bytes())
+                .collect();
+            socket.send(Message::binary(frame)).await.unwrap();
+            socket
+                .send(Message::text(
+                    json!({ "event": "websocket:dtmf", "digit": "5", "duration": 260 }).to_string(),
+                ))
+                .await
+                .unwrap();
+
+            // Three frames of audio then the notify, paced 20ms apart
+            let started = Instant::now();
+            let mut frames = Vec::new();
+            loop {
+                match socket.next().await.unwrap().unwrap() {
+                    Message::Binary(frame) => frames.push(frame.len()),
+                    Message::Text(text) => {
+                        let notify: serde_json::Value =
+                            serde_json::from_str(text.as_str()).unwrap();
+                        assert_eq!(
+                            notify,
+                            json!({ "action": "notify", "payload": { "done": true } })
+                        );
+                        break;
+                    }
+                    message => panic!("Unexpected message: {:?}", message),
+                }
+            }
+            assert_eq!(frames, vec![320, 320, 320]);
+            assert!(started.elapsed() >= Duration::from_millis(40));
+            socket.close(None).await.unwrap();
+        });
+
+        let mut call = server.accept().await.unwrap();
+        assert_eq!(call.uri(), "/socket?call=aaa");
+        assert_eq!(call.format(), AudioFormat::L16_8K);
+        assert_eq!(call.metadata().headers["caller"], "447700900001");
+
+        match call.recv().await.unwrap().unwrap() {
+            WebsocketEvent::Audio(frame) => {
+                assert_eq!(frame.samples.len(), 160);
+                assert_eq!(frame.samples[10], 10);
+                assert_eq!(frame.duration(), Duration::from_millis(20));
+            }
+            event => panic!("Unexpected event: {:?}", event),
+        }
+        assert_eq!(
+            call.recv().await.unwrap().unwrap(),
+            WebsocketEvent::Dtmf {
+                digit: "5".to_string(),
+                duration: Some(260)
+            }
+        );
+
+        let sender = call.sender();
+        // Two and a half frames, the last is padded with silence
+        sender.send_audio(&[1; 400]).unwrap();
+        sender.notify(json!({ "done": true })).unwrap();
+        assert!(call.recv().await.is_none());
+        vonage.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn reject_missing_metadata() {
+        init();
+        let server = WebsocketServer::bind("127.0.0.1:0").await.unwrap();
+        let address = server.local_addr().unwrap();
+        tokio::spawn(async move {
+            let stream = tokio::net::TcpStream::connect(address).await.unwrap();
+            let (mut socket, _) =
+                tokio_tungstenite::client_async(format!("ws://{}/", address), stream)
+                    .await
+                    .unwrap();
+            socket.close(None).await.unwrap();
+        });
+        assert!(matches!(
+            server.accept().await,
+            Err(WebsocketError::MissingMetadata)
+        ));
+    }
+
+    #[tokio::test]
+    async fn time_out_idle_connections() {
+        init();
+        let server = WebsocketServer::bind("127.0.0.1:0")
+            .await
+            .unwrap()
+            .connect_timeout(Duration::from_millis(200));
+        let address = server.local_addr().unwrap();
+
+        // A connection that never starts the handshake
+        let idle = tokio::net::TcpStream::connect(address).await.unwrap();
+        let started = Instant::now();
+        assert!(matches!(
+            server.accept().await,
+            Err(WebsocketError::Timeout)
+        ));
+        assert!(started.elapsed() < Duration::from_secs(5));
+        drop(idle);
+
+        // A connection that completes the handshake but never sends its metadata
+        let client = tokio::spawn(async move {
+            let stream = tokio::net::TcpStream::connect(address).await.unwrap();
+            let (socket, _) = tokio_tungstenite::client_async(format!("ws://{}/", address), stream)
+                .await
+                .unwrap();
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            drop(socket);
+        });
+        assert!(matches!(
+            server.accept().await,
+            Err(WebsocketError::Timeout)
+        ));
+        client.abort();
+    }
+
+    #[tokio::test]
+    async fn serve_past_idle_connections() {
+        init();
+        let server = WebsocketServer::bind("127.0.0.1:0")
+            .await
+            .unwrap()
+            .connect_timeout(Duration::from_millis(200));
+        let address = server.local_addr().unwrap();
+        let (calls, mut received) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(server.serve(move |call| {
+            let calls = calls.clone();
+            async move {
+                calls.send(call.uri().to_string()).unwrap();
+            }
+        }));
+
+        let _idle = tokio::net::TcpStream::connect(address).await.unwrap();
+        let stream = tokio::net::TcpStream::connect(address).await.unwrap();
+        let (mut socket, _) =
+            tokio_tungstenite::client_async(format!("ws://{}/second", address), stream)
+                .await
+                .unwrap();
+        socket
+            .send(Message::text(
+                json!({ "event": "websocket:connected", "content-type": "audio/l16;rate=16000" })
+                    .to_string(),
+            ))
+            .await
+            .unwrap();
+        let uri = tokio::time::timeout(Duration::from_secs(5), received.recv())
+            .await
+            .unwrap();
+        assert_eq!(uri.as_deref(), Some("/second"));
+    }
+
+    #[test]
+    fn back_off_after_listener_errors() {
+        let reset = std::io::Error::from(ErrorKind::ConnectionReset);
+        assert_eq!(accept_backoff(&reset), None);
+        let exhausted = std::io::Error::from_raw_os_error(24);
+        assert_eq!(accept_backoff(&exhausted), Some(ACCEPT_BACKOFF));
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use futures::{Sink, SinkExt};
+use serde_json::{json, Value};
+use tokio::sync::mpsc;
+use tokio::time::MissedTickBehavior;
+use tokio_tungstenite::tungstenite::{self, Message};
+
+use super::WebsocketError;
+use crate::voice::AudioFormat;
+
+#[derive(Debug)]
+enum Command {
+    Audio(Vec<u8>),
+    Text(String),
+    Clear,
+    Close,
+}
+
+/// Audio Sender
+/// Sends audio back to the call in 20ms frames, paced at the rate Vonage plays them
+/// Audio is queued, so a long reply can be sent at once and interrupted with `clear`
+/// Clones send to the same call
+#[derive(Debug, Clone)]
+pub struct AudioSender {
+    format: AudioFormat,
+    commands: mpsc::UnboundedSender<Command>,
+}
+
+impl AudioSender {
+    pub(super) fn spawn<S>(format: AudioFormat, sink: S) -> Self
+    where
+        S: Sink<Message, Error = tungstenite::Error> + Unpin + Send + 'static,
+    {
+        let (commands, receiver) = mpsc::unbounded_channel();
+        tokio::spawn(write_frames(sink, receiver));
+        AudioSender { format, commands }
+    }
+
+    /// The format audio must be sent in, the same as the call audio
+    pub fn format(&self) -> AudioFormat {
+        self.format
+    }
+
+    /// Queue audio to play to the call, the last frame is padded with silence
+    /// @param samples 16-bit linear PCM samples at the sample rate of the format
+    pub fn send_audio(&self, samples: &[i16]) -> Result<(), WebsocketError> {
+        for frame in samples.chunks(self.format.frame_samples()) {
+            let mut bytes = Vec::with_capacity(self.format.frame_bytes());
+            bytes.extend(frame.iter().flat_map(|sample| sample.to_le_bytes()));
+            bytes.resize(self.format.frame_bytes(), 0);
+            self.send(Command::Audio(bytes))?;
+        }
+        Ok(())
+    }
+
+    /// Queue a notify action, Vonage answers with `WebsocketEvent::Notify` once the audio queued before it has played
+    /// @param payload Any JSON, returned as is in the event
+    pub fn notify(&self, payload: Value) -> Result<(), WebsocketError> {
+        self.send(Command::Text(
+            json!({ "action": "notify", "payload": payload }).to_string(),
+        ))
+    }
+
+    /// Drop queued audio and ask Vonage to drop audio it has buffered, e.g. when the caller barges in
+    /// Vonage answers with `WebsocketEvent::Cleared`
+    pub fn clear(&self) -> Result<(), WebsocketError> {
+        self.send(Command::Clear)
+    }
+
+    /// Close the websocket once queued audio has played, which ends the websocket leg
+    pub fn close(&self) -> Result<(), WebsocketError> {
+        self.send(Command::Close)
+    }
+
+    fn send(&self, command: Command) -> Result<(), WebsocketError> {
+        self.commands
+            .send(command)
+            .map_err(|_| WebsocketError::Closed)
+    }
+}
+
+/// Write queued frames to the socket, one audio frame per 20ms tick with text sent in order between them
+async fn write_frames<S>(mut sink: S, mut commands: mpsc::UnboundedReceiver<Command>)
+where
+    S: Sink<Message, Error = tungstenite::Error> + Unpin,
+{
+    let mut queue = VecDeque::new();
+    let mut ticker = tokio::time::interval(Duration::from_millis(AudioFormat::FRAME_MILLIS));
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let mut closing = false;
+    loop {
+        let written = tokio::select! {
+            command = commands.recv(), if !closing => match command {
+                Some(Command::Clear) => {
+                    queue.clear();
+                    sink.send(Message::text(json!({ "action": "clear" }).to_string())).await
+                }
+                Some(Command::Close) => {
+                    closing = true;
+                    Ok(())
+                }
+                Some(command) => {
+                    queue.push_back(command);
+                    Ok(())
+                }
+                None => break,
+            },
+            _ = ticker.tick(), if !queue.is_empty() => write_frame(&mut sink, &mut queue).await,
+        };
+        if let Err(e) = written {
+            log::debug!("Websocket closed while sending audio: {}", e);
+            return;
+        }
+        if closing && queue.is_empty() {
+            break;
+        }
+    }
+    if let Err(e) = sink.close().await {
+        log::debug!("Error closing websocket: {}", e);
+    }
+}
+
+async fn write_frame<S>(
+    sink: &mut S,
+    queue: &mut VecDeque<Command>,
+) -> Result<(), tungstenite::Error>
+where
+    S: Sink<Message, Error = tungstenite::Error> + Unpin,
+{
+    while let Some(command) = queue.pop_front() {
+        match command {
+            Command::Text(text) => sink.send(Message::text(text)).await?,
+            Command::Audio(frame) => return sink.send(Message::binary(frame)).await,
+            Command::Clear | Command::Close => {}
+        }
+    }
+    Ok(())
+}
@@ -1,17 +1,41 @@
-use reqwest::header::{HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_TYPE};
+use std::sync::Arc;
+use std::time::Duration;
+
 use url::Url;
 
 use crate::client_types::SensitiveUnwrap;
 
-use super::client_types::{PrivateKey, Token, TokenGenerator, VonageRegion};
+use super::client_types::{ApiSecret, PrivateKey, VonageRegion};
+use super::hosts::{ApiFamily, HostResolver};
+use super::middleware::Middleware;
+use super::token_provider::{PrivateKeyTokenProvider, TokenProvider};
 use super::VonageClient;
 
 pub struct VonageClientBuilder {
     app_id: Option<String>,
     private_key: Option<PrivateKey>,
+    token_provider: Option<Arc<dyn TokenProvider>>,
+    api_key: Option<String>,
+    api_secret: Option<ApiSecret>,
     region: Option<VonageRegion>,
     base_url: Option<String>,
+    api_base_urls: Vec<(ApiFamily, String)>,
     token_refresh: Option<usize>,
+    middleware: Vec<Arc<dyn Middleware>>,
+    http: HttpConfig,
+}
+
+/// HTTP settings applied when the builder creates the reqwest client
+#[derive(Debug, Default)]
+struct HttpConfig {
+    client: Option<reqwest::Client>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    proxy: Option<String>,
+    pool_idle_timeout: Option<Option<Duration>>,
+    pool_max_idle_per_host: Option<usize>,
+    root_certificates: Vec<Vec<u8>>,
+    user_agent_suffix: Option<String>,
 }
 
 #[allow(dead_code)] // TODO: Remove this
@@ -19,6 +43,7 @@ pub struct VonageClientBuilder {
 pub enum VonageClientBuilderError {
     MissingAppId,
     MissingPrivateKey,
+    MissingApiSecret,
     ErrorGeneratingToken(jsonwebtoken::errors::Error),
     ErrorParsingUrl(url::ParseError),
     ErrorBuildingHttpClient(reqwest::Error),
@@ -30,9 +55,15 @@ impl VonageClientBuilder {
         VonageClientBuilder {
             app_id: None,
             private_key: None,
+            token_provider: None,
+            api_key: None,
+            api_secret: None,
             region: None,
             base_url: None,
+            api_base_urls: Vec::new(),
             token_refresh: None,
+            middleware: Vec::new(),
+            http: HttpConfig::default(),
         }
     }
     /// Set the Vonage Application ID
@@ -59,6 +90,31 @@ impl VonageClientBuilder {
         self
     }
 
+    /// Set the token provider used to authenticate requests
+    /// This replaces the private_key, use it to rotate keys or sign tokens outside of this process
+    /// Set the app_id as well to use the Video API
+    /// Pass an `Arc` and keep a clone to change the provider at runtime, e.g. `RotatingKeyTokenProvider::set_keys`
+    /// @param token_provider The token provider
+    pub fn token_provider(mut self, token_provider: impl TokenProvider + 'static) -> Self {
+        self.token_provider = Some(Arc::new(token_provider));
+        self
+    }
+
+    /// Set the account API key
+    /// The API key and secret authenticate the legacy APIs, e.g. Number Insight, Numbers, SMS and Account
+    /// @param api_key The account API key
+    pub fn api_key(mut self, api_key: String) -> Self {
+        self.api_key = Some(api_key);
+        self
+    }
+
+    /// Set the account API secret
+    /// @param api_secret The account API secret
+    pub fn api_secret(mut self, api_secret: ApiSecret) -> Self {
+        self.api_secret = Some(api_secret);
+        self
+    }
+
     /// Set the Vonage Region
     /// @param region The Vonage Region
     pub fn region(mut self, region: VonageRegion) -> Self {
@@ -74,6 +130,15 @@ impl VonageClientBuilder {
         self
     }
 
+    /// Set the base URL for a single API family
+    /// This takes precedence over the region and `base_url`, use it to point one API at a test server
+    /// @param family The API family
+    /// @param base_url The base URL for the API family
+    pub fn api_base_url(mut self, family: ApiFamily, base_url: String) -> Self {
+        self.api_base_urls.push((family, base_url));
+        self
+    }
+
     /// Set the token refresh time
     /// This is the time in seconds before the token expires to refresh the token
     /// @param token_refresh The time in seconds before the token expires to refresh the token
@@ -82,34 +147,121 @@ impl VonageClientBuilder {
         self
     }
 
+    /// Add a middleware layer to the request chain
+    /// Layers run in the order they are added, the first layer added sees the request first
+    /// @param middleware The middleware layer
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Set the timeout for connecting to the Vonage API
+    /// @param timeout The connect timeout
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the total timeout for a request, from connecting until the response body has been read
+    /// @param timeout The request timeout
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http.timeout = Some(timeout);
+        self
+    }
+
+    /// Send requests through a HTTP or HTTPS proxy
+    /// The URL is parsed when the client is built
+    /// @param proxy_url The proxy URL, e.g. `http://proxy.internal:3128`
+    pub fn proxy(mut self, proxy_url: String) -> Self {
+        self.http.proxy = Some(proxy_url);
+        self
+    }
+
+    /// Set how long idle connections are kept in the pool
+    /// @param timeout The idle timeout, `None` keeps connections open indefinitely
+    pub fn pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.http.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the maximum number of idle connections kept per host
+    /// @param max The maximum number of idle connections
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.http.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Trust an additional root certificate, e.g. the CA of a TLS intercepting proxy
+    /// The certificate is parsed when the client is built
+    /// @param pem The PEM encoded root certificate
+    pub fn add_root_certificate(mut self, pem: Vec<u8>) -> Self {
+        self.http.root_certificates.push(pem);
+        self
+    }
+
+    /// Append a suffix to the user agent sent with every request
+    /// @param suffix The suffix, e.g. `my-service/1.2.0`
+    pub fn user_agent_suffix(mut self, suffix: String) -> Self {
+        self.http.user_agent_suffix = Some(suffix);
+        self
+    }
+
+    /// Use a preconfigured reqwest client
+    /// The other HTTP settings on this builder, including the user agent, are ignored when a client is provided
+    /// @param client The reqwest client
+    pub fn http_client(mut self, client: reqwest::Client) -> Self {
+        self.http.client = Some(client);
+        self
+    }
+
     pub fn build(self) -> Result<VonageClient, VonageClientBuilderError> {
         log::debug!("Building Vonage Client");
-        let app_id = self.app_id.ok_or(VonageClientBuilderError::MissingAppId)?;
-        let private_key = self
-            .private_key
-            .ok_or(VonageClientBuilderError::MissingPrivateKey)?;
-
         // Default to US region if not specified
-        let base_url = self.base_url.unwrap_or(match self.region {
-            Some(VonageRegion::EU) => "https://api-eu.vonage.com".into(),
-            Some(VonageRegion::AP) => "https://api-ap.vonage.com".into(),
-            Some(VonageRegion::US) | None => "https://api-us.vonage.com".into(),
-        });
-        let base_url = Url::parse(&base_url).map_err(|e| {
-            log::error!("Error parsing base URL: {}", e);
-            VonageClientBuilderError::ErrorParsingUrl(e)
-        })?;
-        log::trace!("Building Vonage Client with app_id: {}, private_key: {:?}, region: {:?}, base_url: {}, token_refresh: {:?}",
-            app_id, private_key, self.region, base_url, self.token_refresh);
+        let mut hosts = HostResolver::new(self.region.clone().unwrap_or(VonageRegion::US));
+        if let Some(base_url) = &self.base_url {
+            hosts = hosts.with_default_override(parse_url(base_url)?);
+        }
+        for (family, base_url) in &self.api_base_urls {
+            hosts = hosts.with_override(*family, parse_url(base_url)?);
+        }
+        log::trace!(
+            "Building Vonage Client with region: {:?}, hosts: {:?}, token_refresh: {:?}",
+            self.region,
+            hosts,
+            self.token_refresh
+        );
 
-        let token_gen = TokenGenerator::new(app_id, private_key, self.token_refresh);
+        let api_credentials = match (self.api_key, self.api_secret) {
+            (Some(api_key), Some(api_secret)) => Some((api_key, api_secret)),
+            (Some(_), None) => return Err(VonageClientBuilderError::MissingApiSecret),
+            (None, _) => None,
+        };
 
-        let (token, exp) = token_gen.generate_token().map_err(|e| {
-            log::error!("Error generating token: {}", e);
-            VonageClientBuilderError::ErrorGeneratingToken(e)
-        })?;
+        let app_id = self.app_id.clone();
+        let token_provider = match self.token_provider {
+            Some(token_provider) => Some(token_provider),
+            // A client for the legacy APIs only needs the API key and secret
+            None if self.app_id.is_none() && api_credentials.is_some() => None,
+            None => {
+                let app_id = self.app_id.ok_or(VonageClientBuilderError::MissingAppId)?;
+                let private_key = self
+                    .private_key
+                    .ok_or(VonageClientBuilderError::MissingPrivateKey)?;
+                // Validate the key up front so a bad key fails the build rather than the first request
+                jsonwebtoken::EncodingKey::from_rsa_pem(private_key.clone().unwrap().as_bytes())
+                    .map_err(|e| {
+                        log::error!("Error parsing private key: {}", e);
+                        VonageClientBuilderError::ErrorGeneratingToken(e)
+                    })?;
+                Some(Arc::new(PrivateKeyTokenProvider::new(
+                    app_id,
+                    private_key,
+                    self.token_refresh,
+                )) as Arc<dyn TokenProvider>)
+            }
+        };
 
-        let client = build_http_client(token.clone()).map_err(|e| {
+        let client = build_http_client(self.http).map_err(|e| {
             log::error!("Error building reqwest client: {}", e);
             VonageClientBuilderError::ErrorBuildingHttpClient(e)
         })?;
@@ -117,32 +269,238 @@ impl VonageClientBuilder {
         log::debug!("Vonage Client built successfully");
         Ok(VonageClient {
             client,
-            base_url,
-            token_expiry: exp,
-            token_gen,
+            hosts,
+            app_id,
+            token: None,
+            token_expiry: 0,
+            token_provider,
+            api_credentials,
             token_refresh: self.token_refresh,
+            middleware: self.middleware,
         })
     }
 }
+/// Private function to parse a base URL
+fn parse_url(base_url: &str) -> Result<Url, VonageClientBuilderError> {
+    Url::parse(base_url).map_err(|e| {
+        log::error!("Error parsing base URL: {}", e);
+        VonageClientBuilderError::ErrorParsingUrl(e)
+    })
+}
+
 /// Private function to build the HTTP client
-pub(super) fn build_http_client(token: Token) -> Result<reqwest::Client, reqwest::Error> {
+fn build_http_client(config: HttpConfig) -> Result<reqwest::Client, reqwest::Error> {
+    if let Some(client) = config.client {
+        log::debug!("Using provided HTTP client");
+        return Ok(client);
+    }
     log::debug!("Building HTTP client");
-    let user_agent = format!("VonageServerClient,{}/Rust", env!("CARGO_PKG_VERSION"));
-    let mut headers = reqwest::header::HeaderMap::new();
-    let mut auth_header =
-        HeaderValue::from_str(&format!("Bearer {}", token.clone().unwrap())).unwrap();
-    auth_header.set_sensitive(true);
-    headers.insert(AUTHORIZATION, auth_header);
-    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-    headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+    let mut user_agent = format!("VonageServerClient,{}/Rust", env!("CARGO_PKG_VERSION"));
+    if let Some(suffix) = &config.user_agent_suffix {
+        user_agent = format!("{} {}", user_agent, suffix);
+    }
     log::trace!(
-        "Building HTTP client with user agent: {}, headers: {:?}, with token: {}",
+        "Building HTTP client with user agent: {}, connect_timeout: {:?}, timeout: {:?}, proxy: {}, root certificates: {}",
         user_agent,
-        headers,
-        token
+        config.connect_timeout,
+        config.timeout,
+        config.proxy.is_some(),
+        config.root_certificates.len()
     );
-    reqwest::Client::builder()
-        .user_agent(user_agent)
-        .default_headers(headers)
-        .build()
+    let mut builder = reqwest::Client::builder().user_agent(user_agent);
+    if let Some(connect_timeout) = config.connect_timeout {
+        builder = builder.connect_timeout(connect_timeout);
+    }
+    if let Some(timeout) = config.timeout {
+        builder = builder.timeout(timeout);
+    }
+    if let Some(proxy_url) = &config.proxy {
+        builder = builder.proxy(reqwest::Proxy::all(proxy_url)?);
+    }
+    if let Some(pool_idle_timeout) = config.pool_idle_timeout {
+        builder = builder.pool_idle_timeout(pool_idle_timeout);
+    }
+    if let Some(max) = config.pool_max_idle_per_host {
+        builder = builder.pool_max_idle_per_host(max);
+    }
+    for pem in &config.root_certificates {
+        builder = builder.add_root_certificate(reqwest::Certificate::from_pem(pem)?);
+    }
+    builder.build()
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use crate::client::mock::{LocalServer, MockServer};
+    use crate::client::path::ApiPath;
+    use crate::{StaticTokenProvider, VonageClientError};
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    fn builder(server: &LocalServer) -> VonageClientBuilder {
+        VonageClient::builder()
+            .token_provider(StaticTokenProvider::new("token".into(), usize::MAX))
+            .api_base_url(ApiFamily::Voice, server.url().to_string())
+    }
+
+    /// The value of a header in a raw request head
+    fn header<'a>(head: &'a str, name: &str) -> Option<&'a str> {
+        head.lines().skip(1).find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.eq_ignore_ascii_case(name).then(|| value.trim())
+        })
+    }
+
+    #[tokio::test]
+    async fn build_with_http_config() {
+        init();
+        let server = LocalServer::start(MockServer::default().respond(200, "{}")).await;
+        let mut client = builder(&server)
+            .connect_timeout(Duration::from_secs(2))
+            .timeout(Duration::from_secs(10))
+            .pool_idle_timeout(Some(Duration::from_secs(30)))
+            .pool_max_idle_per_host(4)
+            .user_agent_suffix("test-suite/1.0".into())
+            .build()
+            .unwrap();
+        let _: serde_json::Value = client
+            .get(ApiPath::new(ApiFamily::Voice, "/v1/calls"))
+            .await
+            .unwrap();
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /v1/calls "));
+        let user_agent = header(&requests[0], "user-agent").unwrap();
+        assert!(user_agent.starts_with("VonageServerClient,"));
+        assert!(user_agent.ends_with(" test-suite/1.0"));
+        assert_eq!(header(&requests[0], "accept"), Some("application/json"));
+        assert_eq!(header(&requests[0], "authorization"), Some("Bearer token"));
+    }
+
+    #[tokio::test]
+    async fn build_with_custom_http_client() {
+        init();
+        let server = LocalServer::start(MockServer::default().respond(200, "{}")).await;
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("x-injected", "yes".parse().unwrap());
+        let http_client = reqwest::Client::builder()
+            .default_headers(headers)
+            .build()
+            .unwrap();
+        let mut client = builder(&server)
+            .http_client(http_client)
+            .user_agent_suffix("ignored/1.0".into())
+            .build()
+            .unwrap();
+        let _: serde_json::Value = client
+            .get(ApiPath::new(ApiFamily::Voice, "/v1/calls"))
+            .await
+            .unwrap();
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(header(&requests[0], "x-injected"), Some("yes"));
+        assert_eq!(header(&requests[0], "user-agent"), None);
+    }
+
+    #[tokio::test]
+    async fn timeout_unanswered_request() {
+        init();
+        // Nothing queued, so the server reads the request and never answers
+        let server = LocalServer::start(MockServer::default()).await;
+        let mut client = builder(&server)
+            .timeout(Duration::from_millis(200))
+            .build()
+            .unwrap();
+        let started = std::time::Instant::now();
+        let result: Result<serde_json::Value, _> = client
+            .get(ApiPath::new(ApiFamily::Voice, "/v1/calls"))
+            .await;
+        match result {
+            Err(VonageClientError::HttpClientError(e)) => assert!(e.is_timeout()),
+            other => panic!("Expected a timeout, got {:?}", other),
+        }
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn connect_timeout_unaccepted_connection() {
+        init();
+        // A listener that is never accepted from, with its backlog filled so new connections hang
+        let socket = tokio::net::TcpSocket::new_v4().unwrap();
+        socket.bind("127.0.0.1:0".parse().unwrap()).unwrap();
+        let listener = socket.listen(1).unwrap();
+        let address = listener.local_addr().unwrap();
+        let mut backlog = Vec::new();
+        for _ in 0..8 {
+            let socket = tokio::net::TcpSocket::new_v4().unwrap();
+            if let Ok(Ok(stream)) =
+                tokio::time::timeout(Duration::from_millis(100), socket.connect(address)).await
+            {
+                backlog.push(stream);
+            }
+        }
+        let mut client = VonageClient::builder()
+            .token_provider(StaticTokenProvider::new("token".into(), usize::MAX))
+            .api_base_url(ApiFamily::Voice, format!("http://{}", address))
+            .connect_timeout(Duration::from_millis(200))
+            .build()
+            .unwrap();
+        let started = std::time::Instant::now();
+        let result: Result<serde_json::Value, _> = client
+            .get(ApiPath::new(ApiFamily::Voice, "/v1/calls"))
+            .await;
+        match result {
+            Err(VonageClientError::HttpClientError(e)) => {
+                assert!(e.is_connect() && e.is_timeout(), "{:?}", e)
+            }
+            other => panic!("Expected a connect timeout, got {:?}", other),
+        }
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn build_with_invalid_root_certificate() {
+        init();
+        let result = VonageClient::builder()
+            .token_provider(StaticTokenProvider::new("token".into(), usize::MAX))
+            .add_root_certificate(
+                b"-----BEGIN CERTIFICATE-----\nnot a certificate\n-----END CERTIFICATE-----\n"
+                    .to_vec(),
+            )
+            .build();
+        assert!(matches!(
+            result,
+            Err(VonageClientBuilderError::ErrorBuildingHttpClient(_))
+        ));
+    }
+
+    #[test]
+    fn build_with_invalid_proxy() {
+        init();
+        let result = VonageClient::builder()
+            .token_provider(StaticTokenProvider::new("token".into(), usize::MAX))
+            .proxy("http://proxy.internal:port".into())
+            .build();
+        assert!(matches!(
+            result,
+            Err(VonageClientBuilderError::ErrorBuildingHttpClient(_))
+        ));
+    }
+
+    #[test]
+    fn build_with_proxy() {
+        init();
+        VonageClient::builder()
+            .token_provider(StaticTokenProvider::new("token".into(), usize::MAX))
+            .proxy("http://proxy.internal:3128".into())
+            .build()
+            .unwrap();
+    }
 }
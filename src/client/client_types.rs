@@ -1,6 +1,7 @@
 use std::fmt::Debug;
 
-use super::VonageJwt;
+use serde::{Deserialize, Deserializer};
+use url::Url;
 
 #[derive(Debug, Clone)]
 pub enum VonageRegion {
@@ -9,32 +10,9 @@ pub enum VonageRegion {
     AP,
 }
 
-#[derive(Clone, PartialEq, Eq, Debug)]
-pub(crate) struct TokenGenerator {
-    app_id: String,
-    private_key: Sensitive<String>,
-    expiry: usize,
-}
-
-impl TokenGenerator {
-    pub fn new(app_id: String, private_key: PrivateKey, expiry: Option<usize>) -> Self {
-        log::trace!("Creating TokenGenerator for app_id: {}", app_id);
-        TokenGenerator {
-            app_id,
-            private_key,
-            expiry: expiry.unwrap_or(3600),
-        }
-    }
-
-    pub(crate) fn generate_token(&self) -> Result<(Token, usize), jsonwebtoken::errors::Error> {
-        VonageJwt::new(self.app_id.clone())
-            .set_exp(self.expiry)
-            .generate(self.private_key.clone())
-    }
-}
-
 pub type PrivateKey = Sensitive<String>;
 pub type Token = Sensitive<String>;
+pub type ApiSecret = Sensitive<String>;
 
 pub trait SensitiveUnwrap<T> {
     fn unwrap(self) -> T;
@@ -61,28 +39,74 @@ impl From<&str> for Sensitive<String> {
     }
 }
 
+/// Secrets returned by the API, e.g. a generated private key, are wrapped as they are read
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Sensitive<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Sensitive)
+    }
+}
+
 impl<T> SensitiveUnwrap<T> for Sensitive<T> {
     fn unwrap(self) -> T {
         self.0
     }
 }
 
-impl<T: Debug> Debug for Sensitive<T> {
+/// Sensitive values are redacted in every build profile, use `SensitiveUnwrap` to get at the value
+impl<T> Debug for Sensitive<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if cfg!(debug_assertions) {
-            f.debug_tuple("Sensitive").field(&self.0).finish()
-        } else {
-            f.debug_tuple("Sensitive").field(&"<********>").finish()
-        }
+        f.debug_tuple("Sensitive").field(&"<********>").finish()
     }
 }
 
-impl<T: std::fmt::Display> std::fmt::Display for Sensitive<T> {
+impl<T> std::fmt::Display for Sensitive<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if cfg!(debug_assertions) {
-            write!(f, "Sensitive<{}>", self.0)
-        } else {
-            write!(f, "<********>")
-        }
+        write!(f, "<********>")
+    }
+}
+
+/// Redact a phone number for logging, keeping only the last 4 digits
+/// @param number The phone number to redact
+/// @return The redacted phone number
+#[cfg(any(feature = "number_insight", feature = "sms", feature = "voice"))]
+pub(crate) fn redact_number(number: &str) -> String {
+    let hidden = number.chars().count().saturating_sub(4);
+    number
+        .chars()
+        .enumerate()
+        .map(|(i, c)| if i < hidden { '*' } else { c })
+        .collect()
+}
+
+/// Redact a request URL for logging, dropping the query which can hold phone numbers and credentials
+/// @param url The URL to redact
+/// @return The origin and path of the URL
+pub(crate) fn redact_url(url: &Url) -> String {
+    format!("{}{}", url.origin().ascii_serialization(), url.path())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sensitive_is_redacted() {
+        let token: Token = "secret-token".into();
+        assert_eq!(format!("{}", token), "<********>");
+        assert_eq!(format!("{:?}", token), "Sensitive(\"<********>\")");
+        assert_eq!(token.unwrap(), "secret-token");
+    }
+
+    #[cfg(any(feature = "number_insight", feature = "sms", feature = "voice"))]
+    #[test]
+    fn redact_numbers() {
+        assert_eq!(redact_number("447700900123"), "********0123");
+        assert_eq!(redact_number("123"), "123");
+    }
+
+    #[test]
+    fn redact_urls() {
+        let url = Url::parse("https://api.nexmo.com/ni/basic/json?number=447700900123").unwrap();
+        assert_eq!(redact_url(&url), "https://api.nexmo.com/ni/basic/json");
     }
 }
@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use url::Url;
+
+use super::client_types::VonageRegion;
+
+/// The domains Vonage serves its APIs and media from, including regional hosts such as `api-us-3.vonage.com`
+const VONAGE_DOMAINS: [&str; 2] = ["nexmo.com", "vonage.com"];
+
+/// Whether a URL is HTTPS on a Vonage host, only these are trusted with credentials
+pub(crate) fn is_vonage_url(url: &Url) -> bool {
+    let vonage_host = url.host_str().is_some_and(|host| {
+        VONAGE_DOMAINS
+            .iter()
+            .any(|domain| host == *domain || host.ends_with(&format!(".{}", domain)))
+    });
+    url.scheme() == "https" && vonage_host
+}
+
+/// Region URL Error
+/// Why a region URL was not used
+#[derive(Debug)]
+pub enum RegionUrlError {
+    InvalidUrl(url::ParseError),
+    /// The URL is not HTTPS on a Vonage host, so requests with the application token are not sent to it
+    UntrustedHost(String),
+}
+
+/// API Family
+/// Vonage serves its APIs from several hosts, each family of APIs is routed to its own base URL
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApiFamily {
+    /// Voice API, served from the regional Vonage host
+    Voice,
+    /// Conversation API, served from the regional Vonage host
+    Conversation,
+    /// APIs served from api.nexmo.com, e.g. Applications, Number Insight, Reports and Subaccounts
+    Api,
+    /// Legacy APIs served from rest.nexmo.com, e.g. Numbers, SMS, Account and Pricing
+    Rest,
+    /// Video API, served from video.api.vonage.com
+    Video,
+}
+
+/// Host Resolver
+/// Picks the base URL for each API family from the region, with optional per family overrides
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::{ApiFamily, HostResolver, VonageRegion};
+/// let hosts = HostResolver::new(VonageRegion::EU)
+///     .with_override(ApiFamily::Rest, "http://localhost:8080".parse().unwrap());
+/// assert_eq!(hosts.resolve(ApiFamily::Voice).as_str(), "https://api-eu.vonage.com/");
+/// assert_eq!(hosts.resolve(ApiFamily::Rest).as_str(), "http://localhost:8080/");
+/// ```
+#[derive(Debug, Clone)]
+pub struct HostResolver {
+    region: VonageRegion,
+    overrides: HashMap<ApiFamily, Url>,
+    default_override: Option<Url>,
+}
+
+impl HostResolver {
+    /// Create a new HostResolver for a region
+    /// @param region The Vonage Region used by regional APIs
+    pub fn new(region: VonageRegion) -> Self {
+        HostResolver {
+            region,
+            overrides: HashMap::new(),
+            default_override: None,
+        }
+    }
+
+    /// Route a single API family to a different base URL
+    /// This is useful in testing, or to send mid-call operations to the `region_url` of a call
+    /// @param family The API family
+    /// @param base_url The base URL for the API family
+    pub fn with_override(mut self, family: ApiFamily, base_url: Url) -> Self {
+        self.overrides.insert(family, base_url);
+        self
+    }
+
+    /// Route every API family without its own override to a single base URL
+    /// @param base_url The base URL for all API families
+    pub fn with_default_override(mut self, base_url: Url) -> Self {
+        self.default_override = Some(base_url);
+        self
+    }
+
+    /// Resolve the base URL for an API family
+    /// @param family The API family
+    /// @return The base URL
+    pub fn resolve(&self, family: ApiFamily) -> Url {
+        if let Some(url) = self
+            .overrides
+            .get(&family)
+            .or(self.default_override.as_ref())
+        {
+            return url.clone();
+        }
+        let host = match family {
+            ApiFamily::Voice | ApiFamily::Conversation => match self.region {
+                VonageRegion::US => "https://api-us.vonage.com",
+                VonageRegion::EU => "https://api-eu.vonage.com",
+                VonageRegion::AP => "https://api-ap.vonage.com",
+            },
+            ApiFamily::Api => "https://api.nexmo.com",
+            ApiFamily::Rest => "https://rest.nexmo.com",
+            ApiFamily::Video => "https://video.api.vonage.com",
+        };
+        Url::parse(host).expect("default hosts are valid URLs")
+    }
+}
+
+impl Default for HostResolver {
+    /// Create a HostResolver for the US region
+    fn default() -> Self {
+        HostResolver::new(VonageRegion::US)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_default_hosts() {
+        let hosts = HostResolver::new(VonageRegion::AP);
+        assert_eq!(
+            hosts.resolve(ApiFamily::Voice).as_str(),
+            "https://api-ap.vonage.com/"
+        );
+        assert_eq!(
+            hosts.resolve(ApiFamily::Conversation).as_str(),
+            "https://api-ap.vonage.com/"
+        );
+        assert_eq!(
+            hosts.resolve(ApiFamily::Api).as_str(),
+            "https://api.nexmo.com/"
+        );
+        assert_eq!(
+            hosts.resolve(ApiFamily::Rest).as_str(),
+            "https://rest.nexmo.com/"
+        );
+        assert_eq!(
+            hosts.resolve(ApiFamily::Video).as_str(),
+            "https://video.api.vonage.com/"
+        );
+    }
+
+    #[test]
+    fn vonage_urls() {
+        for url in [
+            "https://api-us-3.vonage.com",
+            "https://api.nexmo.com/v1/files/aaa",
+            "https://vonage.com",
+        ] {
+            assert!(is_vonage_url(&Url::parse(url).unwrap()), "{}", url);
+        }
+        for url in [
+            "http://api-us-3.vonage.com",
+            "https://api.nexmo.com.example.com",
+            "https://evilvonage.com",
+            "https://example.com/api.nexmo.com",
+        ] {
+            assert!(!is_vonage_url(&Url::parse(url).unwrap()), "{}", url);
+        }
+    }
+
+    #[test]
+    fn resolve_overrides() {
+        let hosts = HostResolver::default()
+            .with_default_override(Url::parse("http://localhost:9000").unwrap())
+            .with_override(
+                ApiFamily::Voice,
+                Url::parse("https://api-us-3.vonage.com").unwrap(),
+            );
+        assert_eq!(
+            hosts.resolve(ApiFamily::Voice).as_str(),
+            "https://api-us-3.vonage.com/"
+        );
+        assert_eq!(
+            hosts.resolve(ApiFamily::Rest).as_str(),
+            "http://localhost:9000/"
+        );
+    }
+}
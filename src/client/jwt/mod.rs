@@ -4,6 +4,7 @@ mod time;
 use jsonwebtoken::Algorithm;
 use log::info;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 // local imports
 pub(crate) use jti::generate_jti;
@@ -42,6 +43,8 @@ pub struct VonageJwt {
     sub: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     acl: Option<VonageAcl>,
+    #[serde(flatten)]
+    claims: Map<String, Value>,
 }
 impl VonageJwt {
     /// Create a new Vonage JWT Claims object for an application token
@@ -62,6 +65,7 @@ impl VonageJwt {
             nbf: now,
             sub: None,
             acl: None,
+            claims: Map::new(),
         }
     }
 
@@ -84,6 +88,7 @@ impl VonageJwt {
             jti: generate_jti(),
             nbf: now,
             acl: None,
+            claims: Map::new(),
         }
     }
 
@@ -114,6 +119,24 @@ impl VonageJwt {
         self
     }
 
+    /// Add a claim that is not part of the standard Vonage claims, e.g. the `scope` of a Video client token
+    /// @param name The name of the claim
+    /// @param value The value of the claim
+    /// ```rust
+    /// use vonage_client::client::jwt::VonageJwt;
+    /// let mut claims = VonageJwt::new("application_id".to_string());
+    /// claims.add_claim("scope".to_string(), "session.connect".into());
+    /// ```
+    pub fn add_claim(&mut self, name: String, value: Value) -> &mut Self {
+        self.claims.insert(name, value);
+        self
+    }
+
+    /// Get the expiry time of the JWT Claims as a unix timestamp
+    pub fn exp(&self) -> usize {
+        self.exp
+    }
+
     /// Generate the JWT token for Vonage API requests
     /// @param private_key The private key to sign the token
     /// @return The JWT token and expiry time in seconds
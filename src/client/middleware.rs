@@ -0,0 +1,238 @@
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reqwest::{Request, Response};
+
+use super::VonageClientError;
+
+/// Middleware
+/// A layer in the request chain of `VonageClient`, each layer can inspect or mutate the outgoing
+/// request, the response, or skip the rest of the chain entirely
+///
+/// Layers run in the order they are added to the builder, the first layer added sees the request first
+/// and the response last.
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::{Middleware, Next, VonageClientError};
+/// use reqwest::{Request, Response};
+///
+/// #[derive(Debug)]
+/// struct CorrelationId;
+///
+/// #[async_trait::async_trait]
+/// impl Middleware for CorrelationId {
+///     async fn handle(&self, mut req: Request, next: Next<'_>) -> Result<Response, VonageClientError> {
+///         req.headers_mut().insert("x-correlation-id", "my-id".parse().unwrap());
+///         next.run(req).await
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait Middleware: Debug + Send + Sync {
+    /// Handle a request
+    /// @param req The outgoing request
+    /// @param next The remainder of the chain, call `next.run(req)` to continue
+    /// @return The response from the remainder of the chain
+    async fn handle(&self, req: Request, next: Next<'_>) -> Result<Response, VonageClientError>;
+}
+
+/// The remainder of a middleware chain, ending with the HTTP client
+///
+/// `Next` is `Copy`, so a layer may run the rest of the chain more than once, for example to retry.
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+    client: &'a reqwest::Client,
+    middleware: &'a [Arc<dyn Middleware>],
+    attempts: &'a AtomicUsize,
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(
+        client: &'a reqwest::Client,
+        middleware: &'a [Arc<dyn Middleware>],
+        attempts: &'a AtomicUsize,
+    ) -> Self {
+        Next {
+            client,
+            middleware,
+            attempts,
+        }
+    }
+
+    /// Run the rest of the chain
+    /// @param req The request to pass on
+    /// @return The response from the rest of the chain
+    pub async fn run(self, req: Request) -> Result<Response, VonageClientError> {
+        match self.middleware.split_first() {
+            Some((current, rest)) => {
+                current
+                    .handle(
+                        req,
+                        Next {
+                            middleware: rest,
+                            ..self
+                        },
+                    )
+                    .await
+            }
+            None => {
+                self.attempts.fetch_add(1, Ordering::Relaxed);
+                self.client
+                    .execute(req)
+                    .await
+                    .map_err(super::VonageClient::map_client_error)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use crate::client::path::ApiPath;
+    use crate::{ApiFamily, StaticTokenProvider, VonageClient};
+    use serde_json::{json, Value};
+    use std::sync::Mutex;
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    #[derive(Debug, Default)]
+    struct Recorder {
+        seen: Mutex<Vec<String>>,
+    }
+
+    #[derive(Debug)]
+    struct Tag(&'static str, Arc<Recorder>);
+
+    #[async_trait]
+    impl Middleware for Tag {
+        async fn handle(
+            &self,
+            mut req: Request,
+            next: Next<'_>,
+        ) -> Result<Response, VonageClientError> {
+            self.1
+                .seen
+                .lock()
+                .unwrap()
+                .push(format!("{} request", self.0));
+            req.headers_mut().append("x-layer", self.0.parse().unwrap());
+            let res = next.run(req).await;
+            self.1
+                .seen
+                .lock()
+                .unwrap()
+                .push(format!("{} response", self.0));
+            res
+        }
+    }
+
+    /// Answers every request without touching the network
+    #[derive(Debug)]
+    struct Fake(Arc<Recorder>);
+
+    #[async_trait]
+    impl Middleware for Fake {
+        async fn handle(
+            &self,
+            req: Request,
+            _next: Next<'_>,
+        ) -> Result<Response, VonageClientError> {
+            let layers = req
+                .headers()
+                .get_all("x-layer")
+                .iter()
+                .map(|v| v.to_str().unwrap().to_string())
+                .collect::<Vec<_>>();
+            self.0.seen.lock().unwrap().push(format!(
+                "fake {} {} {}",
+                req.method(),
+                req.url().path(),
+                layers.join(",")
+            ));
+            assert_eq!(
+                req.headers().get("authorization").unwrap(),
+                "Bearer static-token"
+            );
+            let body = json!({ "ok": true }).to_string();
+            Ok(http::Response::builder()
+                .status(200)
+                .body(body)
+                .unwrap()
+                .into())
+        }
+    }
+
+    #[tokio::test]
+    async fn middleware_chain_order() {
+        init();
+        let recorder = Arc::new(Recorder::default());
+        let mut client = VonageClient::builder()
+            .token_provider(StaticTokenProvider::new("static-token".into(), usize::MAX))
+            .middleware(Tag("outer", recorder.clone()))
+            .middleware(Tag("inner", recorder.clone()))
+            .middleware(Fake(recorder.clone()))
+            .build()
+            .unwrap();
+
+        let res: Value = client
+            .put(ApiPath::new(ApiFamily::Api, "/v1/things/1"), json!({}))
+            .await
+            .unwrap();
+        assert_eq!(res, json!({ "ok": true }));
+        assert_eq!(
+            *recorder.seen.lock().unwrap(),
+            vec![
+                "outer request",
+                "inner request",
+                "fake PUT /v1/things/1 outer,inner",
+                "inner response",
+                "outer response",
+            ]
+        );
+    }
+
+    #[derive(Debug)]
+    struct Unavailable;
+
+    #[async_trait]
+    impl Middleware for Unavailable {
+        async fn handle(
+            &self,
+            _req: Request,
+            _next: Next<'_>,
+        ) -> Result<Response, VonageClientError> {
+            Ok(http::Response::builder()
+                .status(503)
+                .body("")
+                .unwrap()
+                .into())
+        }
+    }
+
+    #[tokio::test]
+    async fn middleware_fault_injection() {
+        init();
+        let mut client = VonageClient::builder()
+            .token_provider(StaticTokenProvider::new("static-token".into(), usize::MAX))
+            .middleware(Unavailable)
+            .build()
+            .unwrap();
+
+        let res = client
+            .get::<Value>(ApiPath::new(ApiFamily::Conversation, "/v1/users"))
+            .await;
+        assert!(matches!(
+            res,
+            Err(VonageClientError::RequestError(code, _)) if code.as_u16() == 503
+        ));
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use reqwest::{Method, Request, Response};
+use url::Url;
+
+use super::{Middleware, Next, StaticTokenProvider, VonageClient, VonageClientError};
+
+/// A request seen by the mock server
+#[derive(Debug, Clone)]
+pub(crate) struct RecordedRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: reqwest::header::HeaderMap,
+    pub body: Option<String>,
+}
+
+impl RecordedRequest {
+    /// The path and query of the request
+    pub fn path_and_query(&self) -> String {
+        match self.url.query() {
+            Some(query) => format!("{}?{}", self.url.path(), query),
+            None => self.url.path().to_string(),
+        }
+    }
+}
+
+/// A canned response
+#[derive(Debug)]
+struct MockResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+/// Mock Server
+/// A middleware that records each request and answers with canned responses without touching the network
+#[derive(Debug, Clone, Default)]
+pub(crate) struct MockServer {
+    responses: Arc<Mutex<VecDeque<MockResponse>>>,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl MockServer {
+    /// Queue a response, requests are answered in order and with `200 {}` once the queue is empty
+    pub fn respond(self, status: u16, body: &str) -> Self {
+        self.respond_bytes(status, body.as_bytes().to_vec())
+    }
+
+    /// Queue a response with a binary body, such as a file download
+    pub fn respond_bytes(self, status: u16, body: Vec<u8>) -> Self {
+        self.respond_with_headers(status, &[], body)
+    }
+
+    /// Queue a response with headers, such as the `Content-Range` of a partial download
+    pub fn respond_with_headers(
+        self,
+        status: u16,
+        headers: &[(&str, &str)],
+        body: Vec<u8>,
+    ) -> Self {
+        let headers = headers
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        self.responses.lock().unwrap().push_back(MockResponse {
+            status,
+            headers,
+            body,
+        });
+        self
+    }
+
+    /// A client authenticated with a static token and an API key and secret that sends requests here
+    pub fn client(&self) -> VonageClient {
+        VonageClient::builder()
+            .token_provider(StaticTokenProvider::new("token".into(), usize::MAX))
+            .api_key("key".into())
+            .api_secret("secret".into())
+            .middleware(self.clone())
+            .build()
+            .unwrap()
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl Middleware for MockServer {
+    async fn handle(&self, req: Request, _next: Next<'_>) -> Result<Response, VonageClientError> {
+        self.requests.lock().unwrap().push(RecordedRequest {
+            method: req.method().clone(),
+            url: req.url().clone(),
+            headers: req.headers().clone(),
+            body: req
+                .body()
+                .and_then(|body| body.as_bytes())
+                .map(|body| String::from_utf8_lossy(body).into_owned()),
+        });
+        let MockResponse {
+            status,
+            headers,
+            body,
+        } = self
+            .responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .unwrap_or(MockResponse {
+                status: 200,
+                headers: Vec::new(),
+                body: b"{}".to_vec(),
+            });
+        let mut response = http::Response::builder().status(status);
+        for (name, value) in headers {
+            response = response.header(name, value);
+        }
+        Ok(response.body(body).unwrap().into())
+    }
+}
+
+/// Local Server
+/// A HTTP server on a local port, for tests of the HTTP client itself such as headers and timeouts
+/// Each connection is answered with the next queued response, once the queue is empty connections are held open unanswered
+#[derive(Debug)]
+pub(crate) struct LocalServer {
+    url: Url,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl LocalServer {
+    /// Start serving the queued responses of a `MockServer`
+    pub async fn start(responses: MockServer) -> Self {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        tokio::spawn({
+            let requests = requests.clone();
+            async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    let response = responses.responses.lock().unwrap().pop_front();
+                    tokio::spawn(answer(stream, response, requests.clone()));
+                }
+            }
+        });
+        LocalServer { url, requests }
+    }
+
+    /// The base URL of the server
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// The head of each request received, the request line and headers
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+async fn answer(
+    mut stream: tokio::net::TcpStream,
+    response: Option<MockResponse>,
+    requests: Arc<Mutex<Vec<String>>>,
+) {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    let mut received = Vec::new();
+    let mut buffer = [0; 1024];
+    let head = loop {
+        match stream.read(&mut buffer).await {
+            Ok(0) | Err(_) => return,
+            Ok(read) => received.extend_from_slice(&buffer[..read]),
+        }
+        if let Some(end) = received.windows(4).position(|window| window == b"\r\n\r\n") {
+            break String::from_utf8_lossy(&received[..end]).into_owned();
+        }
+    };
+    requests.lock().unwrap().push(head);
+    let Some(MockResponse {
+        status,
+        headers,
+        body,
+    }) = response
+    else {
+        // Never answer, so the client times out
+        std::future::pending::<()>().await;
+        return;
+    };
+    let mut response = format!(
+        "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n",
+        status,
+        body.len()
+    );
+    for (name, value) in headers {
+        response.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    response.push_str("\r\n");
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.write_all(&body).await;
+    let _ = stream.shutdown().await;
+}
@@ -1,19 +1,42 @@
 mod builder;
 pub(crate) mod client_types;
+mod hosts;
 pub(crate) mod jwt;
+mod middleware;
+#[cfg(test)]
+pub(crate) mod mock;
+mod paginate;
+pub(crate) mod path;
+mod telemetry;
+mod token_provider;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
-use builder::build_http_client;
-use client_types::TokenGenerator;
 use jwt::now_timestamp;
+use path::ApiPath;
+use telemetry::RequestTelemetry;
 
-use reqwest::Response;
+use reqwest::header::{HeaderMap, ACCEPT, CONTENT_TYPE};
+use reqwest::{Method, Response};
 use url::Url;
 
-pub(crate) use jwt::VonageJwt;
+pub use builder::{VonageClientBuilder, VonageClientBuilderError};
+
+/// How long before it expires a token is refreshed, in seconds, unless `token_refresh` is set
+const DEFAULT_TOKEN_REFRESH: usize = 60;
 
-pub use builder::VonageClientBuilder;
+pub(crate) use hosts::is_vonage_url;
+pub use hosts::{ApiFamily, HostResolver, RegionUrlError};
+pub use jwt::{AclMethod, VonageAcl, VonageJwt};
+pub use middleware::{Middleware, Next};
+pub use path::AuthMethod;
 
-pub use client_types::{PrivateKey, Sensitive, SensitiveUnwrap, Token, VonageRegion};
+pub use client_types::{ApiSecret, PrivateKey, Sensitive, SensitiveUnwrap, Token, VonageRegion};
+pub use token_provider::{
+    JwtSigner, PrivateKeyTokenProvider, RotatingKeyTokenProvider, SignerTokenProvider,
+    StaticTokenProvider, TokenProvider, TokenProviderError,
+};
 
 /// Vonage Client
 /// This struct represents a Vonage Client and is used to make requests to the Vonage API
@@ -31,10 +54,14 @@ pub use client_types::{PrivateKey, Sensitive, SensitiveUnwrap, Token, VonageRegi
 #[derive(Debug, Clone)]
 pub struct VonageClient {
     client: reqwest::Client,
-    base_url: Url,
+    hosts: HostResolver,
+    app_id: Option<String>,
+    token: Option<Token>,
     token_expiry: usize,
     token_refresh: Option<usize>,
-    token_gen: TokenGenerator,
+    token_provider: Option<Arc<dyn TokenProvider>>,
+    api_credentials: Option<(String, ApiSecret)>,
+    middleware: Vec<Arc<dyn Middleware>>,
 }
 
 #[derive(Debug)]
@@ -43,120 +70,430 @@ pub enum VonageClientError {
     ResponseParseError(reqwest::Error),
     RequestParseError(serde_json::Error),
     HttpClientError(reqwest::Error),
-    TokenRefreshClientError(reqwest::Error),
-    TokenRefreshError(jsonwebtoken::errors::Error),
+    TokenRefreshError(TokenProviderError),
+    InvalidUrl(url::ParseError),
+    MissingCredentials(AuthMethod),
+    MissingApplicationId,
+    MissingSession,
+    InvalidDownloadUrl(String),
+    /// A download without a range was answered with only part of the file, holds the URL
+    PartialDownload(String),
+    RequestFormError(serde_urlencoded::ser::Error),
+    /// Form authentication needs a form body to carry the API key and secret, they are never sent in the URL
+    MissingFormBody,
+    /// A report was still processing after the last poll, holds the request ID
+    ReportNotFinished(String),
+    /// Writing a download failed
+    Io(std::io::Error),
+}
+
+/// The encoded body of a request
+#[derive(Debug)]
+pub(crate) enum RequestBody {
+    #[cfg_attr(
+        not(any(
+            feature = "account",
+            feature = "applications",
+            feature = "conversation",
+            feature = "reports",
+            feature = "subaccounts",
+            feature = "video",
+            feature = "voice"
+        )),
+        allow(dead_code)
+    )]
+    Json(String),
+    #[cfg_attr(
+        not(any(
+            feature = "account",
+            feature = "numbers",
+            feature = "sms",
+            feature = "video"
+        )),
+        allow(dead_code)
+    )]
+    Form(String),
+}
+
+impl RequestBody {
+    /// Serialize a request body as JSON
+    #[cfg(any(
+        feature = "account",
+        feature = "applications",
+        feature = "conversation",
+        feature = "reports",
+        feature = "subaccounts",
+        feature = "video",
+        feature = "voice"
+    ))]
+    fn json<B: serde::Serialize>(body: B) -> Result<Self, serde_json::Error> {
+        serde_json::to_string(&body)
+            .map(RequestBody::Json)
+            .inspect_err(|err| log::error!("Error serializing request body: {}", err))
+    }
+
+    /// Serialize a request body as a URL encoded form, used by the legacy APIs
+    #[cfg(any(
+        feature = "account",
+        feature = "numbers",
+        feature = "sms",
+        feature = "video"
+    ))]
+    fn form<B: serde::Serialize>(body: B) -> Result<Self, serde_urlencoded::ser::Error> {
+        serde_urlencoded::to_string(&body)
+            .map(RequestBody::Form)
+            .inspect_err(|err| log::error!("Error serializing request form: {}", err))
+    }
 }
 impl VonageClient {
     pub fn builder() -> VonageClientBuilder {
         VonageClientBuilder::new()
     }
 
+    /// Create a copy of this client that sends Voice API requests to the `region_url` of a call
+    /// Mid-call operations should be sent to the region reported in the answer webhook
+    /// Only HTTPS URLs on a Vonage host are accepted, as the application token is sent to them
+    /// @param region_url The region URL from the answer webhook
+    /// @return A client routing Voice API requests to the region URL
+    pub fn with_region_url(&self, region_url: &str) -> Result<VonageClient, RegionUrlError> {
+        let region_url = Url::parse(region_url).map_err(|e| {
+            log::error!("Error parsing region URL: {}", e);
+            RegionUrlError::InvalidUrl(e)
+        })?;
+        if !is_vonage_url(&region_url) {
+            log::error!("Not a Vonage region URL: {}", region_url);
+            return Err(RegionUrlError::UntrustedHost(region_url.to_string()));
+        }
+        log::debug!("Routing Voice API requests to {}", region_url);
+        let mut client = self.clone();
+        client.hosts = client.hosts.with_override(ApiFamily::Voice, region_url);
+        Ok(client)
+    }
+
+    /// Create a copy of this client that authenticates with another API key and secret
+    /// This is used to act as a subaccount, application tokens are unchanged
+    /// @param api_key The API key, e.g. of a subaccount
+    /// @param api_secret The secret of the API key
+    /// @return A client using the API key and secret
+    pub fn with_api_credentials(&self, api_key: String, api_secret: ApiSecret) -> VonageClient {
+        log::debug!("Using API key {}", api_key);
+        let mut client = self.clone();
+        client.api_credentials = Some((api_key, api_secret));
+        client
+    }
+
+    /// The Vonage Application ID the client was built with, if any
+    pub fn application_id(&self) -> Option<&str> {
+        self.app_id.as_deref()
+    }
+
     /// Refresh the token if it is expired
-    /// This will generate a new JWT token and update the Authorization header
-    fn refresh_token(&mut self) -> Result<(), VonageClientError> {
+    /// This will fetch a new token from the token provider, a token is always fetched before the first request
+    async fn refresh_token(&mut self) -> Result<(), VonageClientError> {
         let now = now_timestamp();
-        let build_token = &self.token_gen;
-        // Refresh the token if it is 5 seconds before expiry and a refresh time is set otherwise allow the token to expire
-        if let Some(token_refresh) = self.token_refresh {
-            if now + 5 >= self.token_expiry - token_refresh {
-                let (token, exp) = build_token.generate_token().map_err(|e| {
-                    log::error!("Error generating token: {}", e);
-                    VonageClientError::TokenRefreshError(e)
-                })?;
-                self.client = build_http_client(token.clone()).map_err(|e| {
-                    log::error!("Error building reqwest client: {}", e);
-                    VonageClientError::TokenRefreshClientError(e)
-                })?;
-                self.token_expiry = exp;
-            }
+        // Refresh the token 5 seconds before the refresh time ahead of the expiry the provider returned
+        let token_refresh = self.token_refresh.unwrap_or(DEFAULT_TOKEN_REFRESH);
+        let needs_refresh = match &self.token {
+            None => true,
+            Some(_) => now + 5 >= self.token_expiry.saturating_sub(token_refresh),
+        };
+        if needs_refresh {
+            let token_provider = self.token_provider.as_ref().ok_or_else(|| {
+                log::error!("No application credentials or token provider configured");
+                VonageClientError::MissingCredentials(AuthMethod::Bearer)
+            })?;
+            let (token, exp) = token_provider.token().await.map_err(|e| {
+                log::error!("Error fetching token: {}", e);
+                VonageClientError::TokenRefreshError(e)
+            })?;
+            self.token = Some(token);
+            self.token_expiry = exp;
         }
         Ok(())
     }
 
+    /// The bearer token for the current request, refreshed if required
+    async fn bearer_token(&mut self) -> Result<String, VonageClientError> {
+        self.refresh_token().await?;
+        Ok(self
+            .token
+            .clone()
+            .expect("token is set by refresh_token")
+            .unwrap())
+    }
+
+    /// Sign a set of claims with the token provider, used for tokens handed to client SDKs
+    /// @param claims The claims to sign
+    /// @return The signed token
+    #[cfg(feature = "video")]
+    pub(crate) async fn sign_claims(&self, claims: &VonageJwt) -> Result<Token, VonageClientError> {
+        let token_provider = self.token_provider.as_ref().ok_or_else(|| {
+            log::error!("No application credentials or token provider configured");
+            VonageClientError::MissingCredentials(AuthMethod::Bearer)
+        })?;
+        token_provider.sign(claims).await.map_err(|e| {
+            log::error!("Error signing claims: {}", e);
+            VonageClientError::TokenRefreshError(e)
+        })
+    }
+
+    /// Send Request
+    /// This function authenticates a request and sends it through the middleware chain
+    /// @param method The HTTP method
+    /// @param path The path to make the request to
+    /// @param body The body of the request, if any
+    /// @return The response from the Vonage API if the status code is in the 200 range
+    #[inline]
+    pub(crate) async fn send(
+        &mut self,
+        method: Method,
+        path: ApiPath,
+        body: Option<RequestBody>,
+    ) -> Result<Response, VonageClientError> {
+        self.send_with_headers(method, path, body, HeaderMap::new())
+            .await
+    }
+
+    /// Send a request with extra headers, such as `Range`
+    pub(crate) async fn send_with_headers(
+        &mut self,
+        method: Method,
+        path: ApiPath,
+        mut body: Option<RequestBody>,
+        headers: HeaderMap,
+    ) -> Result<Response, VonageClientError> {
+        log::trace!("Making {} request to {}", method, path.template());
+        if let Some(e) = path.query_error() {
+            return Err(VonageClientError::RequestFormError(e.clone()));
+        }
+        let telemetry = RequestTelemetry::start(&method, &path);
+        let mut request = self.client.request(
+            method,
+            self.url(&path).map_err(|e| {
+                log::error!("Error building request URL: {}", e);
+                VonageClientError::InvalidUrl(e)
+            })?,
+        );
+        let missing_credentials = |auth| {
+            log::error!("No API key and secret configured");
+            VonageClientError::MissingCredentials(auth)
+        };
+        request = match path.auth() {
+            AuthMethod::Bearer => {
+                request.bearer_auth(telemetry.instrument(self.bearer_token()).await?)
+            }
+            AuthMethod::Basic => {
+                let (api_key, api_secret) = self
+                    .api_credentials
+                    .clone()
+                    .ok_or_else(|| missing_credentials(AuthMethod::Basic))?;
+                request.basic_auth(api_key, Some(api_secret.unwrap()))
+            }
+            AuthMethod::Form => {
+                let (api_key, api_secret) = self
+                    .api_credentials
+                    .clone()
+                    .ok_or_else(|| missing_credentials(AuthMethod::Form))?;
+                let credentials = [("api_key", api_key), ("api_secret", api_secret.unwrap())];
+                match body.take() {
+                    Some(RequestBody::Form(form)) => {
+                        let credentials = serde_urlencoded::to_string(credentials)
+                            .map_err(VonageClientError::RequestFormError)?;
+                        body = Some(RequestBody::Form(format!("{}&{}", credentials, form)));
+                        request
+                    }
+                    _ => {
+                        log::error!("Form authentication requires a form body");
+                        return Err(VonageClientError::MissingFormBody);
+                    }
+                }
+            }
+        };
+        request = request.header(ACCEPT, "application/json").headers(headers);
+        request = match body {
+            Some(RequestBody::Json(body)) => {
+                request.header(CONTENT_TYPE, "application/json").body(body)
+            }
+            Some(RequestBody::Form(body)) => request
+                .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+                .body(body),
+            None => request,
+        };
+        let request = request.build().map_err(Self::map_client_error)?;
+        let attempts = AtomicUsize::new(0);
+        let result = telemetry
+            .instrument(Next::new(&self.client, &self.middleware, &attempts).run(request))
+            .await;
+        telemetry.finish(&result, attempts.load(Ordering::Relaxed));
+        result.and_then(Self::check_status_code)
+    }
+
+    /// Resolve the full URL of a path
+    #[inline]
+    fn url(&self, path: &ApiPath) -> Result<Url, url::ParseError> {
+        match path.origin_url() {
+            Some(origin) => origin.clone(),
+            None => self.hosts.resolve(path.family()),
+        }
+        .join(&path.path_and_query())
+    }
+
     /// Get Request
     /// This function makes a GET request to the Vonage API
     /// @param path The path to make the request to
     /// @return The response from the Vonage API
     #[inline]
-    pub(crate) async fn get<R>(&mut self, path: &str) -> Result<R, VonageClientError>
+    pub(crate) async fn get<R>(&mut self, path: ApiPath) -> Result<R, VonageClientError>
     where
         R: serde::de::DeserializeOwned + std::fmt::Debug,
     {
-        log::trace!("Making GET request to {}", path);
-        self.refresh_token()?;
         let res = self
-            .client
-            .get(self.base_url.join(path).unwrap())
-            .send()
+            .send(Method::GET, path, None)
             .await
-            .map(Self::trace_response("Get Response".to_string()))
-            .map(Self::check_status_code)
-            .map_err(Self::map_client_error)??;
+            .map(Self::trace_response("Get Response".to_string()))?;
         res.json::<R>()
             .await
             .map_err(Self::map_parse_error)
             .map(Self::trace_response("Get ResponseParsed".to_string()))
     }
 
+    /// Get Bytes Request
+    /// This function makes a GET request to the Vonage API and returns the raw response body, used for file downloads
+    /// @param path The path to make the request to
+    /// @return The body of the response
+    #[inline]
+    #[cfg(feature = "reports")]
+    pub(crate) async fn get_bytes(&mut self, path: ApiPath) -> Result<Vec<u8>, VonageClientError> {
+        let res = self
+            .send(Method::GET, path, None)
+            .await
+            .map(Self::trace_response("Get Response".to_string()))?;
+        res.bytes()
+            .await
+            .map(|bytes| bytes.to_vec())
+            .map_err(Self::map_parse_error)
+    }
+
     /// Post Request
     /// This function makes a POST request to the Vonage API
     /// @param path The path to make the request to
     /// @param body The body of the request
     /// @return The response from the Vonage API
     #[inline]
-    pub(crate) async fn post<B, R>(&mut self, path: &str, body: B) -> Result<R, VonageClientError>
+    #[cfg(any(
+        feature = "account",
+        feature = "applications",
+        feature = "conversation",
+        feature = "reports",
+        feature = "subaccounts",
+        feature = "video",
+        feature = "voice"
+    ))]
+    pub(crate) async fn post<B, R>(
+        &mut self,
+        path: ApiPath,
+        body: B,
+    ) -> Result<R, VonageClientError>
     where
         B: serde::Serialize,
         R: serde::de::DeserializeOwned + std::fmt::Debug,
     {
-        log::trace!("Making POST request to {}", path);
-        self.refresh_token()?;
-        let body = serde_json::to_string(&body).map_err(|err| {
-            log::error!("Error serializing request body: {}", err);
-            VonageClientError::RequestParseError(err)
-        })?;
         let res = self
-            .client
-            .post(self.base_url.join(path).unwrap())
-            .body(body)
-            .send()
+            .send(
+                Method::POST,
+                path,
+                Some(RequestBody::json(body).map_err(VonageClientError::RequestParseError)?),
+            )
             .await
-            .map(Self::trace_response("Post Response".to_string()))
-            .map(Self::check_status_code)
-            .map_err(Self::map_client_error)??;
-
+            .map(Self::trace_response("Post Response".to_string()))?;
         res.json::<R>()
             .await
             .map_err(Self::map_parse_error)
             .map(Self::trace_response("Post ResponseParsed".to_string()))
     }
 
+    /// Post Form Request
+    /// This function makes a POST request with a URL encoded form body to the Vonage API
+    /// @param path The path to make the request to
+    /// @param body The form fields of the request
+    /// @return The response from the Vonage API
+    #[inline]
+    #[cfg(any(
+        feature = "account",
+        feature = "numbers",
+        feature = "sms",
+        feature = "video"
+    ))]
+    pub(crate) async fn post_form<B, R>(
+        &mut self,
+        path: ApiPath,
+        body: B,
+    ) -> Result<R, VonageClientError>
+    where
+        B: serde::Serialize,
+        R: serde::de::DeserializeOwned + std::fmt::Debug,
+    {
+        let res = self
+            .send(
+                Method::POST,
+                path,
+                Some(RequestBody::form(body).map_err(VonageClientError::RequestFormError)?),
+            )
+            .await
+            .map(Self::trace_response("Post Form Response".to_string()))?;
+        res.json::<R>()
+            .await
+            .map_err(Self::map_parse_error)
+            .map(Self::trace_response("Post Form ResponseParsed".to_string()))
+    }
+
+    /// Post Request without a response body
+    /// This function makes a POST request to the Vonage API and discards the response body
+    /// @param path The path to make the request to
+    /// @param body The body of the request
+    #[inline]
+    #[cfg(feature = "video")]
+    pub(crate) async fn post_no_content<B>(
+        &mut self,
+        path: ApiPath,
+        body: B,
+    ) -> Result<(), VonageClientError>
+    where
+        B: serde::Serialize,
+    {
+        let _ = self
+            .send(
+                Method::POST,
+                path,
+                Some(RequestBody::json(body).map_err(VonageClientError::RequestParseError)?),
+            )
+            .await
+            .map(Self::trace_response("Post Response".to_string()))?;
+
+        log::trace!("Post request successful");
+        Ok(())
+    }
+
     /// Put Request
     /// This function makes a PUT request to the Vonage API
     /// @param path The path to make the request to
     /// @param body The body of the request
     /// @return The response from the Vonage API
     #[inline]
-    pub(crate) async fn put<B, R>(&mut self, path: &str, body: B) -> Result<R, VonageClientError>
+    #[cfg(feature = "applications")]
+    pub(crate) async fn put<B, R>(&mut self, path: ApiPath, body: B) -> Result<R, VonageClientError>
     where
         B: serde::Serialize,
         R: serde::de::DeserializeOwned + std::fmt::Debug,
     {
-        log::trace!("Making PUT request to {}", path);
-        self.refresh_token()?;
-        let body = serde_json::to_string(&body).map_err(|err| {
-            log::error!("Error serializing request body: {}", err);
-            VonageClientError::RequestParseError(err)
-        })?;
         let res = self
-            .client
-            .post(self.base_url.join(path).unwrap())
-            .body(body)
-            .send()
+            .send(
+                Method::PUT,
+                path,
+                Some(RequestBody::json(body).map_err(VonageClientError::RequestParseError)?),
+            )
             .await
-            .map(Self::trace_response("Put Response".to_string()))
-            .map(Self::check_status_code)
-            .map_err(Self::map_client_error)??;
+            .map(Self::trace_response("Put Response".to_string()))?;
         res.json::<R>()
             .await
             .map_err(Self::map_parse_error)
@@ -169,26 +506,24 @@ impl VonageClient {
     /// @param body The body of the request
     /// @return The response from the Vonage API
     #[inline]
-    pub(crate) async fn patch<B, R>(&mut self, path: &str, body: B) -> Result<R, VonageClientError>
+    #[cfg(feature = "subaccounts")]
+    pub(crate) async fn patch<B, R>(
+        &mut self,
+        path: ApiPath,
+        body: B,
+    ) -> Result<R, VonageClientError>
     where
         B: serde::Serialize,
         R: serde::de::DeserializeOwned + std::fmt::Debug,
     {
-        log::trace!("Making PATCH request to {}", path);
-        self.refresh_token()?;
-        let body = serde_json::to_string(&body).map_err(|err| {
-            log::error!("Error serializing request body: {}", err);
-            VonageClientError::RequestParseError(err)
-        })?;
         let res = self
-            .client
-            .post(self.base_url.join(path).unwrap())
-            .body(body)
-            .send()
+            .send(
+                Method::PATCH,
+                path,
+                Some(RequestBody::json(body).map_err(VonageClientError::RequestParseError)?),
+            )
             .await
-            .map(Self::trace_response("Patch Response".to_string()))
-            .map(Self::check_status_code)
-            .map_err(Self::map_client_error)??;
+            .map(Self::trace_response("Patch Response".to_string()))?;
         res.json::<R>()
             .await
             .map_err(Self::map_parse_error)
@@ -201,17 +536,18 @@ impl VonageClient {
     /// @return The response from the Vonage API
     /// @note This function does not return a response body
     #[inline]
-    pub(crate) async fn delete(&mut self, path: &str) -> Result<(), VonageClientError> {
-        log::trace!("Making DELETE request to {}", path);
-        self.refresh_token()?;
+    #[cfg(any(
+        feature = "account",
+        feature = "applications",
+        feature = "conversation",
+        feature = "reports",
+        feature = "video"
+    ))]
+    pub(crate) async fn delete(&mut self, path: ApiPath) -> Result<(), VonageClientError> {
         let _ = self
-            .client
-            .delete(self.base_url.join(path).unwrap())
-            .send()
+            .send(Method::DELETE, path, None)
             .await
-            .map(Self::trace_response("Delete Response".to_string()))
-            .map(Self::check_status_code)
-            .map_err(Self::map_client_error)?;
+            .map(Self::trace_response("Delete Response".to_string()))?;
 
         log::trace!("Delete request successful");
         Ok(())
@@ -223,7 +559,11 @@ impl VonageClient {
         match res.status() {
             code if code.as_u16() >= 200 && code.as_u16() < 300 => Ok(res),
             code => {
-                log::error!("Error making request: {:?}", res);
+                log::error!(
+                    "Error making request to {}: {}",
+                    client_types::redact_url(res.url()),
+                    code
+                );
                 Err(VonageClientError::RequestError(code, res))
             }
         }
@@ -232,19 +572,41 @@ impl VonageClient {
     /// Map a reqwest error to a VonageClientError
     #[inline]
     pub(crate) fn map_client_error(e: reqwest::Error) -> VonageClientError {
-        log::error!("Error making request: {}", e);
+        log::error!("Error making request: {}", Self::redacted_error(&e));
         VonageClientError::HttpClientError(e)
     }
 
     /// Map a reqwest error to a VonageClientError
     #[inline]
     pub(crate) fn map_parse_error(e: reqwest::Error) -> VonageClientError {
-        log::error!("Error parsing response: {}", e);
+        log::error!("Error parsing response: {}", Self::redacted_error(&e));
         VonageClientError::ResponseParseError(e)
     }
 
+    /// Describe a reqwest error for logging without the query of its URL
+    fn redacted_error(e: &reqwest::Error) -> String {
+        match e.url() {
+            Some(url) => e
+                .to_string()
+                .replace(url.as_str(), &client_types::redact_url(url)),
+            None => e.to_string(),
+        }
+    }
+
     /// A curried function to log the response of a request with a message at debug level
     #[inline]
+    #[cfg(any(
+        feature = "account",
+        feature = "applications",
+        feature = "conversation",
+        feature = "number_insight",
+        feature = "numbers",
+        feature = "pricing",
+        feature = "reports",
+        feature = "sms",
+        feature = "subaccounts",
+        feature = "video"
+    ))]
     pub(crate) fn debug_response<T>(msg: String) -> impl FnOnce(T) -> T
     where
         T: std::fmt::Debug,
@@ -267,3 +629,185 @@ impl VonageClient {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use async_trait::async_trait;
+    use reqwest::Request;
+    use std::sync::Mutex;
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    /// Records the URL of each request and answers without touching the network
+    #[derive(Debug, Default)]
+    struct RecordUrls(Arc<Mutex<Vec<String>>>);
+
+    #[async_trait]
+    impl Middleware for RecordUrls {
+        async fn handle(
+            &self,
+            req: Request,
+            _next: Next<'_>,
+        ) -> Result<Response, VonageClientError> {
+            self.0.lock().unwrap().push(req.url().to_string());
+            Ok(http::Response::builder()
+                .status(200)
+                .body("{}")
+                .unwrap()
+                .into())
+        }
+    }
+
+    #[tokio::test]
+    async fn region_url_routes_voice_requests() {
+        init();
+        let urls = Arc::new(Mutex::new(Vec::new()));
+        let client = VonageClient::builder()
+            .token_provider(StaticTokenProvider::new("token".into(), usize::MAX))
+            .region(VonageRegion::EU)
+            .middleware(RecordUrls(urls.clone()))
+            .build()
+            .unwrap();
+        let mut call_client = client
+            .with_region_url("https://api-us-4.vonage.com")
+            .unwrap();
+
+        let _: serde_json::Value = call_client
+            .get(ApiPath::new(ApiFamily::Voice, "/v1/calls"))
+            .await
+            .unwrap();
+        let _: serde_json::Value = call_client
+            .get(ApiPath::new(ApiFamily::Conversation, "/v1/users"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *urls.lock().unwrap(),
+            vec![
+                "https://api-us-4.vonage.com/v1/calls",
+                "https://api-eu.vonage.com/v1/users"
+            ]
+        );
+        assert!(matches!(
+            client.with_region_url("not a url"),
+            Err(RegionUrlError::InvalidUrl(_))
+        ));
+        for region_url in [
+            "https://api.nexmo.com.example.com",
+            "http://api-us-4.vonage.com",
+        ] {
+            assert!(matches!(
+                client.with_region_url(region_url),
+                Err(RegionUrlError::UntrustedHost(_))
+            ));
+        }
+    }
+
+    #[cfg(feature = "sms")]
+    #[tokio::test]
+    async fn form_auth_requires_form_body() {
+        init();
+        let urls = Arc::new(Mutex::new(Vec::new()));
+        let mut client = VonageClient::builder()
+            .api_key("key".into())
+            .api_secret("secret".into())
+            .middleware(RecordUrls(urls.clone()))
+            .build()
+            .unwrap();
+
+        let result: Result<serde_json::Value, _> = client
+            .get(ApiPath::new(ApiFamily::Rest, "/sms/json").form_auth())
+            .await;
+
+        assert!(matches!(result, Err(VonageClientError::MissingFormBody)));
+        assert!(urls.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn query_error_is_returned() {
+        init();
+        let urls = Arc::new(Mutex::new(Vec::new()));
+        let mut client = VonageClient::builder()
+            .token_provider(StaticTokenProvider::new("token".into(), usize::MAX))
+            .middleware(RecordUrls(urls.clone()))
+            .build()
+            .unwrap();
+
+        let result: Result<serde_json::Value, _> = client
+            .get(ApiPath::new(ApiFamily::Voice, "/v1/calls").query(&[("status", vec!["started"])]))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(VonageClientError::RequestFormError(_))
+        ));
+        assert!(urls.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn refresh_expired_tokens() {
+        init();
+        let decode = |authorization: &str, public_key: &str| {
+            let mut validation = jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::RS256);
+            validation.validate_exp = false;
+            jsonwebtoken::decode::<serde_json::Value>(
+                authorization.strip_prefix("Bearer ").unwrap(),
+                &jsonwebtoken::DecodingKey::from_rsa_pem(public_key.as_bytes()).unwrap(),
+                &validation,
+            )
+            .map(|token| token.claims)
+        };
+        let public_key = include_str!("test_data/public_key.pem");
+        let rotated_public_key = include_str!("test_data/rotated_public_key.pem");
+        jwt::set_mock_time(1000);
+        let provider = Arc::new(RotatingKeyTokenProvider::new(
+            "app-id".into(),
+            vec![include_str!("test_data/private_key.pem").into()],
+            Some(600),
+        ));
+        let server = mock::MockServer::default()
+            .respond(200, "{}")
+            .respond(200, "{}")
+            .respond(200, "{}");
+        let mut client = VonageClient::builder()
+            .token_provider(provider.clone())
+            .middleware(server.clone())
+            .build()
+            .unwrap();
+        let path = || ApiPath::new(ApiFamily::Voice, "/v1/calls");
+
+        let _: serde_json::Value = client.get(path()).await.unwrap();
+        jwt::set_mock_time(1500);
+        let _: serde_json::Value = client.get(path()).await.unwrap();
+        // The key is rotated and the token reaches its refresh time
+        provider.set_keys(vec![
+            include_str!("test_data/rotated_private_key.pem").into()
+        ]);
+        jwt::set_mock_time(1540);
+        let _: serde_json::Value = client.get(path()).await.unwrap();
+
+        let authorizations: Vec<String> = server
+            .requests()
+            .iter()
+            .map(|request| {
+                request.headers["authorization"]
+                    .to_str()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(authorizations[0], authorizations[1]);
+        assert_eq!(decode(&authorizations[0], public_key).unwrap()["exp"], 1600);
+        assert!(decode(&authorizations[2], public_key).is_err());
+        assert_eq!(
+            decode(&authorizations[2], rotated_public_key).unwrap()["exp"],
+            2140
+        );
+    }
+}
@@ -0,0 +1,362 @@
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use serde::Serialize;
+
+use super::path::{ApiPath, AuthMethod};
+use super::{ApiFamily, VonageClient, VonageClientError};
+use crate::types::pages::{Page, PageOptions, PageStyle};
+
+/// The state carried between page requests
+struct PageState {
+    client: VonageClient,
+    base: ApiPath,
+    next: Option<ApiPath>,
+    index: usize,
+    fetched: usize,
+}
+
+impl VonageClient {
+    /// Paginate Endpoint
+    /// This function walks a list endpoint that has no method of its own, the `Page` type describes its pages
+    /// @param family The API family, used to pick the host
+    /// @param template The path template, such as `/v1/conversations/{conversation_id}/events`, it names the requests in telemetry
+    /// @param params The values of the `{name}` placeholders of the template
+    /// @param query The query parameters of every page, such as filters
+    /// @param auth How the requests are authenticated
+    /// @param options The page size and the maximum number of records to yield
+    /// @return A stream of records, ending at the last page or once `max_items` records have been yielded
+    ///
+    /// ## Example
+    /// ```rust,no_run
+    /// use futures::TryStreamExt;
+    /// use serde::Deserialize;
+    /// use serde_json::Value;
+    /// use vonage_client::pages::{Page, PageMeta, PageOptions, PageStyle};
+    /// use vonage_client::{ApiFamily, AuthMethod, VonageClient};
+    ///
+    /// #[derive(Debug, Deserialize)]
+    /// struct EventList {
+    ///     events: Vec<Value>,
+    /// }
+    ///
+    /// #[derive(Debug, Deserialize)]
+    /// struct EventPage {
+    ///     #[serde(flatten)]
+    ///     meta: PageMeta,
+    ///     #[serde(rename = "_embedded")]
+    ///     embedded: EventList,
+    /// }
+    ///
+    /// impl Page for EventPage {
+    ///     type Item = Value;
+    ///     const STYLE: PageStyle = PageStyle::Cursor;
+    ///
+    ///     fn next_href(&self) -> Option<&str> {
+    ///         self.meta.links().next().map(|link| link.href())
+    ///     }
+    ///
+    ///     fn into_items(self) -> Vec<Value> {
+    ///         self.embedded.events
+    ///     }
+    /// }
+    ///
+    /// async fn list_events(client: &VonageClient) {
+    ///     let events: Vec<Value> = client
+    ///         .paginate_endpoint::<EventPage, _>(
+    ///             ApiFamily::Conversation,
+    ///             "/v1/conversations/{conversation_id}/events",
+    ///             &[("conversation_id", "CON-1234")],
+    ///             &[("event_type", "text")],
+    ///             AuthMethod::Bearer,
+    ///             PageOptions::new().page_size(100),
+    ///         )
+    ///         .try_collect()
+    ///         .await
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn paginate_endpoint<P, Q>(
+        &self,
+        family: ApiFamily,
+        template: &'static str,
+        params: &[(&str, &str)],
+        query: &Q,
+        auth: AuthMethod,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<P::Item, VonageClientError>> + Send + 'static
+    where
+        P: Page,
+        Q: Serialize + ?Sized,
+    {
+        log::debug!("Listing {}", template);
+        let path = params
+            .iter()
+            .fold(ApiPath::new(family, template), |path, (name, value)| {
+                path.param(name, value)
+            })
+            .query(query)
+            .with_auth(auth);
+        self.paginate::<P>(path, options)
+    }
+
+    /// Paginate
+    /// This function walks a list endpoint page by page and yields each record
+    /// @param path The path of the first page
+    /// @param options The page size and the maximum number of records to yield
+    /// @return A stream of records, ending at the last page or once `max_items` records have been yielded
+    pub(crate) fn paginate<P>(
+        &self,
+        path: ApiPath,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<P::Item, VonageClientError>> + Send + 'static
+    where
+        P: Page,
+    {
+        let base = match options.get_page_size() {
+            Some(page_size) => path.query(&[(P::PAGE_SIZE_PARAM, page_size)]),
+            None => path,
+        };
+        let first = match P::STYLE {
+            PageStyle::Cursor => base.clone(),
+            PageStyle::RecordIndex => base.clone().query(&[(P::PAGE_INDEX_PARAM, 1)]),
+        };
+        let state = PageState {
+            client: self.clone(),
+            base,
+            next: Some(first),
+            index: 1,
+            fetched: 0,
+        };
+
+        stream::try_unfold(state, |mut state| async move {
+            let Some(path) = state.next.take() else {
+                return Ok(None);
+            };
+            log::debug!("Fetching page {} of {}", state.index, path.template());
+            let page: P = state.client.get(path.clone()).await?;
+            state.next = match P::STYLE {
+                PageStyle::Cursor => page
+                    .next_href()
+                    .and_then(|href| href.split_once('?'))
+                    .map(|(_, query)| path.with_raw_query(Some(query))),
+                PageStyle::RecordIndex => None,
+            };
+            let total = page.total();
+            let items = page.into_items();
+            state.index += 1;
+            state.fetched += items.len();
+            if P::STYLE == PageStyle::RecordIndex
+                && !items.is_empty()
+                && total.is_some_and(|total| state.fetched < total)
+            {
+                state.next = Some(
+                    state
+                        .base
+                        .clone()
+                        .query(&[(P::PAGE_INDEX_PARAM, state.index)]),
+                );
+            }
+            Ok(Some((stream::iter(items.into_iter().map(Ok)), state)))
+        })
+        .try_flatten()
+        .take(options.get_max_items().unwrap_or(usize::MAX))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use crate::client::mock::MockServer;
+    use serde::Deserialize;
+    use serde_json::Value;
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct NumberPage {
+        count: usize,
+        numbers: Vec<String>,
+    }
+
+    impl Page for NumberPage {
+        type Item = String;
+        const STYLE: PageStyle = PageStyle::RecordIndex;
+        const PAGE_SIZE_PARAM: &'static str = "size";
+
+        fn total(&self) -> Option<usize> {
+            Some(self.count)
+        }
+
+        fn into_items(self) -> Vec<String> {
+            self.numbers
+        }
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct CallList {
+        calls: Vec<Value>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct CallPage {
+        #[serde(flatten)]
+        meta: crate::types::pages::PageMeta,
+        #[serde(rename = "_embedded")]
+        embedded: CallList,
+    }
+
+    impl Page for CallPage {
+        type Item = Value;
+        const STYLE: PageStyle = PageStyle::Cursor;
+
+        fn next_href(&self) -> Option<&str> {
+            self.meta.links().next().map(|link| link.href())
+        }
+
+        fn into_items(self) -> Vec<Value> {
+            self.embedded.calls
+        }
+    }
+
+    fn paths(server: &MockServer) -> Vec<String> {
+        server
+            .requests()
+            .iter()
+            .map(|req| req.path_and_query())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn paginate_cursor() {
+        init();
+        use crate::api::{ConversationApi, User};
+        let server = MockServer::default()
+            .respond(200, r#"{"page_size":2,"_embedded":{"users":[{"name":"a"},{"name":"b"}]},"_links":{"next":{"href":"https://api-us.vonage.com/v1/users?page_size=2&cursor=abc"}}}"#)
+            .respond(200, r#"{"page_size":2,"_embedded":{"users":[{"name":"c"}]},"_links":{}}"#);
+
+        let users: Vec<User> = server
+            .client()
+            .list_users(PageOptions::new().page_size(2))
+            .try_collect()
+            .await
+            .unwrap();
+
+        let names: Vec<_> = users.iter().map(|u| u.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "b", "c"]);
+        assert_eq!(
+            paths(&server),
+            vec!["/v1/users?page_size=2", "/v1/users?page_size=2&cursor=abc"]
+        );
+    }
+
+    #[tokio::test]
+    async fn paginate_record_index_with_max_items() {
+        init();
+        let server = MockServer::default()
+            .respond(200, r#"{"count":5,"numbers":["1","2"]}"#)
+            .respond(200, r#"{"count":5,"numbers":["3","4"]}"#)
+            .respond(200, r#"{"count":5,"numbers":["5"]}"#);
+        let path = ApiPath::new(ApiFamily::Rest, "/account/numbers");
+
+        let numbers: Vec<String> = server
+            .client()
+            .paginate::<NumberPage>(path.clone(), PageOptions::new().page_size(2))
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(numbers, vec!["1", "2", "3", "4", "5"]);
+        assert_eq!(
+            paths(&server),
+            vec![
+                "/account/numbers?size=2&index=1",
+                "/account/numbers?size=2&index=2",
+                "/account/numbers?size=2&index=3"
+            ]
+        );
+
+        let server = MockServer::default()
+            .respond(200, r#"{"count":5,"numbers":["1","2"]}"#)
+            .respond(200, r#"{"count":5,"numbers":["3","4"]}"#);
+        let numbers: Vec<String> = server
+            .client()
+            .paginate::<NumberPage>(path, PageOptions::new().page_size(2).max_items(3))
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(numbers, vec!["1", "2", "3"]);
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn paginate_endpoint_with_query() {
+        init();
+        let server = MockServer::default()
+            .respond(200, r#"{"page_size":1,"_embedded":{"calls":[{"uuid":"a"}]},"_links":{"next":{"href":"https://api-us.vonage.com/v1/calls?page_size=1&status=completed&record_index=1"}}}"#)
+            .respond(200, r#"{"page_size":1,"_embedded":{"calls":[{"uuid":"b"}]},"_links":{}}"#);
+
+        let calls: Vec<Value> = server
+            .client()
+            .paginate_endpoint::<CallPage, _>(
+                ApiFamily::Voice,
+                "/v1/calls",
+                &[],
+                &[("status", "completed")],
+                AuthMethod::Bearer,
+                PageOptions::new().page_size(1),
+            )
+            .try_collect()
+            .await
+            .unwrap();
+        let uuids: Vec<_> = calls.iter().map(|call| call["uuid"].as_str()).collect();
+        assert_eq!(uuids, vec![Some("a"), Some("b")]);
+        assert_eq!(
+            paths(&server),
+            vec![
+                "/v1/calls?status=completed&page_size=1",
+                "/v1/calls?page_size=1&status=completed&record_index=1"
+            ]
+        );
+        assert!(server.requests()[0]
+            .headers
+            .get("authorization")
+            .is_some_and(|auth| auth.to_str().unwrap().starts_with("Bearer ")));
+    }
+
+    #[tokio::test]
+    async fn paginate_endpoint_with_params_and_basic_auth() {
+        init();
+        let server = MockServer::default()
+            .respond(200, r#"{"count":3,"numbers":["1","2"]}"#)
+            .respond(200, r#"{"count":3,"numbers":["3"]}"#);
+
+        let numbers: Vec<String> = server
+            .client()
+            .paginate_endpoint::<NumberPage, _>(
+                ApiFamily::Rest,
+                "/accounts/{api_key}/numbers",
+                &[("api_key", "key/1")],
+                &(),
+                AuthMethod::Basic,
+                PageOptions::new().page_size(2),
+            )
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(numbers, vec!["1", "2", "3"]);
+        assert_eq!(
+            paths(&server),
+            vec![
+                "/accounts/key%2F1/numbers?size=2&index=1",
+                "/accounts/key%2F1/numbers?size=2&index=2"
+            ]
+        );
+        assert!(server.requests()[0]
+            .headers
+            .get("authorization")
+            .is_some_and(|auth| auth.to_str().unwrap().starts_with("Basic ")));
+    }
+}
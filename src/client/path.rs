@@ -0,0 +1,205 @@
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use url::Url;
+
+use super::hosts::ApiFamily;
+
+/// Characters escaped in a path segment, everything but the RFC 3986 unreserved characters
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// Auth Method
+/// How a request is authenticated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AuthMethod {
+    /// A JWT signed for the application, used by the Voice, Conversation and newer APIs
+    #[default]
+    Bearer,
+    /// The account API key and secret, used by the legacy APIs
+    Basic,
+    /// The account API key and secret sent as form fields, used by the SMS API
+    Form,
+}
+
+/// API Path
+/// The path of an API request along with the template it was rendered from,
+/// the template is used to name telemetry without leaking identifiers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ApiPath {
+    family: ApiFamily,
+    template: &'static str,
+    path: String,
+    query: Option<String>,
+    /// The query failed to serialize, the request is not sent
+    query_error: Option<serde_urlencoded::ser::Error>,
+    auth: AuthMethod,
+    origin: Option<Url>,
+}
+
+impl ApiPath {
+    /// Create a new ApiPath from a template such as `/v1/calls/{uuid}`
+    /// @param family The API family, used to pick the host
+    /// @param template The path template
+    pub(crate) fn new(family: ApiFamily, template: &'static str) -> Self {
+        ApiPath {
+            family,
+            template,
+            path: template.to_string(),
+            query: None,
+            query_error: None,
+            auth: AuthMethod::Bearer,
+            origin: None,
+        }
+    }
+
+    /// Fill a `{name}` placeholder of the template with an escaped value
+    /// @param name The name of the placeholder
+    /// @param value The value, escaped so it stays a single path segment
+    pub(crate) fn param(mut self, name: &str, value: &str) -> Self {
+        self.path = self.path.replace(
+            &format!("{{{}}}", name),
+            &utf8_percent_encode(value, PATH_SEGMENT).to_string(),
+        );
+        self
+    }
+
+    /// Append query parameters from a serializable value
+    /// A serialization error is kept and returned when the request is sent
+    pub(crate) fn query<Q: serde::Serialize + ?Sized>(mut self, query: &Q) -> Self {
+        match serde_urlencoded::to_string(query) {
+            Ok(query) => self.raw_query(&query),
+            Err(e) => {
+                log::error!("Error serializing query for {}: {}", self.template, e);
+                self.query_error.get_or_insert(e);
+                self
+            }
+        }
+    }
+
+    /// Append an already encoded query string
+    pub(crate) fn raw_query(mut self, query: &str) -> Self {
+        if query.is_empty() {
+            return self;
+        }
+        self.query = Some(match self.query.take() {
+            Some(existing) => format!("{}&{}", existing, query),
+            None => query.to_string(),
+        });
+        self
+    }
+
+    /// Replace the query string
+    pub(crate) fn with_raw_query(mut self, query: Option<&str>) -> Self {
+        self.query = None;
+        self.raw_query(query.unwrap_or_default())
+    }
+
+    /// Authenticate the request with the given method
+    pub(crate) fn with_auth(mut self, auth: AuthMethod) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Authenticate the request with the API key and secret rather than a JWT
+    #[cfg(any(
+        feature = "account",
+        feature = "applications",
+        feature = "number_insight",
+        feature = "numbers",
+        feature = "pricing",
+        feature = "reports",
+        feature = "subaccounts"
+    ))]
+    pub(crate) fn basic_auth(mut self) -> Self {
+        self.auth = AuthMethod::Basic;
+        self
+    }
+
+    /// Authenticate the request with the API key and secret sent as form fields
+    #[cfg(feature = "sms")]
+    pub(crate) fn form_auth(mut self) -> Self {
+        self.auth = AuthMethod::Form;
+        self
+    }
+
+    /// Send the request to a host given by the API, such as the regional host of a recording URL,
+    /// rather than the host of the API family
+    #[cfg(feature = "voice")]
+    pub(crate) fn origin(mut self, origin: Url) -> Self {
+        self.origin = Some(origin);
+        self
+    }
+
+    pub(crate) fn origin_url(&self) -> Option<&Url> {
+        self.origin.as_ref()
+    }
+
+    pub(crate) fn auth(&self) -> AuthMethod {
+        self.auth
+    }
+
+    pub(crate) fn family(&self) -> ApiFamily {
+        self.family
+    }
+
+    pub(crate) fn query_error(&self) -> Option<&serde_urlencoded::ser::Error> {
+        self.query_error.as_ref()
+    }
+
+    pub(crate) fn template(&self) -> &'static str {
+        self.template
+    }
+
+    /// The rendered path and query, relative to the API host
+    pub(crate) fn path_and_query(&self) -> String {
+        match &self.query {
+            Some(query) => format!("{}?{}", self.path, query),
+            None => self.path.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    #[test]
+    fn api_path_query() {
+        #[derive(Serialize)]
+        struct Query {
+            page_size: usize,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            cursor: Option<String>,
+        }
+        let path = ApiPath::new(ApiFamily::Conversation, "/v1/users")
+            .query(&Query {
+                page_size: 10,
+                cursor: None,
+            })
+            .raw_query("order=asc");
+        assert_eq!(path.path_and_query(), "/v1/users?page_size=10&order=asc");
+
+        let path = path.with_raw_query(Some("cursor=abc"));
+        assert_eq!(path.path_and_query(), "/v1/users?cursor=abc");
+        assert_eq!(path.template(), "/v1/users");
+    }
+
+    #[test]
+    fn api_path_query_error() {
+        let path = ApiPath::new(ApiFamily::Api, "/v2/applications")
+            .query(&[("ids", vec![1, 2])])
+            .raw_query("page=1");
+        assert!(path.query_error().is_some());
+        assert_eq!(path.path_and_query(), "/v2/applications?page=1");
+    }
+
+    #[test]
+    fn api_path_param() {
+        let path = ApiPath::new(ApiFamily::Api, "/v2/applications/{id}").param("id", "a/b c");
+        assert_eq!(path.path_and_query(), "/v2/applications/a%2Fb%20c");
+        assert_eq!(path.template(), "/v2/applications/{id}");
+    }
+}
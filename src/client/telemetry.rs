@@ -0,0 +1,277 @@
+use std::future::Future;
+use std::time::Instant;
+
+use reqwest::{Method, Response};
+
+use super::path::ApiPath;
+use super::VonageClientError;
+
+/// The header Vonage uses to identify a request in support tickets
+const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Request Telemetry
+/// Times a single API operation and reports it to `log`, and to `tracing` when the feature is enabled
+///
+/// Only the path template is recorded, never the rendered path, so identifiers and numbers stay out of telemetry.
+pub(crate) struct RequestTelemetry {
+    method: Method,
+    template: &'static str,
+    started: Instant,
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+}
+
+impl RequestTelemetry {
+    /// Start timing an operation
+    pub(crate) fn start(method: &Method, path: &ApiPath) -> Self {
+        RequestTelemetry {
+            method: method.clone(),
+            template: path.template(),
+            started: Instant::now(),
+            #[cfg(feature = "tracing")]
+            span: tracing::info_span!(
+                "vonage.request",
+                method = %method,
+                path = path.template(),
+                status = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                request_id = tracing::field::Empty,
+                retries = tracing::field::Empty,
+            ),
+        }
+    }
+
+    /// Run a future inside the operation span
+    #[cfg(feature = "tracing")]
+    pub(crate) fn instrument<F: Future>(&self, future: F) -> impl Future<Output = F::Output> {
+        tracing::Instrument::instrument(future, self.span.clone())
+    }
+
+    /// Run a future inside the operation span
+    #[cfg(not(feature = "tracing"))]
+    pub(crate) fn instrument<F: Future>(&self, future: F) -> impl Future<Output = F::Output> {
+        future
+    }
+
+    /// Record the outcome of the operation
+    /// @param result The result of the middleware chain
+    /// @param attempts The number of times the request reached the HTTP client
+    pub(crate) fn finish(&self, result: &Result<Response, VonageClientError>, attempts: usize) {
+        let latency = self.started.elapsed();
+        let retries = attempts.saturating_sub(1);
+        let (status, request_id) = match result {
+            Ok(res) | Err(VonageClientError::RequestError(_, res)) => (
+                Some(res.status().as_u16()),
+                res.headers()
+                    .get(REQUEST_ID_HEADER)
+                    .and_then(|v| v.to_str().ok()),
+            ),
+            Err(_) => (None, None),
+        };
+        log::debug!(
+            "{} {} -> {:?} in {}ms, request id: {:?}, retries: {}",
+            self.method,
+            self.template,
+            status,
+            latency.as_millis(),
+            request_id,
+            retries
+        );
+        #[cfg(feature = "tracing")]
+        {
+            if let Some(status) = status {
+                self.span.record("status", status);
+            }
+            if let Some(request_id) = request_id {
+                self.span.record("request_id", request_id);
+            }
+            self.span.record("latency_ms", latency.as_millis() as u64);
+            self.span.record("retries", retries as u64);
+            if result.is_err() {
+                self.span
+                    .in_scope(|| tracing::warn!(status, "Vonage API request failed"));
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use crate::client::mock::{LocalServer, MockServer};
+    use crate::{ApiFamily, Middleware, Next, StaticTokenProvider, VonageClient};
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    type Fields = HashMap<String, String>;
+
+    /// Captures the fields of every span and event
+    #[derive(Debug, Clone, Default)]
+    struct Capture {
+        next_id: Arc<AtomicU64>,
+        spans: Arc<Mutex<HashMap<u64, (&'static str, Fields)>>>,
+        events: Arc<Mutex<Vec<Fields>>>,
+    }
+
+    impl Capture {
+        /// The fields of the spans with a name, in the order they were created
+        fn spans(&self, name: &str) -> Vec<Fields> {
+            let spans = self.spans.lock().unwrap();
+            let mut ids: Vec<_> = spans
+                .iter()
+                .filter(|(_, (span, _))| *span == name)
+                .map(|(id, _)| *id)
+                .collect();
+            ids.sort();
+            ids.iter().map(|id| spans[id].1.clone()).collect()
+        }
+    }
+
+    struct Visitor<'a>(&'a mut Fields);
+
+    impl Visit for Visitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{:?}", value));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+    }
+
+    impl Subscriber for Capture {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+            let mut fields = Fields::new();
+            span.record(&mut Visitor(&mut fields));
+            self.spans
+                .lock()
+                .unwrap()
+                .insert(id, (span.metadata().name(), fields));
+            Id::from_u64(id)
+        }
+
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            if let Some((_, fields)) = self.spans.lock().unwrap().get_mut(&span.into_u64()) {
+                values.record(&mut Visitor(fields));
+            }
+        }
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = Fields::new();
+            event.record(&mut Visitor(&mut fields));
+            self.events.lock().unwrap().push(fields);
+        }
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    /// Sends the request again once if it fails with a server error
+    #[derive(Debug)]
+    struct RetryOnce;
+
+    #[async_trait::async_trait]
+    impl Middleware for RetryOnce {
+        async fn handle(
+            &self,
+            req: reqwest::Request,
+            next: Next<'_>,
+        ) -> Result<Response, VonageClientError> {
+            let retry = req.try_clone().unwrap();
+            let res = next.run(req).await?;
+            if res.status().is_server_error() {
+                return next.run(retry).await;
+            }
+            Ok(res)
+        }
+    }
+
+    fn client(base_url: &url::Url) -> VonageClient {
+        VonageClient::builder()
+            .token_provider(StaticTokenProvider::new("token".into(), usize::MAX))
+            .api_base_url(ApiFamily::Voice, base_url.to_string())
+            .middleware(RetryOnce)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn record_request_span() {
+        init();
+        let capture = Capture::default();
+        let _guard = tracing::subscriber::set_default(capture.clone());
+        let server = LocalServer::start(
+            MockServer::default()
+                .respond(503, "{}")
+                .respond_with_headers(200, &[("x-request-id", "req-1")], b"{}".to_vec())
+                .respond(404, "{}"),
+        )
+        .await;
+        let mut client = client(server.url());
+        let path = || ApiPath::new(ApiFamily::Voice, "/v1/calls/{uuid}").param("uuid", "aaa");
+
+        let _: serde_json::Value = client.get(path()).await.unwrap();
+        assert!(client.get::<serde_json::Value>(path()).await.is_err());
+
+        let spans = capture.spans("vonage.request");
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0]["method"], "GET");
+        assert_eq!(spans[0]["path"], "/v1/calls/{uuid}");
+        assert_eq!(spans[0]["status"], "200");
+        assert_eq!(spans[0]["request_id"], "req-1");
+        assert_eq!(spans[0]["retries"], "1");
+        assert!(spans[0]["latency_ms"].parse::<u64>().is_ok());
+        assert_eq!(spans[1]["status"], "404");
+        assert_eq!(spans[1]["retries"], "0");
+        assert!(!spans[1].contains_key("request_id"));
+    }
+
+    #[tokio::test]
+    async fn record_failed_request_span() {
+        init();
+        let capture = Capture::default();
+        let _guard = tracing::subscriber::set_default(capture.clone());
+        // A port nothing listens on
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = url::Url::parse(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+        drop(listener);
+        let mut client = client(&url);
+
+        let result = client
+            .get::<serde_json::Value>(ApiPath::new(ApiFamily::Voice, "/v1/calls"))
+            .await;
+
+        assert!(matches!(result, Err(VonageClientError::HttpClientError(_))));
+        let spans = capture.spans("vonage.request");
+        assert_eq!(spans.len(), 1);
+        assert!(!spans[0].contains_key("status"));
+        assert_eq!(spans[0]["retries"], "0");
+        assert!(spans[0].contains_key("latency_ms"));
+        assert!(capture
+            .events
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|event| event["message"] == "Vonage API request failed"));
+    }
+}
@@ -0,0 +1,396 @@
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+
+use super::client_types::{PrivateKey, Sensitive, Token};
+use super::jwt::VonageJwt;
+
+/// Token Provider
+/// This trait supplies the bearer token `VonageClient` sends with each request
+///
+/// Implement this trait to source tokens from somewhere other than a local private key,
+/// for example a secrets manager or a signing service.
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::{StaticTokenProvider, VonageClient};
+/// let client = VonageClient::builder()
+///    .token_provider(StaticTokenProvider::new("pre-signed-jwt".into(), 1735689600))
+///    .build();
+/// assert!(client.is_ok());
+/// ```
+#[async_trait]
+pub trait TokenProvider: Debug + Send + Sync {
+    /// Provide a token
+    /// @return The token and its expiry as a unix timestamp
+    async fn token(&self) -> Result<(Token, usize), TokenProviderError>;
+
+    /// Sign a set of claims with the application key, used for tokens handed to client SDKs
+    /// Providers that cannot sign, such as `StaticTokenProvider`, return `SigningUnsupported`
+    /// @param claims The claims to sign
+    /// @return The signed token
+    async fn sign(&self, _claims: &VonageJwt) -> Result<Token, TokenProviderError> {
+        Err(TokenProviderError::SigningUnsupported)
+    }
+}
+
+/// A shared provider, so the caller can keep a handle to it, e.g. to call `RotatingKeyTokenProvider::set_keys`
+#[async_trait]
+impl<T: TokenProvider + ?Sized> TokenProvider for Arc<T> {
+    async fn token(&self) -> Result<(Token, usize), TokenProviderError> {
+        (**self).token().await
+    }
+
+    async fn sign(&self, claims: &VonageJwt) -> Result<Token, TokenProviderError> {
+        (**self).sign(claims).await
+    }
+}
+
+#[derive(Debug)]
+pub enum TokenProviderError {
+    ErrorGeneratingToken(jsonwebtoken::errors::Error),
+    ErrorSerializingClaims(serde_json::Error),
+    SignerError(String),
+    NoKeysConfigured,
+    SigningUnsupported,
+}
+
+impl std::fmt::Display for TokenProviderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokenProviderError::ErrorGeneratingToken(e) => {
+                write!(f, "error generating token: {}", e)
+            }
+            TokenProviderError::ErrorSerializingClaims(e) => {
+                write!(f, "error serializing claims: {}", e)
+            }
+            TokenProviderError::SignerError(e) => write!(f, "signer error: {}", e),
+            TokenProviderError::NoKeysConfigured => write!(f, "no keys configured"),
+            TokenProviderError::SigningUnsupported => {
+                write!(f, "the token provider cannot sign claims")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TokenProviderError {}
+
+/// Private Key Token Provider
+/// Generates application tokens from a local PEM encoded private key
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PrivateKeyTokenProvider {
+    app_id: String,
+    private_key: PrivateKey,
+    expiry: usize,
+}
+
+impl PrivateKeyTokenProvider {
+    /// Create a new PrivateKeyTokenProvider
+    /// @param app_id The Vonage Application ID
+    /// @param private_key The private key for the Vonage Application
+    /// @param expiry The lifetime of generated tokens in seconds, defaults to 3600
+    pub fn new(app_id: String, private_key: PrivateKey, expiry: Option<usize>) -> Self {
+        log::trace!("Creating PrivateKeyTokenProvider for app_id: {}", app_id);
+        PrivateKeyTokenProvider {
+            app_id,
+            private_key,
+            expiry: expiry.unwrap_or(3600),
+        }
+    }
+}
+
+#[async_trait]
+impl TokenProvider for PrivateKeyTokenProvider {
+    async fn token(&self) -> Result<(Token, usize), TokenProviderError> {
+        VonageJwt::new(self.app_id.clone())
+            .set_exp(self.expiry)
+            .generate(self.private_key.clone())
+            .map_err(TokenProviderError::ErrorGeneratingToken)
+    }
+
+    async fn sign(&self, claims: &VonageJwt) -> Result<Token, TokenProviderError> {
+        claims
+            .generate(self.private_key.clone())
+            .map(|(token, _)| token)
+            .map_err(TokenProviderError::ErrorGeneratingToken)
+    }
+}
+
+/// Static Token Provider
+/// Always provides the same pre-signed token, useful when tokens are minted by another service
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct StaticTokenProvider {
+    token: Token,
+    expiry: usize,
+}
+
+impl StaticTokenProvider {
+    /// Create a new StaticTokenProvider
+    /// @param token The pre-signed token
+    /// @param expiry The expiry of the token as a unix timestamp
+    pub fn new(token: Token, expiry: usize) -> Self {
+        StaticTokenProvider { token, expiry }
+    }
+}
+
+#[async_trait]
+impl TokenProvider for StaticTokenProvider {
+    async fn token(&self) -> Result<(Token, usize), TokenProviderError> {
+        Ok((self.token.clone(), self.expiry))
+    }
+}
+
+/// Rotating Key Token Provider
+/// Signs each new token with the next private key in turn, so keys can be rotated without downtime
+///
+/// Keys can be replaced at runtime with `set_keys`, the next token will be signed with the new set.
+/// Pass the provider to the builder in an `Arc` and keep a clone to call `set_keys` on.
+///
+/// ## Example
+/// ```rust
+/// use std::sync::Arc;
+/// use vonage_client::{RotatingKeyTokenProvider, VonageClient};
+/// let provider = Arc::new(RotatingKeyTokenProvider::new("app-id".into(), vec![], None));
+/// let client = VonageClient::builder()
+///    .token_provider(provider.clone())
+///    .build();
+/// assert!(client.is_ok());
+/// // later, once the new key is registered with the application
+/// provider.set_keys(vec![]);
+/// ```
+#[derive(Debug)]
+pub struct RotatingKeyTokenProvider {
+    app_id: String,
+    keys: RwLock<Vec<PrivateKey>>,
+    next: AtomicUsize,
+    expiry: usize,
+}
+
+impl RotatingKeyTokenProvider {
+    /// Create a new RotatingKeyTokenProvider
+    /// @param app_id The Vonage Application ID
+    /// @param keys The private keys to rotate between
+    /// @param expiry The lifetime of generated tokens in seconds, defaults to 3600
+    pub fn new(app_id: String, keys: Vec<PrivateKey>, expiry: Option<usize>) -> Self {
+        RotatingKeyTokenProvider {
+            app_id,
+            keys: RwLock::new(keys),
+            next: AtomicUsize::new(0),
+            expiry: expiry.unwrap_or(3600),
+        }
+    }
+
+    /// Replace the keys to rotate between
+    /// @param keys The new private keys
+    pub fn set_keys(&self, keys: Vec<PrivateKey>) {
+        log::debug!("Replacing {} rotating keys", keys.len());
+        *self.keys.write().unwrap() = keys;
+        self.next.store(0, Ordering::Relaxed);
+    }
+
+    fn next_key(&self) -> Option<PrivateKey> {
+        let keys = self.keys.read().unwrap();
+        if keys.is_empty() {
+            return None;
+        }
+        let index = self.next.fetch_add(1, Ordering::Relaxed) % keys.len();
+        keys.get(index).cloned()
+    }
+}
+
+#[async_trait]
+impl TokenProvider for RotatingKeyTokenProvider {
+    async fn token(&self) -> Result<(Token, usize), TokenProviderError> {
+        let private_key = self
+            .next_key()
+            .ok_or(TokenProviderError::NoKeysConfigured)?;
+        VonageJwt::new(self.app_id.clone())
+            .set_exp(self.expiry)
+            .generate(private_key)
+            .map_err(TokenProviderError::ErrorGeneratingToken)
+    }
+
+    async fn sign(&self, claims: &VonageJwt) -> Result<Token, TokenProviderError> {
+        let private_key = self
+            .next_key()
+            .ok_or(TokenProviderError::NoKeysConfigured)?;
+        claims
+            .generate(private_key)
+            .map(|(token, _)| token)
+            .map_err(TokenProviderError::ErrorGeneratingToken)
+    }
+}
+
+/// JWT Signer
+/// Produces an RS256 signature over a JWT signing input without exposing the private key,
+/// implement this for a KMS, HSM or any other external signing service
+#[async_trait]
+pub trait JwtSigner: Debug + Send + Sync {
+    /// Sign the message
+    /// @param message The JWT signing input, `base64url(header).base64url(claims)`
+    /// @return The raw RSASSA-PKCS1-v1_5 SHA-256 signature bytes
+    async fn sign(&self, message: &[u8]) -> Result<Vec<u8>, TokenProviderError>;
+}
+
+/// Signer Token Provider
+/// Builds application tokens and has them signed by a `JwtSigner`
+#[derive(Debug)]
+pub struct SignerTokenProvider<S: JwtSigner> {
+    app_id: String,
+    signer: S,
+    expiry: usize,
+}
+
+impl<S: JwtSigner> SignerTokenProvider<S> {
+    /// Create a new SignerTokenProvider
+    /// @param app_id The Vonage Application ID
+    /// @param signer The signer holding the private key
+    /// @param expiry The lifetime of generated tokens in seconds, defaults to 3600
+    pub fn new(app_id: String, signer: S, expiry: Option<usize>) -> Self {
+        SignerTokenProvider {
+            app_id,
+            signer,
+            expiry: expiry.unwrap_or(3600),
+        }
+    }
+}
+
+#[async_trait]
+impl<S: JwtSigner> TokenProvider for SignerTokenProvider<S> {
+    async fn token(&self) -> Result<(Token, usize), TokenProviderError> {
+        let mut claims = VonageJwt::new(self.app_id.clone());
+        claims.set_exp(self.expiry);
+        let token = self.sign(&claims).await?;
+        Ok((token, claims.exp()))
+    }
+
+    async fn sign(&self, claims: &VonageJwt) -> Result<Token, TokenProviderError> {
+        let header = serde_json::to_vec(&jsonwebtoken::Header::new(jsonwebtoken::Algorithm::RS256))
+            .map_err(TokenProviderError::ErrorSerializingClaims)?;
+        let payload =
+            serde_json::to_vec(claims).map_err(TokenProviderError::ErrorSerializingClaims)?;
+        let message = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(header),
+            URL_SAFE_NO_PAD.encode(payload)
+        );
+        let signature = self.signer.sign(message.as_bytes()).await?;
+        let token = format!("{}.{}", message, URL_SAFE_NO_PAD.encode(signature));
+        Ok(Sensitive::new(token))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate pretty_env_logger;
+    use super::*;
+    use crate::client::jwt::set_mock_time;
+    use crate::client_types::SensitiveUnwrap;
+    use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Validation};
+    use serde_json::Value;
+
+    const PRIVATE_KEY: &str = include_str!("test_data/private_key.pem");
+    const PUBLIC_KEY: &str = include_str!("test_data/public_key.pem");
+
+    fn init() {
+        let _ = pretty_env_logger::formatted_builder()
+            .is_test(true)
+            .try_init();
+    }
+
+    fn decode(token: Token) -> Value {
+        let mut validation = Validation::new(Algorithm::RS256);
+        validation.validate_exp = false;
+        validation.validate_nbf = false;
+        jsonwebtoken::decode::<Value>(
+            &token.unwrap(),
+            &DecodingKey::from_rsa_pem(PUBLIC_KEY.as_bytes()).unwrap(),
+            &validation,
+        )
+        .unwrap()
+        .claims
+    }
+
+    /// A stand-in for a KMS, the key stays inside the signer
+    struct LocalKms {
+        key: EncodingKey,
+    }
+
+    impl std::fmt::Debug for LocalKms {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("LocalKms").finish_non_exhaustive()
+        }
+    }
+
+    #[async_trait]
+    impl JwtSigner for LocalKms {
+        async fn sign(&self, message: &[u8]) -> Result<Vec<u8>, TokenProviderError> {
+            let signature = jsonwebtoken::crypto::sign(message, &self.key, Algorithm::RS256)
+                .map_err(|e| TokenProviderError::SignerError(e.to_string()))?;
+            URL_SAFE_NO_PAD
+                .decode(signature)
+                .map_err(|e| TokenProviderError::SignerError(e.to_string()))
+        }
+    }
+
+    #[tokio::test]
+    async fn private_key_provider() {
+        init();
+        set_mock_time(1000);
+        let provider = PrivateKeyTokenProvider::new("app-id".into(), PRIVATE_KEY.into(), Some(60));
+        let (token, exp) = provider.token().await.unwrap();
+        assert_eq!(exp, 1060);
+        let claims = decode(token);
+        assert_eq!(claims["application_id"], "app-id");
+        assert_eq!(claims["exp"], 1060);
+    }
+
+    #[tokio::test]
+    async fn static_provider() {
+        init();
+        let provider = StaticTokenProvider::new("pre-signed".into(), 42);
+        let (token, exp) = provider.token().await.unwrap();
+        assert_eq!(token.unwrap(), "pre-signed");
+        assert_eq!(exp, 42);
+    }
+
+    #[tokio::test]
+    async fn rotating_provider() {
+        init();
+        let provider = RotatingKeyTokenProvider::new(
+            "app-id".into(),
+            vec![PRIVATE_KEY.into(), "not-a-key".into()],
+            None,
+        );
+        assert!(provider.token().await.is_ok());
+        assert!(matches!(
+            provider.token().await,
+            Err(TokenProviderError::ErrorGeneratingToken(_))
+        ));
+        assert!(provider.token().await.is_ok());
+
+        provider.set_keys(vec![]);
+        assert!(matches!(
+            provider.token().await,
+            Err(TokenProviderError::NoKeysConfigured)
+        ));
+    }
+
+    #[tokio::test]
+    async fn signer_provider() {
+        init();
+        set_mock_time(1000);
+        let kms = LocalKms {
+            key: EncodingKey::from_rsa_pem(PRIVATE_KEY.as_bytes()).unwrap(),
+        };
+        let provider = SignerTokenProvider::new("app-id".into(), kms, Some(120));
+        let (token, exp) = provider.token().await.unwrap();
+        assert_eq!(exp, 1120);
+        let claims = decode(token);
+        assert_eq!(claims["application_id"], "app-id");
+        assert_eq!(claims["exp"], 1120);
+    }
+}
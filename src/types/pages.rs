@@ -1,10 +1,23 @@
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
+
 #[derive(Debug, Deserialize)]
 pub struct PageMeta {
     page_size: Option<usize>,
     _links: Links,
 }
-#[derive(Debug, Deserialize)]
+
+impl PageMeta {
+    pub fn page_size(&self) -> Option<usize> {
+        self.page_size
+    }
+
+    pub fn links(&self) -> &Links {
+        &self._links
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
 pub struct Links {
     first: Option<Link>,
     #[serde(rename = "self")]
@@ -12,7 +25,108 @@ pub struct Links {
     next: Option<Link>,
     prev: Option<Link>,
 }
-#[derive(Debug, Deserialize)]
+
+impl Links {
+    pub fn first(&self) -> Option<&Link> {
+        self.first.as_ref()
+    }
+
+    pub fn me(&self) -> Option<&Link> {
+        self.me.as_ref()
+    }
+
+    pub fn next(&self) -> Option<&Link> {
+        self.next.as_ref()
+    }
+
+    pub fn prev(&self) -> Option<&Link> {
+        self.prev.as_ref()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
 pub struct Link {
     href: String,
-}
\ No newline at end of file
+}
+
+impl Link {
+    pub fn href(&self) -> &str {
+        &self.href
+    }
+}
+
+/// Page Style
+/// How a list endpoint moves from one page to the next
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageStyle {
+    /// Follow the `_links.next` href returned with each page, used by the Conversation and Voice APIs
+    Cursor,
+    /// Request pages by a 1-based index until `count` records have been seen, used by the Numbers API
+    RecordIndex,
+}
+
+/// Page
+/// A single page of a list endpoint
+pub trait Page: DeserializeOwned + std::fmt::Debug + Send + 'static {
+    type Item: Send + 'static;
+
+    /// How this endpoint moves between pages
+    const STYLE: PageStyle;
+    /// The query parameter used to set the page size
+    const PAGE_SIZE_PARAM: &'static str = "page_size";
+    /// The query parameter used to set the page index, for `PageStyle::RecordIndex`
+    const PAGE_INDEX_PARAM: &'static str = "index";
+
+    /// The href of the next page, for `PageStyle::Cursor`
+    fn next_href(&self) -> Option<&str> {
+        None
+    }
+
+    /// The total number of records, for `PageStyle::RecordIndex`
+    fn total(&self) -> Option<usize> {
+        None
+    }
+
+    /// Take the items of the page
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// Page Options
+/// Controls how a list endpoint is walked
+///
+/// ## Example
+/// ```rust
+/// use vonage_client::pages::PageOptions;
+/// let options = PageOptions::new().page_size(100).max_items(1000);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PageOptions {
+    page_size: Option<usize>,
+    max_items: Option<usize>,
+}
+
+impl PageOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of records to request per page, defaults to the API default
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// Stop after this many records have been yielded
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    pub fn get_page_size(&self) -> Option<usize> {
+        self.page_size
+    }
+
+    pub fn get_max_items(&self) -> Option<usize> {
+        self.max_items
+    }
+}